@@ -1,3 +1,6 @@
+// Shared by several bench targets; each one only uses part of it.
+#![allow(dead_code)]
+
 use criterion::{Criterion, Throughput, PlotConfiguration, AxisScale};
 
 pub fn setup_benchmark_group<'a>(name: &str, c: &'a mut Criterion) -> criterion::BenchmarkGroup<'a, criterion::measurement::WallTime> {
@@ -16,6 +19,10 @@ pub fn get_extended_bit_sizes() -> Vec<usize> {
     vec![64, 128, 256, 512, 1024]
 }
 
+pub fn get_rsa_bit_sizes() -> Vec<usize> {
+    vec![2048, 3072, 4096, 8192]
+}
+
 pub struct BenchmarkConfig {
     pub plot_config: PlotConfiguration,
     pub throughput: Throughput,
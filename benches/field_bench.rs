@@ -0,0 +1,96 @@
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion};
+use nail::{reduce, FieldParams, Fp, Reduction, Uint};
+
+mod common;
+use common::*;
+
+// Each prime is declared twice: once with the default Montgomery backend and once with its
+// special-form reduction, so the two can be compared on identical inputs.
+macro_rules! field_pair {
+    ($monty:ident, $special:ident, $limbs:literal, $modulus:expr, $reduce:expr) => {
+        #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+        struct $monty;
+
+        impl FieldParams<$limbs> for $monty {
+            const MODULUS: Uint<$limbs> = $modulus;
+        }
+
+        #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+        struct $special;
+
+        impl FieldParams<$limbs> for $special {
+            const MODULUS: Uint<$limbs> = $modulus;
+            const REDUCTION: Reduction<$limbs> = Reduction::Special($reduce);
+        }
+    };
+}
+
+field_pair!(
+    P25519Monty,
+    P25519Special,
+    4,
+    Uint::from_be_hex("7FFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFED"),
+    reduce::reduce_p25519
+);
+field_pair!(
+    Secp256k1Monty,
+    Secp256k1Special,
+    4,
+    Uint::from_be_hex("FFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFEFFFFFC2F"),
+    reduce::reduce_secp256k1
+);
+field_pair!(
+    P256Monty,
+    P256Special,
+    4,
+    Uint::from_be_hex("FFFFFFFF00000001000000000000000000000000FFFFFFFFFFFFFFFFFFFFFFFF"),
+    reduce::reduce_p256
+);
+field_pair!(
+    GoldilocksMonty,
+    GoldilocksSpecial,
+    1,
+    Uint::from_u64(0xFFFF_FFFF_0000_0001),
+    reduce::reduce_goldilocks
+);
+
+fn create_field_element<const LIMBS: usize, P: FieldParams<LIMBS>>() -> Fp<LIMBS, P> {
+    let mut uint = Uint::<LIMBS>::zero();
+    for limb in uint.limbs.iter_mut() {
+        *limb = 0x123456789ABCDEF0u64;
+    }
+    Fp::from_uint(&uint)
+}
+
+fn bench_field_mul<const LIMBS: usize, P: FieldParams<LIMBS>>(
+    group: &mut criterion::BenchmarkGroup<'_, criterion::measurement::WallTime>,
+    backend: &str,
+    prime: &str,
+) {
+    let a = create_field_element::<LIMBS, P>();
+    let b = a.square();
+    group.bench_with_input(BenchmarkId::new(backend, prime), &prime, |bench, _| {
+        bench.iter(|| {
+            let result = black_box(&a).mul(black_box(&b));
+            black_box(result)
+        })
+    });
+}
+
+fn bench_field_multiplication(c: &mut Criterion) {
+    let mut group = setup_benchmark_group("Field Multiplication", c);
+
+    bench_field_mul::<4, P25519Monty>(&mut group, "montgomery", "p25519");
+    bench_field_mul::<4, P25519Special>(&mut group, "special", "p25519");
+    bench_field_mul::<4, Secp256k1Monty>(&mut group, "montgomery", "secp256k1");
+    bench_field_mul::<4, Secp256k1Special>(&mut group, "special", "secp256k1");
+    bench_field_mul::<4, P256Monty>(&mut group, "montgomery", "p256");
+    bench_field_mul::<4, P256Special>(&mut group, "special", "p256");
+    bench_field_mul::<1, GoldilocksMonty>(&mut group, "montgomery", "goldilocks");
+    bench_field_mul::<1, GoldilocksSpecial>(&mut group, "special", "goldilocks");
+
+    group.finish();
+}
+
+criterion_group!(benches, bench_field_multiplication);
+criterion_main!(benches);
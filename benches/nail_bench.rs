@@ -1,5 +1,5 @@
 use criterion::{black_box, criterion_group, criterion_main, Criterion, BenchmarkId};
-use nail::Uint;
+use nail::{MontyParams, Uint};
 
 mod common;
 use common::*;
@@ -41,6 +41,14 @@ fn create_fixed_array_uint_1024() -> Uint<16> {
     uint
 }
 
+fn create_fixed_array_uint<const LIMBS: usize>() -> Uint<LIMBS> {
+    let mut uint = Uint::<LIMBS>::zero();
+    for i in 0..LIMBS {
+        uint.limbs[i] = 0x123456789ABCDEF0u64;
+    }
+    uint
+}
+
 fn bench_nail_addition(c: &mut Criterion) {
     let mut group = setup_benchmark_group("Nail Addition", c);
     
@@ -286,10 +294,53 @@ fn bench_nail_modular(c: &mut Criterion) {
     group.finish();
 }
 
+fn bench_nail_rsa_width<const LIMBS: usize>(
+    group: &mut criterion::BenchmarkGroup<'_, criterion::measurement::WallTime>,
+    bits: usize,
+) {
+    let a = create_fixed_array_uint::<LIMBS>();
+    let b = create_fixed_array_uint::<LIMBS>();
+    // Montgomery arithmetic needs an odd modulus.
+    let mut modulus = create_fixed_array_uint::<LIMBS>();
+    modulus.limbs[0] |= 1;
+    let monty = MontyParams::new(&modulus);
+    let a_monty = monty.to_monty(&a.shr(1));
+    let b_monty = monty.to_monty(&b.shr(1));
+
+    group.bench_with_input(BenchmarkId::new("add", bits), &bits, |bench, _| {
+        bench.iter(|| black_box(black_box(&a).add(black_box(&b))))
+    });
+    group.bench_with_input(BenchmarkId::new("mul", bits), &bits, |bench, _| {
+        bench.iter(|| black_box(black_box(&a).mul(black_box(&b))))
+    });
+    group.bench_with_input(BenchmarkId::new("widening_mul", bits), &bits, |bench, _| {
+        bench.iter(|| black_box(black_box(&a).widening_mul(black_box(&b))))
+    });
+    group.bench_with_input(BenchmarkId::new("monty_mul", bits), &bits, |bench, _| {
+        bench.iter(|| black_box(monty.mul(black_box(&a_monty), black_box(&b_monty))))
+    });
+}
+
+fn bench_nail_rsa_widths(c: &mut Criterion) {
+    let mut group = setup_benchmark_group("Nail RSA Widths", c);
+
+    for bits in get_rsa_bit_sizes() {
+        match bits {
+            2048 => bench_nail_rsa_width::<32>(&mut group, bits),
+            3072 => bench_nail_rsa_width::<48>(&mut group, bits),
+            4096 => bench_nail_rsa_width::<64>(&mut group, bits),
+            8192 => bench_nail_rsa_width::<128>(&mut group, bits),
+            _ => unreachable!()
+        }
+    }
+    group.finish();
+}
+
 criterion_group!(
     benches,
     bench_nail_addition,
     bench_nail_multiplication,
-    bench_nail_modular
+    bench_nail_modular,
+    bench_nail_rsa_widths
 );
 criterion_main!(benches);
\ No newline at end of file
@@ -1,4 +1,4 @@
-use nail::{U256, U512};
+use nail::{curves::secp256k1::ProjectivePoint, U256, U512};
 
 fn main() {
     println!("=== Cryptographic Operations Example ===\n");
@@ -40,22 +40,19 @@ fn main() {
     println!("Encrypted (simplified): 0x{:016X}{:016X}", 
              encrypted.limbs[1], encrypted.limbs[0]);
 
-    println!("\n3. Elliptic Curve Operations (Point Addition Simulation):");
-    
-    // Simulate elliptic curve point coordinates
-    let x1 = U256::from_u64(0x123456789ABCDEF0);
-    let y1 = U256::from_u64(0xFEDCBA9876543210);
-    let x2 = U256::from_u64(0x1111111111111111);
-    let y2 = U256::from_u64(0x2222222222222222);
-    
-    println!("Point 1: (0x{:016X}, 0x{:016X})", x1.limbs[0], y1.limbs[0]);
-    println!("Point 2: (0x{:016X}, 0x{:016X})", x2.limbs[0], y2.limbs[0]);
-    
-    // Simplified point addition (real EC addition is much more complex)
-    let x3 = x1.add(&x2);
-    let y3 = y1.add(&y2);
-    
-    println!("Result:  (0x{:016X}, 0x{:016X})", x3.limbs[0], y3.limbs[0]);
+    println!("\n3. Elliptic Curve Operations (secp256k1):");
+
+    // Real group arithmetic on secp256k1: G + 2G must equal 3G
+    let g = ProjectivePoint::GENERATOR;
+    let g2 = g.double();
+    let sum = g.add(&g2);
+    let g3 = g.mul(&U256::from_u64(3));
+
+    println!("G:      {}", g.to_affine().x());
+    println!("2G:     {}", g2.to_affine().x());
+    println!("G + 2G: {}", sum.to_affine().x());
+    println!("3G:     {}", g3.to_affine().x());
+    println!("G + 2G == 3G: {}", sum == g3);
 
     println!("\n4. Hash Function Simulation:");
     
@@ -3,7 +3,7 @@ use std::time::Instant;
 
 fn benchmark_operation<F>(name: &str, iterations: usize, mut op: F) 
 where 
-    F: FnMut() -> (),
+    F: FnMut(),
 {
     let start = Instant::now();
     for _ in 0..iterations {
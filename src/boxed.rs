@@ -0,0 +1,578 @@
+//! Heap-allocated unsigned integers whose width is chosen at runtime.
+//!
+//! [`BoxedUint`] suits sizes that come from configuration, such as RSA moduli, where
+//! monomorphizing [`Uint`] for every width is unwanted. It runs on the same limb kernels as
+//! `Uint`, so results agree limb for limb, and has the same wrapping, width-preserving semantics:
+//! the left operand of a binary operation must be at least as wide as the right one, and the
+//! result has the width of the left operand. [`BoxedMontyParams`] is the runtime counterpart of
+//! [`MontyParams`](crate::MontyParams).
+//!
+//! Requires the `alloc` feature.
+
+use alloc::{boxed::Box, vec, vec::Vec};
+use core::{cmp::Ordering, fmt};
+
+use crate::{
+    limb::{
+        add_assign, cond_add_assign, div_rem_into, div_rem_vartime_into, lt, mask, monty_mul_into,
+        mul_into, sbb, shl_into, shr_into, sub_assign,
+    },
+    uint::Uint,
+};
+
+/// An unsigned integer with a runtime number of 64-bit limbs.
+///
+/// Comparisons are by value, so integers of different widths can be equal.
+#[derive(Clone)]
+pub struct BoxedUint {
+    limbs: Box<[u64]>,
+}
+
+impl BoxedUint {
+    /// Returns zero with `nlimbs` limbs. Panics if `nlimbs` is zero.
+    pub fn zero(nlimbs: usize) -> Self {
+        assert!(nlimbs > 0, "a BoxedUint needs at least one limb");
+        Self {
+            limbs: vec![0; nlimbs].into_boxed_slice(),
+        }
+    }
+
+    /// Returns one with `nlimbs` limbs. Panics if `nlimbs` is zero.
+    pub fn one(nlimbs: usize) -> Self {
+        Self::from_u64(1, nlimbs)
+    }
+
+    /// Creates an integer with `nlimbs` limbs from a `u64`. Panics if `nlimbs` is zero.
+    pub fn from_u64(x: u64, nlimbs: usize) -> Self {
+        let mut out = Self::zero(nlimbs);
+        out.limbs[0] = x;
+        out
+    }
+
+    /// Creates an integer from little-endian limbs. Panics if `limbs` is empty.
+    pub fn from_limbs(limbs: Vec<u64>) -> Self {
+        assert!(!limbs.is_empty(), "a BoxedUint needs at least one limb");
+        Self {
+            limbs: limbs.into_boxed_slice(),
+        }
+    }
+
+    /// Parses a big-endian hexadecimal string (without a `0x` prefix) into `nlimbs` limbs.
+    ///
+    /// Returns `None` on an invalid digit or if the value does not fit.
+    pub fn from_be_hex(hex: &str, nlimbs: usize) -> Option<Self> {
+        let mut out = Self::zero(nlimbs);
+        for (i, c) in hex.bytes().rev().enumerate() {
+            let nibble = (c as char).to_digit(16)? as u64;
+            if i / 16 < nlimbs {
+                out.limbs[i / 16] |= nibble << ((i % 16) * 4);
+            } else if nibble != 0 {
+                return None;
+            }
+        }
+        Some(out)
+    }
+
+    /// Returns the little-endian limbs.
+    pub fn as_limbs(&self) -> &[u64] {
+        &self.limbs
+    }
+
+    /// Returns the number of limbs.
+    pub fn nlimbs(&self) -> usize {
+        self.limbs.len()
+    }
+
+    /// Returns the width in bits, `nlimbs() * 64`.
+    pub fn bits_precision(&self) -> usize {
+        self.limbs.len() * 64
+    }
+
+    /// Returns the number of significant bits, i.e. the position of the highest set bit plus one.
+    pub fn bits(&self) -> usize {
+        match self.limbs.iter().rposition(|&limb| limb != 0) {
+            Some(i) => i * 64 + 64 - self.limbs[i].leading_zeros() as usize,
+            None => 0,
+        }
+    }
+
+    /// Returns the bit at position `index`; indices beyond the width read as zero.
+    pub fn bit(&self, index: usize) -> bool {
+        index < self.bits_precision() && (self.limbs[index / 64] >> (index % 64)) & 1 == 1
+    }
+
+    /// Returns `true` if the value is zero.
+    pub fn is_zero(&self) -> bool {
+        self.limbs.iter().fold(0, |acc, &limb| acc | limb) == 0
+    }
+
+    /// Returns `true` if the least significant bit is set.
+    pub fn is_odd(&self) -> bool {
+        self.limbs[0] & 1 == 1
+    }
+
+    /// Converts to `nlimbs` limbs, zero-extending or truncating the high limbs.
+    pub fn resize(&self, nlimbs: usize) -> Self {
+        let mut out = Self::zero(nlimbs);
+        let n = nlimbs.min(self.nlimbs());
+        out.limbs[..n].copy_from_slice(&self.limbs[..n]);
+        out
+    }
+
+    /// Converts to a `Uint<LIMBS>`, or returns `None` if the value does not fit.
+    pub fn to_uint<const LIMBS: usize>(&self) -> Option<Uint<LIMBS>> {
+        if self.bits() > Uint::<LIMBS>::BITS {
+            return None;
+        }
+        let mut out = Uint::ZERO;
+        let n = LIMBS.min(self.nlimbs());
+        out.limbs[..n].copy_from_slice(&self.limbs[..n]);
+        Some(out)
+    }
+
+    fn assert_wider_or_equal(&self, other: &Self) {
+        assert!(
+            self.nlimbs() >= other.nlimbs(),
+            "Left operand must have >= limbs than right operand"
+        );
+    }
+
+    /// Wrapping addition; `other` must not have more limbs than `self`.
+    pub fn add(&self, other: &Self) -> Self {
+        self.overflowing_add(other).0
+    }
+
+    /// Computes `self + other`, returning the wrapped sum and whether the addition overflowed.
+    /// `other` must not have more limbs than `self`.
+    pub fn overflowing_add(&self, other: &Self) -> (Self, bool) {
+        self.assert_wider_or_equal(other);
+        let mut out = self.clone();
+        let carry = add_assign(&mut out.limbs, &other.limbs);
+        (out, carry != 0)
+    }
+
+    /// Wrapping subtraction; `other` must not have more limbs than `self`.
+    pub fn sub(&self, other: &Self) -> Self {
+        self.overflowing_sub(other).0
+    }
+
+    /// Computes `self - other`, returning the wrapped difference and whether the subtraction
+    /// underflowed. `other` must not have more limbs than `self`.
+    pub fn overflowing_sub(&self, other: &Self) -> (Self, bool) {
+        self.assert_wider_or_equal(other);
+        let mut out = self.clone();
+        let borrow = sub_assign(&mut out.limbs, &other.limbs);
+        (out, borrow != 0)
+    }
+
+    /// Wrapping multiplication, truncated to the width of `self`; `other` must not have more limbs
+    /// than `self`.
+    pub fn mul(&self, other: &Self) -> Self {
+        self.assert_wider_or_equal(other);
+        let mut out = Self::zero(self.nlimbs());
+        mul_into(&mut out.limbs, &self.limbs, &other.limbs);
+        out
+    }
+
+    /// Computes the full product, with `self.nlimbs() + other.nlimbs()` limbs.
+    pub fn widening_mul(&self, other: &Self) -> Self {
+        let mut out = Self::zero(self.nlimbs() + other.nlimbs());
+        mul_into(&mut out.limbs, &self.limbs, &other.limbs);
+        out
+    }
+
+    /// Shifts left by `shift` bits, discarding bits shifted out.
+    pub fn shl(&self, shift: usize) -> Self {
+        let mut out = Self::zero(self.nlimbs());
+        shl_into(&mut out.limbs, &self.limbs, shift);
+        out
+    }
+
+    /// Shifts right by `shift` bits.
+    pub fn shr(&self, shift: usize) -> Self {
+        let mut out = Self::zero(self.nlimbs());
+        shr_into(&mut out.limbs, &self.limbs, shift);
+        out
+    }
+
+    /// Computes `(self / divisor, self % divisor)` by binary long division, both with the width of
+    /// `self`.
+    ///
+    /// Variable-time, like [`Uint::div_rem`]. Panics if `divisor` is zero or has more limbs than
+    /// `self`.
+    pub fn div_rem(&self, divisor: &Self) -> (Self, Self) {
+        self.div_rem_with(divisor, false)
+    }
+
+    /// Computes `self % modulus`, in variable time. Panics if `modulus` is zero or has more limbs
+    /// than `self`.
+    pub fn rem(&self, modulus: &Self) -> Self {
+        self.div_rem(modulus).1
+    }
+
+    /// Computes `(self / divisor, self % divisor)` in constant time, like [`Uint::ct_div_rem`].
+    /// Panics if `divisor` is zero or has more limbs than `self`.
+    pub fn ct_div_rem(&self, divisor: &Self) -> (Self, Self) {
+        self.div_rem_with(divisor, true)
+    }
+
+    /// Computes `self % modulus` in constant time. Panics if `modulus` is zero or has more limbs
+    /// than `self`.
+    pub fn ct_rem(&self, modulus: &Self) -> Self {
+        self.ct_div_rem(modulus).1
+    }
+
+    fn div_rem_with(&self, divisor: &Self, constant_time: bool) -> (Self, Self) {
+        self.assert_wider_or_equal(divisor);
+        assert!(!divisor.is_zero(), "division by zero");
+        let divisor = divisor.resize(self.nlimbs());
+        let mut q = Self::zero(self.nlimbs());
+        let mut r = Self::zero(self.nlimbs());
+        if constant_time {
+            div_rem_into(&mut q.limbs, &mut r.limbs, &self.limbs, &divisor.limbs);
+        } else {
+            div_rem_vartime_into(&mut q.limbs, &mut r.limbs, &self.limbs, &divisor.limbs);
+        }
+        (q, r)
+    }
+
+    /// Computes `self + other mod modulus` for operands of equal width already reduced modulo
+    /// `modulus`.
+    pub fn add_mod(&self, other: &Self, modulus: &Self) -> Self {
+        let (mut out, carry) = self.overflowing_add(other);
+        let borrow = sub_assign(&mut out.limbs, &modulus.limbs);
+        // Undo the subtraction if the sum was below the modulus and did not overflow
+        let (_, restore) = sbb(carry as u64, 0, borrow);
+        cond_add_assign(&mut out.limbs, &modulus.limbs, restore);
+        out
+    }
+
+    /// Computes `self - other mod modulus` for operands of equal width already reduced modulo
+    /// `modulus`.
+    pub fn sub_mod(&self, other: &Self, modulus: &Self) -> Self {
+        let (mut out, borrow) = self.overflowing_sub(other);
+        cond_add_assign(&mut out.limbs, &modulus.limbs, borrow as u64);
+        out
+    }
+
+    /// Computes `-self mod modulus` for `self` already reduced modulo `modulus`.
+    pub fn neg_mod(&self, modulus: &Self) -> Self {
+        Self::zero(self.nlimbs()).sub_mod(self, modulus)
+    }
+
+    /// Returns `a` when `choice` is 0 and `b` when `choice` is 1, without branching. Both must
+    /// have the same width.
+    pub(crate) fn select(a: &Self, b: &Self, choice: u64) -> Self {
+        let m = mask(choice);
+        let limbs = a
+            .limbs
+            .iter()
+            .zip(b.limbs.iter())
+            .map(|(&a, &b)| a ^ (m & (a ^ b)));
+        Self {
+            limbs: limbs.collect(),
+        }
+    }
+}
+
+impl<const LIMBS: usize> From<Uint<LIMBS>> for BoxedUint {
+    fn from(x: Uint<LIMBS>) -> Self {
+        Self::from_limbs(x.limbs.to_vec())
+    }
+}
+
+impl<const LIMBS: usize> From<&Uint<LIMBS>> for BoxedUint {
+    fn from(x: &Uint<LIMBS>) -> Self {
+        Self::from(*x)
+    }
+}
+
+impl PartialEq for BoxedUint {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for BoxedUint {}
+
+impl PartialOrd for BoxedUint {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for BoxedUint {
+    /// Zero-extends both operands to the wider width and compares every limb, like [`Uint`]; only
+    /// the widths and the returned `Ordering` affect the running time.
+    fn cmp(&self, other: &Self) -> Ordering {
+        let n = self.nlimbs().max(other.nlimbs());
+        let (a, b) = (self.resize(n), other.resize(n));
+        let less = lt(&a.limbs, &b.limbs);
+        let greater = lt(&b.limbs, &a.limbs);
+        (greater as i64 - less as i64).cmp(&0)
+    }
+}
+
+impl fmt::Debug for BoxedUint {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "BoxedUint({:#x})", self)
+    }
+}
+
+impl fmt::LowerHex for BoxedUint {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if f.alternate() {
+            f.write_str("0x")?;
+        }
+        for limb in self.limbs.iter().rev() {
+            write!(f, "{:016x}", limb)?;
+        }
+        Ok(())
+    }
+}
+
+impl fmt::UpperHex for BoxedUint {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if f.alternate() {
+            f.write_str("0x")?;
+        }
+        for limb in self.limbs.iter().rev() {
+            write!(f, "{:016X}", limb)?;
+        }
+        Ok(())
+    }
+}
+
+/// Montgomery arithmetic modulo an odd [`BoxedUint`] modulus, mirroring
+/// [`MontyParams`](crate::MontyParams).
+///
+/// Values are `BoxedUint`s with the width of the modulus.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BoxedMontyParams {
+    /// The modulus `p`.
+    modulus: BoxedUint,
+    /// `R mod p`, the Montgomery form of one.
+    one: BoxedUint,
+    /// `R^2 mod p`, used to convert into Montgomery form.
+    r2: BoxedUint,
+    /// `-p^-1 mod 2^64`.
+    inv: u64,
+}
+
+impl BoxedMontyParams {
+    /// Computes the Montgomery constants for `modulus`, with `R = 2^(64 * modulus.nlimbs())`.
+    ///
+    /// Panics if the modulus is even or smaller than 3.
+    pub fn new(modulus: &BoxedUint) -> Self {
+        assert!(modulus.is_odd(), "Montgomery modulus must be odd");
+        assert!(
+            modulus.bits() > 1,
+            "Montgomery modulus must be greater than 1"
+        );
+
+        // Newton iteration doubles the number of correct low bits each step: 1 -> 2 -> ... -> 64.
+        let mut inv: u64 = 1;
+        for _ in 0..6 {
+            inv = inv.wrapping_mul(2u64.wrapping_sub(modulus.limbs[0].wrapping_mul(inv)));
+        }
+
+        // R mod p and R^2 mod p by repeated modular doubling of one.
+        let mut one = BoxedUint::one(modulus.nlimbs());
+        for _ in 0..modulus.bits_precision() {
+            one = one.add_mod(&one, modulus);
+        }
+        let mut r2 = one.clone();
+        for _ in 0..modulus.bits_precision() {
+            r2 = r2.add_mod(&r2, modulus);
+        }
+
+        Self {
+            modulus: modulus.clone(),
+            one,
+            r2,
+            inv: inv.wrapping_neg(),
+        }
+    }
+
+    /// Returns the modulus.
+    pub fn modulus(&self) -> &BoxedUint {
+        &self.modulus
+    }
+
+    /// Returns `R mod p`, the Montgomery form of one.
+    pub fn one(&self) -> &BoxedUint {
+        &self.one
+    }
+
+    /// Converts `x` into Montgomery form. Any `x` of the modulus width is accepted and reduced
+    /// modulo `p`.
+    pub fn to_monty(&self, x: &BoxedUint) -> BoxedUint {
+        self.mul(x, &self.r2)
+    }
+
+    /// Converts `x` out of Montgomery form, returning the canonical residue.
+    pub fn from_monty(&self, x: &BoxedUint) -> BoxedUint {
+        self.mul(x, &BoxedUint::one(self.modulus.nlimbs()))
+    }
+
+    /// Montgomery product `a * b * R^-1 mod p`. Panics unless both operands have the width of
+    /// the modulus.
+    pub fn mul(&self, a: &BoxedUint, b: &BoxedUint) -> BoxedUint {
+        let n = self.modulus.nlimbs();
+        assert!(
+            a.nlimbs() == n && b.nlimbs() == n,
+            "operands must have the modulus width"
+        );
+        let mut out = BoxedUint::zero(n);
+        monty_mul_into(
+            &mut out.limbs,
+            &a.limbs,
+            &b.limbs,
+            &self.modulus.limbs,
+            self.inv,
+        );
+        out
+    }
+
+    /// Montgomery square `a^2 * R^-1 mod p`.
+    pub fn square(&self, a: &BoxedUint) -> BoxedUint {
+        self.mul(a, a)
+    }
+
+    /// Computes `a + b mod p` for reduced `a` and `b`.
+    pub fn add(&self, a: &BoxedUint, b: &BoxedUint) -> BoxedUint {
+        a.add_mod(b, &self.modulus)
+    }
+
+    /// Computes `a - b mod p` for reduced `a` and `b`.
+    pub fn sub(&self, a: &BoxedUint, b: &BoxedUint) -> BoxedUint {
+        a.sub_mod(b, &self.modulus)
+    }
+
+    /// Computes `-a mod p` for reduced `a`.
+    pub fn neg(&self, a: &BoxedUint) -> BoxedUint {
+        a.neg_mod(&self.modulus)
+    }
+
+    /// Raises `base` (in Montgomery form) to `exp`, returning the result in Montgomery form.
+    ///
+    /// Every bit of the exponent's width costs one square and one multiply, so the running time
+    /// depends only on the widths, not on the exponent value.
+    pub fn pow(&self, base: &BoxedUint, exp: &BoxedUint) -> BoxedUint {
+        let mut acc = self.one.clone();
+        for i in (0..exp.bits_precision()).rev() {
+            acc = self.square(&acc);
+            let product = self.mul(&acc, base);
+            acc = BoxedUint::select(&acc, &product, exp.bit(i) as u64);
+        }
+        acc
+    }
+
+    /// Like [`BoxedMontyParams::pow`] but skips leading zero bits of the exponent.
+    ///
+    /// Only use this when the exponent is public.
+    pub fn pow_vartime(&self, base: &BoxedUint, exp: &BoxedUint) -> BoxedUint {
+        let mut acc = self.one.clone();
+        for i in (0..exp.bits()).rev() {
+            acc = self.square(&acc);
+            if exp.bit(i) {
+                acc = self.mul(&acc, base);
+            }
+        }
+        acc
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::MontyParams;
+
+    const A: Uint<4> =
+        Uint::from_be_hex("fedcba9876543210f0e1d2c3b4a5968778695a4b3c2d1e0f0123456789abcdef");
+    const B: Uint<4> =
+        Uint::from_be_hex("00000000000000001123581321345589ffffffffffffffff8000000000000001");
+
+    #[test]
+    fn test_conversions() {
+        let a = BoxedUint::from(A);
+        assert_eq!(a.nlimbs(), 4);
+        assert_eq!(a.to_uint::<4>(), Some(A));
+        assert_eq!(a.to_uint::<8>(), Some(A.resize()));
+        assert_eq!(a.to_uint::<3>(), None);
+        assert_eq!(BoxedUint::from(B).to_uint::<3>(), Some(B.resize()));
+
+        let hex = "fedcba9876543210f0e1d2c3b4a5968778695a4b3c2d1e0f0123456789abcdef";
+        assert_eq!(BoxedUint::from_be_hex(hex, 4), Some(a.clone()));
+        assert_eq!(BoxedUint::from_be_hex(hex, 3), None);
+        assert_eq!(BoxedUint::from_be_hex("xyz", 1), None);
+        assert_eq!(format!("{:x}", a), format!("{:x}", A));
+
+        // Equality and ordering ignore the width
+        assert_eq!(a.resize(6), a);
+        assert!(BoxedUint::from(B).resize(8) < a);
+        assert!(a.resize(8) > BoxedUint::from(B));
+        assert_eq!(a.cmp(&a.resize(6)), Ordering::Equal);
+        assert_eq!(a.resize(2).cmp(&a), A.resize::<2>().resize::<4>().cmp(&A));
+        assert_eq!(a.resize(2).to_uint::<2>(), Some(A.resize()));
+    }
+
+    #[test]
+    fn test_arithmetic_matches_uint() {
+        let (a, b) = (BoxedUint::from(A), BoxedUint::from(B));
+        assert_eq!(a.add(&b), BoxedUint::from(A.add(&B)));
+        assert_eq!(a.overflowing_add(&a).1, A.overflowing_add(&A).1);
+        assert_eq!(b.sub(&a), BoxedUint::from(B.sub(&A)));
+        assert!(b.overflowing_sub(&a).1);
+        assert_eq!(a.mul(&b), BoxedUint::from(A.mul(&B)));
+        let (lo, hi) = A.widening_mul(&B);
+        assert_eq!(
+            a.widening_mul(&b),
+            BoxedUint::from(lo.resize::<8>().add(&hi.resize::<8>().shl(256)))
+        );
+        for shift in [0, 1, 63, 64, 65, 200, 256, 300] {
+            assert_eq!(a.shl(shift), BoxedUint::from(A.shl(shift)));
+            assert_eq!(a.shr(shift), BoxedUint::from(A.shr(shift)));
+        }
+        let (q, r) = a.div_rem(&b);
+        let (uq, ur) = A.div_rem(&B);
+        assert_eq!((q, r), (BoxedUint::from(uq), BoxedUint::from(ur)));
+        assert_eq!(a.ct_div_rem(&b), (BoxedUint::from(uq), BoxedUint::from(ur)));
+        let three = BoxedUint::from_u64(3, 1);
+        assert_eq!(a.ct_rem(&three), a.rem(&three));
+
+        // Mixed widths keep the left operand's width
+        let small = BoxedUint::from_u64(3, 1);
+        assert_eq!(a.mul(&small).nlimbs(), 4);
+        assert_eq!(
+            a.div_rem(&small).1,
+            BoxedUint::from(A.rem(&Uint::from_u64(3)))
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "Left operand must have >= limbs than right operand")]
+    fn test_narrower_left_operand() {
+        let _ = BoxedUint::from_u64(1, 1).add(&BoxedUint::from_u64(1, 2));
+    }
+
+    #[test]
+    fn test_monty_matches_uint() {
+        let p = A;
+        let params = MontyParams::new(&p);
+        let boxed = BoxedMontyParams::new(&BoxedUint::from(p));
+        assert_eq!(boxed.one(), &BoxedUint::from(*params.one()));
+
+        let x = BoxedUint::from(B);
+        let xm = boxed.to_monty(&x);
+        assert_eq!(xm, BoxedUint::from(params.to_monty(&B)));
+        assert_eq!(boxed.from_monty(&xm), x);
+
+        let exp = BoxedUint::from_u64(65537, 1);
+        let expected = params.pow(&params.to_monty(&B), &Uint::<1>::from_u64(65537));
+        assert_eq!(boxed.pow(&xm, &exp), BoxedUint::from(expected));
+        assert_eq!(boxed.pow_vartime(&xm, &exp), BoxedUint::from(expected));
+        assert_eq!(boxed.add(&xm, &boxed.neg(&xm)), BoxedUint::zero(4));
+        assert_eq!(boxed.sub(&xm, &xm), BoxedUint::zero(4));
+    }
+}
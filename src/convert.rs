@@ -0,0 +1,206 @@
+//! Conversions between [`Uint`] and the arbitrary-precision integers of
+//! [`num-bigint`](https://docs.rs/num-bigint), [`rug`](https://docs.rs/rug) and
+//! [`malachite`](https://docs.rs/malachite), each behind the feature of the same name.
+//!
+//! Converting a `Uint` always succeeds. Converting back fails with [`TryFromBigIntError`] when the
+//! value is negative or needs more than `LIMBS` limbs.
+
+use core::fmt;
+
+use crate::uint::Uint;
+
+/// The error returned when an arbitrary-precision integer does not fit in a [`Uint`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TryFromBigIntError;
+
+impl fmt::Display for TryFromBigIntError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("integer out of range for the target Uint")
+    }
+}
+
+impl core::error::Error for TryFromBigIntError {}
+
+/// Collects little-endian limbs, failing if a nonzero limb lands past `LIMBS`.
+fn from_limbs_le<const LIMBS: usize>(
+    limbs: impl IntoIterator<Item = u64>,
+) -> Result<Uint<LIMBS>, TryFromBigIntError> {
+    let mut out = Uint::ZERO;
+    for (i, limb) in limbs.into_iter().enumerate() {
+        match out.limbs.get_mut(i) {
+            Some(slot) => *slot = limb,
+            None if limb == 0 => {}
+            None => return Err(TryFromBigIntError),
+        }
+    }
+    Ok(out)
+}
+
+#[cfg(feature = "num-bigint")]
+mod num_bigint_impls {
+    use num_bigint::BigUint;
+
+    use super::{from_limbs_le, TryFromBigIntError};
+    use crate::uint::Uint;
+
+    impl<const LIMBS: usize> From<Uint<LIMBS>> for BigUint {
+        fn from(value: Uint<LIMBS>) -> Self {
+            BigUint::new(
+                value
+                    .limbs
+                    .iter()
+                    .flat_map(|&limb| [limb as u32, (limb >> 32) as u32])
+                    .collect(),
+            )
+        }
+    }
+
+    impl<const LIMBS: usize> TryFrom<&BigUint> for Uint<LIMBS> {
+        type Error = TryFromBigIntError;
+
+        fn try_from(value: &BigUint) -> Result<Self, TryFromBigIntError> {
+            from_limbs_le(value.iter_u64_digits())
+        }
+    }
+
+    impl<const LIMBS: usize> TryFrom<BigUint> for Uint<LIMBS> {
+        type Error = TryFromBigIntError;
+
+        fn try_from(value: BigUint) -> Result<Self, TryFromBigIntError> {
+            Self::try_from(&value)
+        }
+    }
+}
+
+#[cfg(feature = "rug")]
+mod rug_impls {
+    use core::cmp::Ordering;
+
+    use rug::{integer::Order, Integer};
+
+    use super::TryFromBigIntError;
+    use crate::uint::Uint;
+
+    impl<const LIMBS: usize> From<Uint<LIMBS>> for Integer {
+        fn from(value: Uint<LIMBS>) -> Self {
+            Integer::from_digits(&value.limbs, Order::Lsf)
+        }
+    }
+
+    impl<const LIMBS: usize> TryFrom<&Integer> for Uint<LIMBS> {
+        type Error = TryFromBigIntError;
+
+        fn try_from(value: &Integer) -> Result<Self, TryFromBigIntError> {
+            if value.cmp0() == Ordering::Less || value.significant_digits::<u64>() > LIMBS {
+                return Err(TryFromBigIntError);
+            }
+            let mut out = Uint::ZERO;
+            value.write_digits(&mut out.limbs, Order::Lsf);
+            Ok(out)
+        }
+    }
+
+    impl<const LIMBS: usize> TryFrom<Integer> for Uint<LIMBS> {
+        type Error = TryFromBigIntError;
+
+        fn try_from(value: Integer) -> Result<Self, TryFromBigIntError> {
+            Self::try_from(&value)
+        }
+    }
+}
+
+#[cfg(feature = "malachite")]
+mod malachite_impls {
+    use malachite::Natural;
+
+    use super::{from_limbs_le, TryFromBigIntError};
+    use crate::uint::Uint;
+
+    impl<const LIMBS: usize> From<Uint<LIMBS>> for Natural {
+        fn from(value: Uint<LIMBS>) -> Self {
+            Natural::from_limbs_asc(&value.limbs)
+        }
+    }
+
+    impl<const LIMBS: usize> TryFrom<&Natural> for Uint<LIMBS> {
+        type Error = TryFromBigIntError;
+
+        fn try_from(value: &Natural) -> Result<Self, TryFromBigIntError> {
+            from_limbs_le(value.limbs())
+        }
+    }
+
+    impl<const LIMBS: usize> TryFrom<Natural> for Uint<LIMBS> {
+        type Error = TryFromBigIntError;
+
+        fn try_from(value: Natural) -> Result<Self, TryFromBigIntError> {
+            Self::try_from(&value)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    type U = Uint<2>;
+
+    const X: &str = "123456789abcdef0fedcba9876543210";
+
+    #[test]
+    #[cfg(feature = "num-bigint")]
+    fn test_num_bigint_round_trip() {
+        use num_bigint::BigUint;
+
+        let x = U::from_be_hex(X);
+        let big = BigUint::from(x);
+        assert_eq!(big, BigUint::parse_bytes(X.as_bytes(), 16).unwrap());
+        assert_eq!(U::try_from(&big), Ok(x));
+        assert_eq!(BigUint::from(U::ZERO), BigUint::default());
+        assert_eq!(U::try_from(BigUint::default()), Ok(U::ZERO));
+        assert_eq!(
+            U::try_from(BigUint::from(1u8) << 128),
+            Err(TryFromBigIntError)
+        );
+        assert_eq!(
+            Uint::<3>::try_from(BigUint::from(1u8) << 128),
+            Ok(Uint::ONE.shl(128))
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "rug")]
+    fn test_rug_round_trip() {
+        use rug::Integer;
+
+        let x = U::from_be_hex(X);
+        let big = Integer::from(x);
+        assert_eq!(big, Integer::from_str_radix(X, 16).unwrap());
+        assert_eq!(U::try_from(&big), Ok(x));
+        assert_eq!(U::try_from(Integer::new()), Ok(U::ZERO));
+        assert_eq!(U::try_from(Integer::from(-1)), Err(TryFromBigIntError));
+        assert_eq!(
+            U::try_from(Integer::from(1) << 128),
+            Err(TryFromBigIntError)
+        );
+        assert_eq!(
+            Uint::<3>::try_from(Integer::from(1) << 128),
+            Ok(Uint::ONE.shl(128))
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "malachite")]
+    fn test_malachite_round_trip() {
+        use malachite::Natural;
+
+        let x = U::from_be_hex(X);
+        let big = Natural::from(x);
+        assert_eq!(big.to_string(), "24197857203266734881846307747534221840");
+        assert_eq!(U::try_from(&big), Ok(x));
+        assert_eq!(U::try_from(Natural::from(0u32)), Ok(U::ZERO));
+        let two_128 = Natural::from(1u32) << 128u32;
+        assert_eq!(U::try_from(&two_128), Err(TryFromBigIntError));
+        assert_eq!(Uint::<3>::try_from(two_128), Ok(Uint::ONE.shl(128)));
+    }
+}
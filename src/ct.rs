@@ -0,0 +1,427 @@
+//! Constant-time comparisons and selection.
+//!
+//! A [`Choice`] is a secret boolean. Comparisons such as [`Uint::ct_lt`] produce one without
+//! branching on the operands, and [`Uint::conditional_select`], [`Uint::conditional_swap`] and
+//! [`Uint::conditional_negate`] consume one by masking every limb, so a sequence of these
+//! operations runs the same instructions whatever the values are.
+//!
+//! [`CtTable`] extends selection to table lookups: it reads every entry and keeps the wanted one
+//! with masks, so fixed-window exponentiation and scalar multiplication can index precomputed
+//! multiples by secret digits without the index showing up in the cache access pattern.
+//!
+//! Choices pass through an optimization barrier when they are created and before they become
+//! masks, which keeps the compiler from recognizing them as booleans and reintroducing branches.
+//! Converting a `Choice` to `bool` is where constant time ends; only do it for public results.
+
+use core::ops::{BitAnd, BitOr, BitXor, Not};
+
+use crate::{
+    limb::{adc, is_nonzero, lt, mask, select},
+    uint::Uint,
+};
+
+/// A boolean that may be secret, held as the limb value 0 or 1.
+#[derive(Debug, Clone, Copy)]
+pub struct Choice(u64);
+
+impl Choice {
+    /// The choice `false`.
+    pub const FALSE: Self = Self(0);
+
+    /// The choice `true`.
+    pub const TRUE: Self = Self(1);
+
+    /// Creates a choice from a bit that must be 0 or 1.
+    pub const fn from_bit(bit: u64) -> Self {
+        debug_assert!(bit <= 1, "choice bit must be 0 or 1");
+        Self(core::hint::black_box(bit))
+    }
+
+    /// Returns the choice as the bit 0 or 1.
+    pub const fn to_bit(self) -> u64 {
+        self.0
+    }
+
+    /// Returns `u64::MAX` for `true` and `0` for `false`.
+    pub const fn to_mask(self) -> u64 {
+        mask(self.0)
+    }
+
+    /// Logical and.
+    pub const fn and(self, other: Self) -> Self {
+        Self(self.0 & other.0)
+    }
+
+    /// Logical or.
+    pub const fn or(self, other: Self) -> Self {
+        Self(self.0 | other.0)
+    }
+
+    /// Logical exclusive or.
+    pub const fn xor(self, other: Self) -> Self {
+        Self(self.0 ^ other.0)
+    }
+
+    /// Logical negation.
+    pub const fn not(self) -> Self {
+        Self(self.0 ^ 1)
+    }
+
+    /// Returns `a` when the choice is false and `b` when it is true, in constant time.
+    pub const fn select_u64(self, a: u64, b: u64) -> u64 {
+        select(a, b, self.0)
+    }
+}
+
+impl From<bool> for Choice {
+    fn from(value: bool) -> Self {
+        Self::from_bit(value as u64)
+    }
+}
+
+impl From<Choice> for bool {
+    fn from(choice: Choice) -> Self {
+        choice.0 == 1
+    }
+}
+
+impl BitAnd for Choice {
+    type Output = Self;
+
+    fn bitand(self, rhs: Self) -> Self {
+        self.and(rhs)
+    }
+}
+
+impl BitOr for Choice {
+    type Output = Self;
+
+    fn bitor(self, rhs: Self) -> Self {
+        self.or(rhs)
+    }
+}
+
+impl BitXor for Choice {
+    type Output = Self;
+
+    fn bitxor(self, rhs: Self) -> Self {
+        self.xor(rhs)
+    }
+}
+
+impl Not for Choice {
+    type Output = Self;
+
+    fn not(self) -> Self {
+        Choice::not(self)
+    }
+}
+
+/// Types that can be selected between in constant time.
+pub trait ConditionallySelectable: Clone {
+    /// Returns `a` when `choice` is false and `b` when it is true, in constant time.
+    fn conditional_select(a: &Self, b: &Self, choice: Choice) -> Self;
+}
+
+impl<const LIMBS: usize> ConditionallySelectable for Uint<LIMBS> {
+    fn conditional_select(a: &Self, b: &Self, choice: Choice) -> Self {
+        Self::select(a, b, choice.0)
+    }
+}
+
+/// Bits per digit in fixed-window algorithms.
+pub(crate) const WINDOW_BITS: usize = 4;
+
+/// Entries in a fixed-window table: every digit value `0..2^WINDOW_BITS`.
+pub(crate) const WINDOW_SIZE: usize = 1 << WINDOW_BITS;
+
+/// A table of `N` precomputed values read with a secret index.
+///
+/// [`CtTable::lookup`] touches every entry and masks all but the requested one away, so its
+/// running time and memory accesses are the same for every index. That costs `N` selections per
+/// lookup instead of one load, which is why the tables in windowed algorithms stay small.
+#[derive(Debug, Clone)]
+pub struct CtTable<T, const N: usize> {
+    entries: [T; N],
+}
+
+impl<T: ConditionallySelectable, const N: usize> CtTable<T, N> {
+    /// Creates a table from its entries.
+    pub const fn new(entries: [T; N]) -> Self {
+        Self { entries }
+    }
+
+    /// Gives mutable access to the entries, so large tables can be filled in place.
+    pub(crate) fn entries_mut(&mut self) -> &mut [T; N] {
+        &mut self.entries
+    }
+
+    /// Returns the entry at `index` in constant time. `index` must be below `N`.
+    pub fn lookup(&self, index: u64) -> T {
+        debug_assert!(index < N as u64, "table index out of range");
+        let mut acc = self.entries[0].clone();
+        for (i, entry) in self.entries.iter().enumerate().skip(1) {
+            let hit = Choice::from_bit(is_nonzero(i as u64 ^ index) ^ 1);
+            acc = T::conditional_select(&acc, entry, hit);
+        }
+        acc
+    }
+}
+
+/// Combines `base` with itself `exp` times in time that depends only on `S`.
+///
+/// `op` is the group operation with neutral element `identity` and `sq` applies it to an element
+/// and itself, so this computes `base^exp` for multiplication and `exp * base` for point addition.
+/// After filling a [`CtTable`] with `base^0..base^15`, every 4-bit digit of `exp` costs four `sq`
+/// and one `op` with the entry for that digit, so the digits only ever reach the table lookup.
+pub(crate) fn fixed_window<T: ConditionallySelectable + Copy, const S: usize>(
+    identity: T,
+    base: &T,
+    exp: &Uint<S>,
+    op: impl Fn(&T, &T) -> T,
+    sq: impl Fn(&T) -> T,
+) -> T {
+    let mut table = CtTable::new([identity; WINDOW_SIZE]);
+    let powers = table.entries_mut();
+    for i in 1..WINDOW_SIZE {
+        powers[i] = op(&powers[i - 1], base);
+    }
+
+    let mut acc = identity;
+    for i in (0..Uint::<S>::BITS / WINDOW_BITS).rev() {
+        for _ in 0..WINDOW_BITS {
+            acc = sq(&acc);
+        }
+        acc = op(&acc, &table.lookup(exp.window(i)));
+    }
+    acc
+}
+
+impl<const LIMBS: usize> Uint<LIMBS> {
+    /// Returns digit `index` in base `2^WINDOW_BITS`, counting from the least significant.
+    pub(crate) const fn window(&self, index: usize) -> u64 {
+        let per_limb = 64 / WINDOW_BITS;
+        let shift = (index % per_limb) * WINDOW_BITS;
+        (self.limbs[index / per_limb] >> shift) & (WINDOW_SIZE as u64 - 1)
+    }
+
+    /// Returns whether the value is zero, in constant time.
+    pub const fn ct_is_zero(&self) -> Choice {
+        let mut acc = 0;
+        let mut i = 0;
+        while i < LIMBS {
+            acc |= self.limbs[i];
+            i += 1;
+        }
+        Choice::from_bit(is_nonzero(acc) ^ 1)
+    }
+
+    /// Returns whether `self == other`, in constant time.
+    pub const fn ct_eq(&self, other: &Self) -> Choice {
+        let mut acc = 0;
+        let mut i = 0;
+        while i < LIMBS {
+            acc |= self.limbs[i] ^ other.limbs[i];
+            i += 1;
+        }
+        Choice::from_bit(is_nonzero(acc) ^ 1)
+    }
+
+    /// Returns whether `self != other`, in constant time.
+    pub const fn ct_ne(&self, other: &Self) -> Choice {
+        self.ct_eq(other).not()
+    }
+
+    /// Returns whether `self < other`, in constant time.
+    pub const fn ct_lt(&self, other: &Self) -> Choice {
+        Choice::from_bit(lt(&self.limbs, &other.limbs))
+    }
+
+    /// Returns whether `self > other`, in constant time.
+    pub const fn ct_gt(&self, other: &Self) -> Choice {
+        other.ct_lt(self)
+    }
+
+    /// Returns `a` when `choice` is false and `b` when it is true, in constant time.
+    pub const fn conditional_select(a: &Self, b: &Self, choice: Choice) -> Self {
+        Self::select(a, b, choice.0)
+    }
+
+    /// Swaps `a` and `b` when `choice` is true and leaves them in place otherwise, in constant
+    /// time.
+    pub const fn conditional_swap(a: &mut Self, b: &mut Self, choice: Choice) {
+        let m = choice.to_mask();
+        let mut i = 0;
+        while i < LIMBS {
+            let t = m & (a.limbs[i] ^ b.limbs[i]);
+            a.limbs[i] ^= t;
+            b.limbs[i] ^= t;
+            i += 1;
+        }
+    }
+
+    /// Replaces `self` with its wrapping negation `2^(LIMBS * 64) - self` when `choice` is true,
+    /// in constant time.
+    pub const fn conditional_negate(&mut self, choice: Choice) {
+        // -x = !x + 1: flip every bit under the mask and add the choice bit itself.
+        let m = choice.to_mask();
+        let mut carry = choice.0;
+        let mut i = 0;
+        while i < LIMBS {
+            (self.limbs[i], carry) = adc(self.limbs[i] ^ m, 0, carry);
+            i += 1;
+        }
+    }
+
+    /// Returns the bit at position `index` as a choice. The index is public; the bit need not be.
+    pub const fn ct_bit(&self, index: usize) -> Choice {
+        let limb = if index < Self::BITS {
+            self.limbs[index / 64]
+        } else {
+            0
+        };
+        Choice::from_bit((limb >> (index % 64)) & 1)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rand::TestRng;
+
+    fn samples() -> Vec<Uint<3>> {
+        let mut rng = TestRng(7);
+        let mut values = vec![Uint::ZERO, Uint::ONE, Uint::MAX, Uint::from_u64(u64::MAX)];
+        values.push(Uint { limbs: [0, 0, 1] });
+        values.push(Uint {
+            limbs: [u64::MAX, u64::MAX, 0],
+        });
+        for _ in 0..16 {
+            values.push(Uint::random(&mut rng));
+        }
+        values
+    }
+
+    #[test]
+    fn test_choice_logic() {
+        for a in [false, true] {
+            assert_eq!(bool::from(!Choice::from(a)), !a);
+            for b in [false, true] {
+                let (x, y) = (Choice::from(a), Choice::from(b));
+                assert_eq!(bool::from(x & y), a & b);
+                assert_eq!(bool::from(x | y), a | b);
+                assert_eq!(bool::from(x ^ y), a ^ b);
+            }
+        }
+        assert_eq!(Choice::TRUE.to_mask(), u64::MAX);
+        assert_eq!(Choice::FALSE.to_mask(), 0);
+        assert_eq!(Choice::TRUE.select_u64(3, 5), 5);
+        assert_eq!(Choice::FALSE.select_u64(3, 5), 3);
+    }
+
+    #[test]
+    fn test_comparisons_match_ord() {
+        let values = samples();
+        for a in &values {
+            assert_eq!(bool::from(a.ct_is_zero()), a.is_zero());
+            for b in &values {
+                assert_eq!(bool::from(a.ct_eq(b)), a == b);
+                assert_eq!(bool::from(a.ct_ne(b)), a != b);
+                assert_eq!(bool::from(a.ct_lt(b)), a < b);
+                assert_eq!(bool::from(a.ct_gt(b)), a > b);
+            }
+        }
+        // Limbs that differ only in the top bit, where a naive signed comparison goes wrong.
+        let (a, b) = (Uint::<1>::from_u64(1 << 63), Uint::<1>::from_u64(1));
+        assert!(bool::from(a.ct_gt(&b)));
+        assert!(bool::from(b.ct_lt(&a)));
+    }
+
+    #[test]
+    fn test_conditional_select_and_swap() {
+        let values = samples();
+        for pair in values.windows(2) {
+            let (a, b) = (pair[0], pair[1]);
+            assert_eq!(Uint::conditional_select(&a, &b, Choice::FALSE), a);
+            assert_eq!(Uint::conditional_select(&a, &b, Choice::TRUE), b);
+
+            let (mut x, mut y) = (a, b);
+            Uint::conditional_swap(&mut x, &mut y, Choice::FALSE);
+            assert_eq!((x, y), (a, b));
+            Uint::conditional_swap(&mut x, &mut y, Choice::TRUE);
+            assert_eq!((x, y), (b, a));
+        }
+    }
+
+    #[test]
+    fn test_conditional_negate() {
+        for a in samples() {
+            let mut x = a;
+            x.conditional_negate(Choice::FALSE);
+            assert_eq!(x, a);
+            x.conditional_negate(Choice::TRUE);
+            assert_eq!(x.add(&a), Uint::ZERO);
+            x.conditional_negate(Choice::TRUE);
+            assert_eq!(x, a);
+        }
+    }
+
+    #[test]
+    fn test_table_lookup() {
+        let table = CtTable::new(core::array::from_fn::<_, 16, _>(|i| {
+            Uint::<2>::from_u64(i as u64 * 0x1111).shl(i)
+        }));
+        for i in 0..16 {
+            assert_eq!(table.lookup(i), Uint::from_u64(i * 0x1111).shl(i as usize));
+        }
+    }
+
+    #[test]
+    fn test_window() {
+        let x = Uint::<2>::from_be_hex("fedcba98765432100123456789abcdef");
+        let digits: Vec<u64> = (0..32).map(|i| x.window(i)).collect();
+        let expected: Vec<u64> = "fedcba98765432100123456789abcdef"
+            .chars()
+            .rev()
+            .map(|c| c.to_digit(16).unwrap() as u64)
+            .collect();
+        assert_eq!(digits, expected);
+    }
+
+    #[test]
+    fn test_fixed_window() {
+        let base = Uint::<2>::from_be_hex("0123456789abcdeffedcba9876543210");
+        for exp in [
+            Uint::<2>::ZERO,
+            Uint::ONE,
+            Uint::from_u64(0xf0),
+            Uint::from_be_hex("8000000000000000000000000000000f"),
+            Uint::MAX,
+        ] {
+            let multiple = fixed_window(Uint::ZERO, &base, &exp, |a, b| a.add(b), |a| a.add(a));
+            assert_eq!(multiple, base.mul(&exp));
+
+            let power = fixed_window(Uint::ONE, &base, &exp, |a, b| a.mul(b), |a| a.mul(a));
+            let mut expected = Uint::<2>::ONE;
+            for i in (0..exp.bits()).rev() {
+                expected = expected.mul(&expected);
+                if exp.bit(i) {
+                    expected = expected.mul(&base);
+                }
+            }
+            assert_eq!(power, expected);
+        }
+    }
+
+    #[test]
+    fn test_ct_bit() {
+        let x = Uint::<2> {
+            limbs: [0b101, 1 << 63],
+        };
+        assert!(bool::from(x.ct_bit(0)));
+        assert!(!bool::from(x.ct_bit(1)));
+        assert!(bool::from(x.ct_bit(2)));
+        assert!(bool::from(x.ct_bit(127)));
+        assert!(!bool::from(x.ct_bit(128)));
+    }
+}
@@ -0,0 +1,65 @@
+//! The group G1 on `E: y^2 = x^3 + 4` over the base field.
+
+use super::{fe, FieldElement, Scalar, ScalarParams};
+use crate::{field::FieldParams, uint::Uint};
+
+/// The coefficient `b = 4`.
+const B: FieldElement = FieldElement::from_uint_const(&Uint::from_u64(4));
+
+define_group!(
+    /// A G1 point in affine coordinates, or the point at infinity.
+    G1Affine,
+    /// A G1 point in homogeneous projective coordinates `(X : Y : Z)`.
+    G1Projective,
+    FieldElement,
+    b: B,
+    b3: B.double().add(&B),
+    generator: (
+        fe("17f1d3a73197d7942695638c4fa9ac0fc3688c4f9774b905a14e3a3f171bac586c55e83ff97a1aeffb3af00adb22c6bb"),
+        fe("08b3f481e3aaa0f1a09e30ed741d8ae4fcf5e095d5d00af600db18cb2c04b3edd03cc744a2888ae40caa232946c5e7e1"),
+    ),
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_generator() {
+        assert!(G1Affine::GENERATOR.is_on_curve());
+        assert!(G1Affine::GENERATOR.is_torsion_free());
+        assert!(G1Projective::GENERATOR
+            .mul(&ScalarParams::MODULUS)
+            .is_identity());
+        assert!(G1Affine::IDENTITY.is_torsion_free());
+    }
+
+    #[test]
+    fn test_group_laws() {
+        let g = G1Projective::GENERATOR;
+        let id = G1Projective::IDENTITY;
+        assert_eq!(g + id, g);
+        assert_eq!(g - g, id);
+        assert_eq!(g.double(), g + g);
+        assert_eq!(g.double() + g, g.mul(&Uint::<1>::from_u64(3)));
+        assert_eq!(id.double(), id);
+
+        let a = Scalar::from_u64(0x1234_5678_9abc_def0);
+        let b = Scalar::from_u64(0x0fed_cba9_8765_4321);
+        assert_eq!(&g * &a + &g * &b, &g * &a.add(&b));
+        assert_eq!((&g * &a).mul_vartime(&b.to_uint()), &g * &a.mul(&b));
+        assert_eq!(G1Affine::from(g.neg()), G1Affine::GENERATOR.neg());
+    }
+
+    #[test]
+    fn test_rejects_points_outside_subgroup() {
+        // (0, 2) is on E but has order 3, which does not divide r.
+        let point = G1Affine::new(FieldElement::ZERO, FieldElement::from_u64(2)).unwrap();
+        assert!(!point.is_torsion_free());
+        assert!(point
+            .to_projective()
+            .mul(&Uint::<1>::from_u64(3))
+            .is_identity());
+        assert!(G1Affine::new(FieldElement::ONE, FieldElement::ONE).is_none());
+    }
+}
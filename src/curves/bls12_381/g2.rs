@@ -0,0 +1,76 @@
+//! The group G2 on the sextic twist `E': y^2 = x^3 + 4(u + 1)` over [`Fp2`].
+
+use super::{fe, FieldElement, Fp2, Scalar, ScalarParams};
+use crate::{field::FieldParams, uint::Uint};
+
+/// The coefficient `b = 4(u + 1)`.
+const B: Fp2 = {
+    let four = FieldElement::from_uint_const(&Uint::from_u64(4));
+    Fp2::new(four, four)
+};
+
+define_group!(
+    /// A G2 point in affine coordinates, or the point at infinity.
+    G2Affine,
+    /// A G2 point in homogeneous projective coordinates `(X : Y : Z)`.
+    G2Projective,
+    Fp2,
+    b: B,
+    b3: B.double().add(&B),
+    generator: (
+        Fp2::new(
+            fe("024aa2b2f08f0a91260805272dc51051c6e47ad4fa403b02b4510b647ae3d1770bac0326a805bbefd48056c8c121bdb8"),
+            fe("13e02b6052719f607dacd3a088274f65596bd0d09920b61ab5da61bbdc7f5049334cf11213945d57e5ac7d055d042b7e"),
+        ),
+        Fp2::new(
+            fe("0ce5d527727d6e118cc9cdc6da2e351aadfd9baa8cbdd3a76d429a695160d12c923ac9cc3baca289e193548608b82801"),
+            fe("0606c4a02ea734cc32acd2b02bc28b99cb3e287e85a763af267492ab572e99ab3f370d275cec1da1aaa9075ff05f79be"),
+        ),
+    ),
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_generator() {
+        assert!(G2Affine::GENERATOR.is_on_curve());
+        assert!(G2Affine::GENERATOR.is_torsion_free());
+        assert!(G2Projective::GENERATOR
+            .mul(&ScalarParams::MODULUS)
+            .is_identity());
+    }
+
+    #[test]
+    fn test_group_laws() {
+        let g = G2Projective::GENERATOR;
+        let id = G2Projective::IDENTITY;
+        assert_eq!(g + id, g);
+        assert_eq!(g - g, id);
+        assert_eq!(g.double(), g + g);
+        assert!(g.double().is_on_curve());
+
+        let a = Scalar::from_u64(0x1234_5678_9abc_def0);
+        let b = Scalar::from_u64(0x0fed_cba9_8765_4321);
+        assert_eq!(&g * &a + &g * &b, &g * &a.add(&b));
+        assert_eq!((&g * &a).mul_vartime(&b.to_uint()), &g * &a.mul(&b));
+    }
+
+    #[test]
+    fn test_rejects_points_outside_subgroup() {
+        // Walk x = 0, 1, 2, ... until it lands on E'; such points almost never lie in G2, since
+        // the twist's cofactor is far larger than r.
+        let mut x = FieldElement::ZERO;
+        let point = loop {
+            let candidate = Fp2::new(x, FieldElement::ONE);
+            let rhs = candidate.square().mul(&candidate).add(&B);
+            if let Some(point) = rhs.sqrt().and_then(|y| G2Affine::new(candidate, y)) {
+                break point;
+            }
+            x = x.add(&FieldElement::ONE);
+        };
+        assert!(point.is_on_curve());
+        assert!(!point.is_torsion_free());
+    }
+}
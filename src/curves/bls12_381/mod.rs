@@ -0,0 +1,469 @@
+//! The pairing-friendly curve BLS12-381.
+//!
+//! [`G1Affine`]/[`G1Projective`] live on `E: y^2 = x^3 + 4` over the 381-bit base field and
+//! [`G2Affine`]/[`G2Projective`] on the sextic twist `E': y^2 = x^3 + 4(u + 1)` over [`Fp2`], using
+//! the generic towers of [`crate::extension`]. Both groups use the complete `a = 0` formulas of
+//! Renes, Costello and Batina. The complete formulas need odd order, which both curves have, but
+//! only the subgroup of prime order `r` is useful; points from untrusted sources should be checked
+//! with `is_torsion_free`.
+//!
+//! [`pairing`] computes the optimal ate pairing into [`Gt`], the order-`r` subgroup of
+//! [`Fp12`]`^*`, as a Miller loop followed by the final exponentiation.
+//!
+//! ```rust
+//! use nail::curves::bls12_381::{pairing, G1Affine, G1Projective, G2Affine, Scalar};
+//!
+//! let a = Scalar::from_u64(5);
+//! let p = (G1Projective::GENERATOR * &a).to_affine();
+//! assert_eq!(
+//!     pairing(&p, &G2Affine::GENERATOR),
+//!     pairing(&G1Affine::GENERATOR, &G2Affine::GENERATOR).pow_vartime(&a.to_uint())
+//! );
+//! ```
+
+use crate::{
+    extension::{self, Fp12Params, Fp2Params, Fp6Params},
+    field::{FieldParams, Fp},
+    uint::Uint,
+};
+
+/// The base field, `p = (x - 1)^2 (x^4 - x^2 + 1) / 3 + x` for `x = -0xd201000000010000`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BaseParams;
+
+impl FieldParams<6> for BaseParams {
+    const MODULUS: Uint<6> = Uint::from_be_hex(
+        "1a0111ea397fe69a4b1ba7b6434bacd764774b84f38512bf6730d2a0f6b0f6241eabfffeb153ffffb9feffffffffaaab",
+    );
+}
+
+/// The scalar field, modulo the subgroup order `r = x^4 - x^2 + 1`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ScalarParams;
+
+impl FieldParams<4> for ScalarParams {
+    const MODULUS: Uint<4> =
+        Uint::from_be_hex("73eda753299d7d483339d80809a1d80553bda402fffe5bfeffffffff00000001");
+}
+
+/// An element of the base field.
+pub type FieldElement = Fp<6, BaseParams>;
+
+/// An integer modulo the subgroup order `r`.
+pub type Scalar = Fp<4, ScalarParams>;
+
+/// The quadratic extension `Fp[u]/(u^2 + 1)`.
+pub type Fp2 = extension::Fp2<6, BaseParams>;
+
+/// The cubic extension `Fp2[v]/(v^3 - (u + 1))`.
+pub type Fp6 = extension::Fp6<6, BaseParams>;
+
+/// The quadratic extension `Fp6[w]/(w^2 - v)`, the codomain of the pairing.
+pub type Fp12 = extension::Fp12<6, BaseParams>;
+
+impl Fp2Params<6> for BaseParams {
+    const FP2_NONRESIDUE: FieldElement = FieldElement::ONE.neg();
+}
+
+impl Fp6Params<6> for BaseParams {
+    const FP6_NONRESIDUE: Fp2 = Fp2::new(FieldElement::ONE, FieldElement::ONE);
+    const FROBENIUS_V: Fp2 = Fp2::new(
+        FieldElement::ZERO,
+        fe("1a0111ea397fe699ec02408663d4de85aa0d857d89759ad4897d29650fb85f9b409427eb4f49fffd8bfd00000000aaac"),
+    );
+    const FROBENIUS_V2: Fp2 = Fp2::new(
+        fe("1a0111ea397fe699ec02408663d4de85aa0d857d89759ad4897d29650fb85f9b409427eb4f49fffd8bfd00000000aaad"),
+        FieldElement::ZERO,
+    );
+}
+
+impl Fp12Params<6> for BaseParams {
+    const FROBENIUS_W: Fp2 = Fp2::new(
+        fe("1904d3bf02bb0667c231beb4202c0d1f0fd603fd3cbd5f4f7b2443d784bab9c4f67ea53d63e7813d8d0775ed92235fb8"),
+        fe("00fc3e2b36c4e03288e9e902231f9fb854a14787b6c7b36fec0c8ec971f63c5f282d5ac14d6c7ec22cf78a126ddc4af3"),
+    );
+}
+
+/// Parses a base field constant from big-endian hex.
+const fn fe(hex: &str) -> FieldElement {
+    Fp::from_uint_const(&Uint::from_be_hex(hex))
+}
+
+/// Defines affine and projective point types on a curve `y^2 = x^3 + b` whose coordinates live
+/// in `$field`, which must provide the same arithmetic as [`Fp`].
+macro_rules! define_group {
+    (
+        $(#[$affine_meta:meta])* $affine:ident,
+        $(#[$projective_meta:meta])* $projective:ident,
+        $field:ty, b: $b:expr, b3: $b3:expr, generator: ($gx:expr, $gy:expr $(,)?) $(,)?
+    ) => {
+        $(#[$affine_meta])*
+        #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+        pub struct $affine {
+            x: $field,
+            y: $field,
+            /// Set for the point at infinity, whose coordinates are both zero.
+            infinity: bool,
+        }
+
+        impl $affine {
+            /// The point at infinity.
+            pub const IDENTITY: Self = Self {
+                x: <$field>::ZERO,
+                y: <$field>::ZERO,
+                infinity: true,
+            };
+
+            /// The standard generator of the order-`r` subgroup.
+            pub const GENERATOR: Self = Self {
+                x: $gx,
+                y: $gy,
+                infinity: false,
+            };
+
+            /// Creates a point from affine coordinates.
+            ///
+            /// Returns `None` if `(x, y)` is not on the curve. Membership of the order-`r`
+            /// subgroup is not checked; see [`Self::is_torsion_free`].
+            pub fn new(x: $field, y: $field) -> Option<Self> {
+                let point = Self { x, y, infinity: false };
+                point.is_on_curve().then_some(point)
+            }
+
+            /// Returns the `x` coordinate, zero for the point at infinity.
+            pub fn x(&self) -> $field {
+                self.x
+            }
+
+            /// Returns the `y` coordinate, zero for the point at infinity.
+            pub fn y(&self) -> $field {
+                self.y
+            }
+
+            /// Returns `true` for the point at infinity.
+            pub fn is_identity(&self) -> bool {
+                self.infinity
+            }
+
+            /// Returns `true` if the point is the identity or satisfies the curve equation.
+            pub fn is_on_curve(&self) -> bool {
+                self.infinity || self.y.square() == self.x.square().mul(&self.x).add(&$b)
+            }
+
+            /// Returns `true` if the point lies in the subgroup of order `r`.
+            pub fn is_torsion_free(&self) -> bool {
+                self.to_projective().is_torsion_free()
+            }
+
+            /// Returns `-self`.
+            pub fn neg(&self) -> Self {
+                Self {
+                    x: self.x,
+                    y: self.y.neg(),
+                    infinity: self.infinity,
+                }
+            }
+
+            /// Converts to projective coordinates.
+            pub fn to_projective(&self) -> $projective {
+                $projective::from_affine(self)
+            }
+        }
+
+        impl Default for $affine {
+            fn default() -> Self {
+                Self::IDENTITY
+            }
+        }
+
+        $(#[$projective_meta])*
+        ///
+        /// The identity is any point with `Z = 0`; equality compares the represented points.
+        #[derive(Debug, Clone, Copy)]
+        pub struct $projective {
+            x: $field,
+            y: $field,
+            z: $field,
+        }
+
+        impl $projective {
+            /// The point at infinity, `(0 : 1 : 0)`.
+            pub const IDENTITY: Self = Self {
+                x: <$field>::ZERO,
+                y: <$field>::ONE,
+                z: <$field>::ZERO,
+            };
+
+            /// The standard generator of the order-`r` subgroup.
+            pub const GENERATOR: Self = Self {
+                x: $gx,
+                y: $gy,
+                z: <$field>::ONE,
+            };
+
+            const B3: $field = $b3;
+
+            /// Converts an affine point, mapping the point at infinity to the identity.
+            pub fn from_affine(point: &$affine) -> Self {
+                let infinity = point.infinity as u64;
+                Self {
+                    x: point.x,
+                    y: <$field>::select(&point.y, &<$field>::ONE, infinity),
+                    z: <$field>::select(&<$field>::ONE, &<$field>::ZERO, infinity),
+                }
+            }
+
+            /// Converts to affine coordinates with one field inversion.
+            pub fn to_affine(&self) -> $affine {
+                match self.z.invert() {
+                    Some(z_inv) => $affine {
+                        x: self.x.mul(&z_inv),
+                        y: self.y.mul(&z_inv),
+                        infinity: false,
+                    },
+                    None => $affine::IDENTITY,
+                }
+            }
+
+            /// Returns `true` for the point at infinity.
+            pub fn is_identity(&self) -> bool {
+                self.z.is_zero()
+            }
+
+            /// Returns `true` if the point satisfies `Y^2 Z = X^3 + b Z^3`.
+            pub fn is_on_curve(&self) -> bool {
+                let lhs = self.y.square().mul(&self.z);
+                let rhs = self
+                    .x
+                    .square()
+                    .mul(&self.x)
+                    .add(&$b.mul(&self.z.square()).mul(&self.z));
+                lhs == rhs
+            }
+
+            /// Returns `true` if the point lies in the subgroup of order `r`, by checking
+            /// `r * self == O`.
+            pub fn is_torsion_free(&self) -> bool {
+                self.mul_vartime(&ScalarParams::MODULUS).is_identity()
+            }
+
+            /// Returns `a` when `choice` is 0 and `b` when `choice` is 1, without branching.
+            fn select(a: &Self, b: &Self, choice: u64) -> Self {
+                Self {
+                    x: <$field>::select(&a.x, &b.x, choice),
+                    y: <$field>::select(&a.y, &b.y, choice),
+                    z: <$field>::select(&a.z, &b.z, choice),
+                }
+            }
+
+            /// Returns `self + other` using Algorithm 7 of Renes–Costello–Batina.
+            pub fn add(&self, other: &Self) -> Self {
+                let b3 = &Self::B3;
+                let (x1, y1, z1) = (&self.x, &self.y, &self.z);
+                let (x2, y2, z2) = (&other.x, &other.y, &other.z);
+
+                let t0 = x1.mul(x2);
+                let t1 = y1.mul(y2);
+                let t2 = z1.mul(z2);
+                let t3 = x1.add(y1).mul(&x2.add(y2)).sub(&t0.add(&t1));
+                let t4 = y1.add(z1).mul(&y2.add(z2)).sub(&t1.add(&t2));
+                let y3 = x1.add(z1).mul(&x2.add(z2)).sub(&t0.add(&t2));
+
+                let t0 = t0.double().add(&t0);
+                let t2 = b3.mul(&t2);
+                let z3 = t1.add(&t2);
+                let t1 = t1.sub(&t2);
+                let y3 = b3.mul(&y3);
+
+                Self {
+                    x: t3.mul(&t1).sub(&t4.mul(&y3)),
+                    y: t1.mul(&z3).add(&y3.mul(&t0)),
+                    z: z3.mul(&t4).add(&t0.mul(&t3)),
+                }
+            }
+
+            /// Returns `2 * self` using Algorithm 9 of Renes–Costello–Batina.
+            pub fn double(&self) -> Self {
+                let b3 = &Self::B3;
+                let (x, y, z) = (&self.x, &self.y, &self.z);
+
+                let t0 = y.square();
+                let z3 = t0.double().double().double();
+                let t1 = y.mul(z);
+                let t2 = b3.mul(&z.square());
+                let x3 = t2.mul(&z3);
+                let y3 = t0.add(&t2);
+                let z3 = t1.mul(&z3);
+                let t2 = t2.double().add(&t2);
+                let t0 = t0.sub(&t2);
+                let y3 = t0.mul(&y3).add(&x3);
+
+                Self {
+                    x: t0.mul(&x.mul(y)).double(),
+                    y: y3,
+                    z: z3,
+                }
+            }
+
+            /// Returns `-self`.
+            pub fn neg(&self) -> Self {
+                Self {
+                    x: self.x,
+                    y: self.y.neg(),
+                    z: self.z,
+                }
+            }
+
+            /// Returns `self - other`.
+            pub fn sub(&self, other: &Self) -> Self {
+                self.add(&other.neg())
+            }
+
+            /// Multiplies the point by `k` with a fixed 4-bit window, in time that depends only
+            /// on `S`.
+            pub fn mul<const S: usize>(&self, k: &Uint<S>) -> Self {
+                crate::ct::fixed_window(Self::IDENTITY, self, k, Self::add, Self::double)
+            }
+
+            /// Multiplies the point by a public `k`, skipping leading zero bits and absent
+            /// additions.
+            pub fn mul_vartime<const S: usize>(&self, k: &Uint<S>) -> Self {
+                let mut acc = Self::IDENTITY;
+                for i in (0..k.bits()).rev() {
+                    acc = acc.double();
+                    if k.bit(i) {
+                        acc = acc.add(self);
+                    }
+                }
+                acc
+            }
+        }
+
+        impl PartialEq for $projective {
+            fn eq(&self, other: &Self) -> bool {
+                self.x.mul(&other.z) == other.x.mul(&self.z)
+                    && self.y.mul(&other.z) == other.y.mul(&self.z)
+            }
+        }
+
+        impl Eq for $projective {}
+
+        impl crate::ct::ConditionallySelectable for $projective {
+            fn conditional_select(a: &Self, b: &Self, choice: crate::ct::Choice) -> Self {
+                Self::select(a, b, choice.to_bit())
+            }
+        }
+
+        impl Default for $projective {
+            fn default() -> Self {
+                Self::IDENTITY
+            }
+        }
+
+        impl From<$affine> for $projective {
+            fn from(point: $affine) -> Self {
+                Self::from_affine(&point)
+            }
+        }
+
+        impl From<$projective> for $affine {
+            fn from(point: $projective) -> Self {
+                point.to_affine()
+            }
+        }
+
+        impl_group_binop!($projective, Add, add, AddAssign, add_assign);
+        impl_group_binop!($projective, Sub, sub, SubAssign, sub_assign);
+
+        impl core::ops::Neg for $projective {
+            type Output = $projective;
+
+            fn neg(self) -> $projective {
+                $projective::neg(&self)
+            }
+        }
+
+        impl core::ops::Neg for &$projective {
+            type Output = $projective;
+
+            fn neg(self) -> $projective {
+                $projective::neg(self)
+            }
+        }
+
+        impl core::ops::Mul<&Scalar> for $projective {
+            type Output = $projective;
+
+            fn mul(self, rhs: &Scalar) -> $projective {
+                $projective::mul(&self, &rhs.to_uint())
+            }
+        }
+
+        impl core::ops::Mul<&Scalar> for &$projective {
+            type Output = $projective;
+
+            fn mul(self, rhs: &Scalar) -> $projective {
+                $projective::mul(self, &rhs.to_uint())
+            }
+        }
+    };
+}
+
+/// Implements a binary operator and its assigning form for a projective point type.
+macro_rules! impl_group_binop {
+    ($point:ident, $trait:ident, $method:ident, $assign_trait:ident, $assign_method:ident) => {
+        impl core::ops::$trait<&$point> for &$point {
+            type Output = $point;
+
+            fn $method(self, rhs: &$point) -> $point {
+                $point::$method(self, rhs)
+            }
+        }
+
+        impl core::ops::$trait<$point> for &$point {
+            type Output = $point;
+
+            fn $method(self, rhs: $point) -> $point {
+                $point::$method(self, &rhs)
+            }
+        }
+
+        impl core::ops::$trait<&$point> for $point {
+            type Output = $point;
+
+            fn $method(self, rhs: &$point) -> $point {
+                $point::$method(&self, rhs)
+            }
+        }
+
+        impl core::ops::$trait<$point> for $point {
+            type Output = $point;
+
+            fn $method(self, rhs: $point) -> $point {
+                $point::$method(&self, &rhs)
+            }
+        }
+
+        impl core::ops::$assign_trait<&$point> for $point {
+            fn $assign_method(&mut self, rhs: &$point) {
+                *self = $point::$method(self, rhs);
+            }
+        }
+
+        impl core::ops::$assign_trait<$point> for $point {
+            fn $assign_method(&mut self, rhs: $point) {
+                *self = $point::$method(self, &rhs);
+            }
+        }
+    };
+}
+
+mod g1;
+mod g2;
+mod pairing;
+
+pub use self::{
+    g1::{G1Affine, G1Projective},
+    g2::{G2Affine, G2Projective},
+    pairing::{final_exponentiation, miller_loop, pairing, Gt},
+};
@@ -0,0 +1,293 @@
+//! The optimal ate pairing `e: G1 x G2 -> Gt`.
+
+use super::{Fp12, Fp2, Fp6, G1Affine, G2Affine};
+use crate::uint::Uint;
+
+/// `|x|` for the curve parameter `x = -0xd201000000010000`; the Miller loop runs over its bits.
+const X_ABS: u64 = 0xd201_0000_0001_0000;
+
+/// The hard part of the final exponentiation, `(p^4 - p^2 + 1) / r`.
+const HARD_EXPONENT: Uint<20> = Uint::from_be_hex(concat!(
+    "f686b3d807d01c0bd38c3195c899ed3cde88eeb996ca394506632528d6a9a2f230063cf081517f68f7764c28b6f8ae5a",
+    "72bce8d63cb9f827eca0ba621315b2076995003fc77a17988f8761bdc51dc2378b9039096d1b767f17fcbde783765915",
+    "c97f36c6f18212ed0b283ed237db421d160aeb6a1e79983774940996754c8c71a2629b0dea236905ce937335d5b68fa9",
+    "912aae208ccf1e516c3f438e3ba79",
+));
+
+/// An element of the target group: the subgroup of order `r` in the multiplicative group of
+/// [`Fp12`]. The group is written multiplicatively.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Gt(Fp12);
+
+impl Gt {
+    /// The identity, `1`.
+    pub const IDENTITY: Self = Self(Fp12::ONE);
+
+    /// Returns the underlying [`Fp12`] element.
+    pub fn as_fp12(&self) -> &Fp12 {
+        &self.0
+    }
+
+    /// Returns `true` for the identity.
+    pub fn is_identity(&self) -> bool {
+        self.0 == Fp12::ONE
+    }
+
+    /// Returns the group operation `self * other`.
+    pub fn mul(&self, other: &Self) -> Self {
+        Self(self.0.mul(&other.0))
+    }
+
+    /// Returns `self^2`.
+    pub fn square(&self) -> Self {
+        Self(self.0.square())
+    }
+
+    /// Returns `self^-1`. Elements of `Gt` have norm one over `Fp6`, so this is the conjugate.
+    pub fn invert(&self) -> Self {
+        Self(self.0.conjugate())
+    }
+
+    /// Raises `self` to a public exponent.
+    pub fn pow_vartime<const E: usize>(&self, exp: &Uint<E>) -> Self {
+        Self(self.0.pow_vartime(exp))
+    }
+}
+
+impl Default for Gt {
+    fn default() -> Self {
+        Self::IDENTITY
+    }
+}
+
+/// Computes `e(p, q)`.
+pub fn pairing(p: &G1Affine, q: &G2Affine) -> Gt {
+    final_exponentiation(&miller_loop(p, q))
+}
+
+/// Evaluates the Miller function `f_{x, q}` at `p`, without the final exponentiation.
+///
+/// The result is only meaningful up to factors that [`final_exponentiation`] removes, so products
+/// of several Miller loops can share one final exponentiation. Either argument being the identity
+/// yields one.
+///
+/// The loop runs in affine coordinates on the twist and spends one [`Fp2`] inversion per step;
+/// it is variable time in the points. Points of small order outside G2, which [`G2Affine::new`]
+/// accepts, do not panic, but the result then is not a pairing value.
+pub fn miller_loop(p: &G1Affine, q: &G2Affine) -> Fp12 {
+    if p.is_identity() || q.is_identity() {
+        return Fp12::ONE;
+    }
+    let q = (q.x(), q.y());
+
+    let mut f = Fp12::ONE;
+    // The running multiple of `q`, `None` once it reaches the identity.
+    let mut t = Some(q);
+    for i in (0..63).rev() {
+        f = f.square();
+        t = t.and_then(|t| tangent_step(&mut f, t, p));
+        if (X_ABS >> i) & 1 == 1 {
+            t = match t {
+                None => Some(q),
+                Some(t) if t == q => tangent_step(&mut f, t, p),
+                Some(t) => chord_step(&mut f, t, q, p),
+            };
+        }
+    }
+
+    // x is negative.
+    f.conjugate()
+}
+
+// Vertical lines evaluate to elements of Fp6, which the final exponentiation maps to one, so the
+// steps below skip them: when `2T` or `T + Q` is the identity they return `None` and leave `f`.
+
+/// Multiplies `f` by the tangent line at `t`, evaluated at `p`, and returns `2t`.
+fn tangent_step(f: &mut Fp12, (xt, yt): (Fp2, Fp2), p: &G1Affine) -> Option<(Fp2, Fp2)> {
+    // Slope 3 x_T^2 / (2 y_T); y_T is zero exactly when T has order two.
+    let inv = yt.double().invert()?;
+    let xt2 = xt.square();
+    let lambda = xt2.double().add(&xt2).mul(&inv);
+    *f = f.mul(&line(&lambda, &xt, &yt, p));
+    let x3 = lambda.square().sub(&xt.double());
+    Some((x3, lambda.mul(&xt.sub(&x3)).sub(&yt)))
+}
+
+/// Multiplies `f` by the chord through `t` and `q`, evaluated at `p`, and returns `t + q`.
+/// Requires `t != q`.
+fn chord_step(
+    f: &mut Fp12,
+    (xt, yt): (Fp2, Fp2),
+    (xq, yq): (Fp2, Fp2),
+    p: &G1Affine,
+) -> Option<(Fp2, Fp2)> {
+    // Slope (y_Q - y_T) / (x_Q - x_T); x_T = x_Q with t != q means t = -q.
+    let inv = xq.sub(&xt).invert()?;
+    let lambda = yq.sub(&yt).mul(&inv);
+    *f = f.mul(&line(&lambda, &xt, &yt, p));
+    let x3 = lambda.square().sub(&xt).sub(&xq);
+    Some((x3, lambda.mul(&xt.sub(&x3)).sub(&yt)))
+}
+
+/// Evaluates at `p` the line of twist slope `lambda` through `(xt, yt)`, mapped to `E` and
+/// scaled by `w^3`:
+/// `l = (lambda x_T - y_T) - lambda x_P v + y_P v w`.
+fn line(lambda: &Fp2, xt: &Fp2, yt: &Fp2, p: &G1Affine) -> Fp12 {
+    let c0 = Fp6::new(
+        lambda.mul(xt).sub(yt),
+        lambda.mul_by_base(&p.x()).neg(),
+        Fp2::ZERO,
+    );
+    let c1 = Fp6::new(Fp2::ZERO, Fp2::from_base(p.y()), Fp2::ZERO);
+    Fp12::new(c0, c1)
+}
+
+/// Raises a Miller loop output to `(p^12 - 1) / r`, mapping it into [`Gt`].
+///
+/// # Panics
+///
+/// Panics if `f` is zero, which [`miller_loop`] never returns.
+pub fn final_exponentiation(f: &Fp12) -> Gt {
+    // Easy part: f^((p^6 - 1)(p^2 + 1)).
+    let f = f
+        .conjugate()
+        .mul(&f.invert().expect("Miller loop output is nonzero"));
+    let f = f.frobenius_map().frobenius_map().mul(&f);
+    // Hard part: f^((p^4 - p^2 + 1) / r).
+    Gt(f.pow_vartime(&HARD_EXPONENT))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        super::{fe, G1Projective, G2Projective, Scalar, ScalarParams},
+        *,
+    };
+    use crate::field::FieldParams;
+
+    #[test]
+    fn test_known_answer() {
+        let fp2 = |c0, c1| Fp2::new(fe(c0), fe(c1));
+        let expected = Fp12::new(
+            Fp6::new(
+                fp2(
+                    "11619b45f61edfe3b47a15fac19442526ff489dcda25e59121d9931438907dfd448299a87dde3a649bdba96e84d54558",
+                    "153ce14a76a53e205ba8f275ef1137c56a566f638b52d34ba3bf3bf22f277d70f76316218c0dfd583a394b8448d2be7f",
+                ),
+                fp2(
+                    "095668fb4a02fe930ed44767834c915b283b1c6ca98c047bd4c272e9ac3f3ba6ff0b05a93e59c71fba77bce995f04692",
+                    "16deedaa683124fe7260085184d88f7d036b86f53bb5b7f1fc5e248814782065413e7d958d17960109ea006b2afdeb5f",
+                ),
+                fp2(
+                    "09c92cf02f3cd3d2f9d34bc44eee0dd50314ed44ca5d30ce6a9ec0539be7a86b121edc61839ccc908c4bdde256cd6048",
+                    "111061f398efc2a97ff825b04d21089e24fd8b93a47e41e60eae7e9b2a38d54fa4dedced0811c34ce528781ab9e929c7",
+                ),
+            ),
+            Fp6::new(
+                fp2(
+                    "01ecfcf31c86257ab00b4709c33f1c9c4e007659dd5ffc4a735192167ce197058cfb4c94225e7f1b6c26ad9ba68f63bc",
+                    "08890726743a1f94a8193a166800b7787744a8ad8e2f9365db76863e894b7a11d83f90d873567e9d645ccf725b32d26f",
+                ),
+                fp2(
+                    "0e61c752414ca5dfd258e9606bac08daec29b3e2c57062669556954fb227d3f1260eedf25446a086b0844bcd43646c10",
+                    "0fe63f185f56dd29150fc498bbeea78969e7e783043620db33f75a05a0a2ce5c442beaff9da195ff15164c00ab66bdde",
+                ),
+                fp2(
+                    "10900338a92ed0b47af211636f7cfdec717b7ee43900eee9b5fc24f0000c5874d4801372db478987691c566a8c474978",
+                    "1454814f3085f0e6602247671bc408bbce2007201536818c901dbd4d2095dd86c1ec8b888e59611f60a301af7776be3d",
+                ),
+            ),
+        );
+        assert_eq!(
+            *pairing(&G1Affine::GENERATOR, &G2Affine::GENERATOR).as_fp12(),
+            expected
+        );
+    }
+
+    #[test]
+    fn test_bilinearity() {
+        let a = Scalar::from_u64(0xdead_beef_0123_4567);
+        let b = Scalar::from_u64(0x7654_3210_feed_face);
+        let p = (G1Projective::GENERATOR * &a).to_affine();
+        let q = (G2Projective::GENERATOR * &b).to_affine();
+
+        let base = pairing(&G1Affine::GENERATOR, &G2Affine::GENERATOR);
+        let e_ab = pairing(&p, &q);
+        assert_eq!(e_ab, base.pow_vartime(&a.mul(&b).to_uint()));
+        assert_eq!(
+            e_ab,
+            pairing(
+                &G1Affine::GENERATOR,
+                &(G2Projective::GENERATOR * &a.mul(&b)).to_affine()
+            )
+        );
+
+        // e(P1 + P2, Q) = e(P1, Q) e(P2, Q), and e(-P, Q) = e(P, Q)^-1.
+        let p2 = (G1Projective::GENERATOR + G1Projective::GENERATOR * &b).to_affine();
+        let lhs = pairing(&p2, &G2Affine::GENERATOR);
+        let rhs = base.mul(&pairing(
+            &(G1Projective::GENERATOR * &b).to_affine(),
+            &G2Affine::GENERATOR,
+        ));
+        assert_eq!(lhs, rhs);
+        assert_eq!(
+            pairing(&p.neg(), &G2Affine::GENERATOR),
+            pairing(&p, &G2Affine::GENERATOR).invert()
+        );
+    }
+
+    #[test]
+    fn test_small_order_points() {
+        // The twist's order is h2 r with 13^2 dividing the cofactor h2. Multiplying a point of E'
+        // by h2 r / 13^2 leaves a point of order 1, 13 or 169, and from there one of order 13.
+        const ORDER_OVER_169: Uint<12> = Uint::from_be_hex(concat!(
+            "4005449cda731a7136c440a0c65b728ba1c1fa6b6708356f3b9bdc84396cab33907d71557a7d33677f5d45f",
+            "7cedb8cfdac10ff1fc5b48d6461e907737d78e96568f2d18c750b4b3ca5c33c3fd8ff8a70629888281914529",
+            "f4e3380941cfdd",
+        ));
+        let thirteen = Uint::<1>::from_u64(13);
+        let g = G2Affine::GENERATOR;
+        let b = g.y().square().sub(&g.x().square().mul(&g.x()));
+
+        let mut x = Fp2::ZERO;
+        let q = loop {
+            x = x.add(&Fp2::ONE);
+            let rhs = x.square().mul(&x).add(&b);
+            let Some(point) = rhs.sqrt().and_then(|y| G2Affine::new(x, y)) else {
+                continue;
+            };
+            let mut q = point.to_projective().mul_vartime(&ORDER_OVER_169);
+            if !q.mul_vartime(&thirteen).is_identity() {
+                q = q.mul_vartime(&thirteen);
+            }
+            if !q.is_identity() {
+                break q.to_affine();
+            }
+        };
+        assert!(q.is_on_curve());
+        assert!(q.to_projective().mul_vartime(&thirteen).is_identity());
+
+        // The running point of the Miller loop reaches -Q and then the identity.
+        let e = pairing(&G1Affine::GENERATOR, &q);
+        assert!(e.pow_vartime(&ScalarParams::MODULUS).is_identity());
+        let f = miller_loop(&G1Affine::GENERATOR, &q);
+        assert_eq!(final_exponentiation(&f), e);
+    }
+
+    #[test]
+    fn test_target_group() {
+        let base = pairing(&G1Affine::GENERATOR, &G2Affine::GENERATOR);
+        assert!(!base.is_identity());
+        assert!(base.pow_vartime(&ScalarParams::MODULUS).is_identity());
+        assert_eq!(base.mul(&base.invert()), Gt::IDENTITY);
+        assert_eq!(base.square(), base.mul(&base));
+        assert!(pairing(&G1Affine::IDENTITY, &G2Affine::GENERATOR).is_identity());
+        assert!(pairing(&G1Affine::GENERATOR, &G2Affine::IDENTITY).is_identity());
+
+        // Products of Miller loops share a final exponentiation: e(P, Q) e(-P, Q) = 1.
+        let p = G1Affine::GENERATOR;
+        let q = G2Affine::GENERATOR;
+        let f = miller_loop(&p, &q).mul(&miller_loop(&p.neg(), &q));
+        assert!(final_exponentiation(&f).is_identity());
+    }
+}
@@ -0,0 +1,177 @@
+//! Curve25519: the base field `2^255 - 19` and X25519 Diffie-Hellman from RFC 7748.
+//!
+//! ```rust
+//! use nail::curves::curve25519::{x25519, X25519_BASEPOINT};
+//!
+//! let alice_secret = [0x11; 32];
+//! let bob_secret = [0x22; 32];
+//! let alice_public = x25519(alice_secret, X25519_BASEPOINT);
+//! let bob_public = x25519(bob_secret, X25519_BASEPOINT);
+//! assert_eq!(
+//!     x25519(alice_secret, bob_public),
+//!     x25519(bob_secret, alice_public)
+//! );
+//! ```
+
+use crate::{
+    field::{FieldParams, Fp, Reduction},
+    reduce,
+    uint::Uint,
+};
+
+/// The base field, `p = 2^255 - 19`, using the pseudo-Mersenne reduction.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BaseParams;
+
+impl FieldParams<4> for BaseParams {
+    const MODULUS: Uint<4> =
+        Uint::from_be_hex("7FFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFED");
+    const REDUCTION: Reduction<4> = Reduction::Special(reduce::reduce_p25519);
+}
+
+/// An element of the base field.
+pub type FieldElement = Fp<4, BaseParams>;
+
+/// The u-coordinate of the standard base point, `u = 9`.
+pub const X25519_BASEPOINT: [u8; 32] = {
+    let mut bytes = [0u8; 32];
+    bytes[0] = 9;
+    bytes
+};
+
+/// `(A - 2) / 4` for the Montgomery curve `v^2 = u^3 + 486662 u^2 + u`.
+const A24: FieldElement = Fp::from_uint_const(&Uint::from_u64(121665));
+
+/// Computes the X25519 function of RFC 7748: the u-coordinate of `scalar * u`.
+///
+/// The scalar is clamped as the RFC requires and the most significant bit of `u` is ignored;
+/// non-canonical u-coordinates are reduced modulo `p`. The Montgomery ladder performs the same
+/// operations for every scalar.
+pub fn x25519(scalar: [u8; 32], u: [u8; 32]) -> [u8; 32] {
+    let mut k = scalar;
+    k[0] &= 248;
+    k[31] &= 127;
+    k[31] |= 64;
+    let k = Uint::<4>::from_le_bytes(&k).expect("32 bytes fit in Uint<4>");
+
+    let mut u = u;
+    u[31] &= 127;
+    let x1 = FieldElement::from_uint(&Uint::from_le_bytes(&u).expect("32 bytes fit in Uint<4>"));
+
+    let (mut x2, mut z2) = (FieldElement::ONE, FieldElement::ZERO);
+    let (mut x3, mut z3) = (x1, FieldElement::ONE);
+    let mut swap = 0u64;
+    for t in (0..255).rev() {
+        let bit = k.bit(t) as u64;
+        swap ^= bit;
+        (x2, x3) = cswap(&x2, &x3, swap);
+        (z2, z3) = cswap(&z2, &z3, swap);
+        swap = bit;
+
+        let a = x2.add(&z2);
+        let aa = a.square();
+        let b = x2.sub(&z2);
+        let bb = b.square();
+        let e = aa.sub(&bb);
+        let c = x3.add(&z3);
+        let d = x3.sub(&z3);
+        let da = d.mul(&a);
+        let cb = c.mul(&b);
+        x3 = da.add(&cb).square();
+        z3 = x1.mul(&da.sub(&cb).square());
+        x2 = aa.mul(&bb);
+        z2 = e.mul(&aa.add(&A24.mul(&e)));
+    }
+    (x2, _) = cswap(&x2, &x3, swap);
+    (z2, _) = cswap(&z2, &z3, swap);
+
+    // z2 = 0 for low-order inputs; the inversion then yields zero, matching the RFC's x * z^(p-2).
+    let z_inv = z2.invert().unwrap_or(FieldElement::ZERO);
+    let mut out = [0u8; 32];
+    x2.mul(&z_inv).write_le_bytes(&mut out);
+    out
+}
+
+/// Swaps `a` and `b` when `choice` is 1, without branching.
+fn cswap(a: &FieldElement, b: &FieldElement, choice: u64) -> (FieldElement, FieldElement) {
+    (Fp::select(a, b, choice), Fp::select(b, a, choice))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn from_hex(hex: &str) -> [u8; 32] {
+        let mut out = [0u8; 32];
+        for (i, byte) in out.iter_mut().enumerate() {
+            *byte = u8::from_str_radix(&hex[2 * i..2 * i + 2], 16).unwrap();
+        }
+        out
+    }
+
+    #[test]
+    fn test_rfc7748_vectors() {
+        let out = x25519(
+            from_hex("a546e36bf0527c9d3b16154b82465edd62144c0ac1fc5a18506a2244ba449ac4"),
+            from_hex("e6db6867583030db3594c1a424b15f7c726624ec26b3353b10a903a6d0ab1c4c"),
+        );
+        assert_eq!(
+            out,
+            from_hex("c3da55379de9c6908e94ea4df28d084f32eccf03491c71f754b4075577a28552")
+        );
+
+        // The u-coordinate has its top bit set, which must be ignored
+        let out = x25519(
+            from_hex("4b66e9d4d1b4673c5ad22691957d6af5c11b6421e0ea01d42ca4169e7918ba0d"),
+            from_hex("e5210f12786811d3f4b7959d0538ae2c31dbe7106fc03c3efc4cd549c715a493"),
+        );
+        assert_eq!(
+            out,
+            from_hex("95cbde9476e8907d7aade45cb4b873f88b595a68799fa152e6f8f7647aac7957")
+        );
+    }
+
+    #[test]
+    fn test_rfc7748_iterations() {
+        let mut k = X25519_BASEPOINT;
+        let mut u = X25519_BASEPOINT;
+        for i in 1..=1000 {
+            (k, u) = (x25519(k, u), k);
+            if i == 1 {
+                assert_eq!(
+                    k,
+                    from_hex("422c8e7a6227d7bca1350b3e2bb7279f7897b87bb6854b783c60e80311ae3079")
+                );
+            }
+        }
+        assert_eq!(
+            k,
+            from_hex("684cf59ba83309552800ef566f2f4d3c1c3887c49360e3875f2eb94d99532c51")
+        );
+    }
+
+    #[test]
+    fn test_rfc7748_diffie_hellman() {
+        let alice = from_hex("77076d0a7318a57d3c16c17251b26645df4c2f87ebc0992ab177fba51db92c2a");
+        let bob = from_hex("5dab087e624a8a4b79e17f8b83800ee66f3bb1292618b6fd1c2f8b27ff88e0eb");
+        let alice_public = x25519(alice, X25519_BASEPOINT);
+        let bob_public = x25519(bob, X25519_BASEPOINT);
+        assert_eq!(
+            alice_public,
+            from_hex("8520f0098930a754748b7ddcb43ef75a0dbf3a0d26381af4eba4a98eaa9b4e6a")
+        );
+        assert_eq!(
+            bob_public,
+            from_hex("de9edb7d7b7dc1b4d35b61c2ece435373f8343c85b78674dadfc7e146f882b4f")
+        );
+
+        let shared = from_hex("4a5d9d5ba4ce2de1728e3bf480350f25e07e21c947d19e3376f09b3c1e161742");
+        assert_eq!(x25519(alice, bob_public), shared);
+        assert_eq!(x25519(bob, alice_public), shared);
+    }
+
+    #[test]
+    fn test_low_order_input_yields_zero() {
+        assert_eq!(x25519([0x42; 32], [0; 32]), [0; 32]);
+    }
+}
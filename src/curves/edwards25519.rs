@@ -0,0 +1,460 @@
+//! The twisted Edwards curve `-x^2 + y^2 = 1 + d x^2 y^2` birationally equivalent to Curve25519,
+//! as used by Ed25519 (RFC 8032).
+//!
+//! Points are kept in extended coordinates `(X : Y : Z : T)` with `x = X/Z`, `y = Y/Z` and
+//! `T = XY/Z`, where the unified formulas of Hisil, Wong, Carter and Dawson are complete. The group
+//! has order `8 * l`; [`EdwardsPoint::is_small_order`] and [`EdwardsPoint::is_torsion_free`] tell
+//! the cofactor components apart.
+
+use crate::{
+    ct::{fixed_window, Choice, ConditionallySelectable},
+    curves::curve25519::FieldElement,
+    field::{FieldParams, Fp},
+    uint::Uint,
+};
+
+/// The scalar field, modulo the prime subgroup order
+/// `l = 2^252 + 27742317777372353535851937790883648493`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ScalarParams;
+
+impl FieldParams<4> for ScalarParams {
+    const MODULUS: Uint<4> =
+        Uint::from_be_hex("1000000000000000000000000000000014DEF9DEA2F79CD65812631A5CF5D3ED");
+}
+
+/// An integer modulo `l`. Use [`Fp::from_le_bytes_wide`] to reduce 64-byte hash outputs.
+pub type Scalar = Fp<4, ScalarParams>;
+
+/// The curve constant `d = -121665 / 121666`.
+const D: FieldElement = Fp::from_uint_const(&Uint::from_be_hex(
+    "52036CEE2B6FFE738CC740797779E89800700A4D4141D8AB75EB4DCA135978A3",
+));
+
+/// `2 * d`, used by the addition formulas.
+const D2: FieldElement = D.double();
+
+/// A point on edwards25519 in extended coordinates.
+#[derive(Debug, Clone, Copy)]
+pub struct EdwardsPoint {
+    x: FieldElement,
+    y: FieldElement,
+    z: FieldElement,
+    t: FieldElement,
+}
+
+impl EdwardsPoint {
+    /// The neutral element `(0, 1)`.
+    pub const IDENTITY: Self = Self {
+        x: Fp::ZERO,
+        y: Fp::ONE,
+        z: Fp::ONE,
+        t: Fp::ZERO,
+    };
+
+    /// The standard base point `B` of RFC 8032, with `y = 4/5` and even `x`.
+    pub const BASEPOINT: Self = {
+        let x = Fp::from_uint_const(&Uint::from_be_hex(
+            "216936D3CD6E53FEC0A4E231FDD6DC5C692CC7609525A7B2C9562D608F25D51A",
+        ));
+        let y = Fp::from_uint_const(&Uint::from_be_hex(
+            "6666666666666666666666666666666666666666666666666666666666666658",
+        ));
+        let t = Fp::from_uint_const(&Uint::from_be_hex(
+            "67875F0FD78B766566EA4E8E64ABE37D20F09F80775152F56DDE8AB3A5B7DDA3",
+        ));
+        Self {
+            x,
+            y,
+            z: Fp::ONE,
+            t,
+        }
+    };
+
+    /// Creates a point from affine coordinates, or returns `None` if it is not on the curve.
+    pub fn from_affine(x: &FieldElement, y: &FieldElement) -> Option<Self> {
+        let (x2, y2) = (x.square(), y.square());
+        if y2.sub(&x2) != FieldElement::ONE.add(&D.mul(&x2).mul(&y2)) {
+            return None;
+        }
+        Some(Self {
+            x: *x,
+            y: *y,
+            z: Fp::ONE,
+            t: x.mul(y),
+        })
+    }
+
+    /// Returns the affine coordinates `(x, y)`.
+    pub fn to_affine(&self) -> (FieldElement, FieldElement) {
+        let z_inv = self
+            .z
+            .invert()
+            .expect("extended coordinates never have Z = 0");
+        (self.x.mul(&z_inv), self.y.mul(&z_inv))
+    }
+
+    /// Encodes the point as in RFC 8032: `y` in little-endian with the parity of `x` in the top
+    /// bit.
+    pub fn compress(&self) -> [u8; 32] {
+        let (x, y) = self.to_affine();
+        let mut out = [0u8; 32];
+        y.write_le_bytes(&mut out);
+        out[31] |= (x.to_uint().is_odd() as u8) << 7;
+        out
+    }
+
+    /// Decodes an RFC 8032 point encoding, recovering `x` with a square root.
+    ///
+    /// Returns `None` if `y` is not canonical, no matching `x` exists, or the encoding asks for
+    /// an odd `x = 0`.
+    pub fn decompress(bytes: &[u8; 32]) -> Option<Self> {
+        let sign = bytes[31] >> 7 == 1;
+        let mut y_bytes = *bytes;
+        y_bytes[31] &= 0x7F;
+        let y = FieldElement::from_le_bytes(&y_bytes)?;
+
+        // x^2 = (y^2 - 1) / (d y^2 + 1); the denominator never vanishes because d is not a square
+        let y2 = y.square();
+        let u = y2.sub(&FieldElement::ONE);
+        let v = D.mul(&y2).add(&FieldElement::ONE);
+        let x = u.mul(&v.invert()?).sqrt()?;
+        if x.is_zero() && sign {
+            return None;
+        }
+        let x = if x.to_uint().is_odd() != sign {
+            x.neg()
+        } else {
+            x
+        };
+
+        Some(Self {
+            x,
+            y,
+            z: Fp::ONE,
+            t: x.mul(&y),
+        })
+    }
+
+    /// Returns `true` for the neutral element.
+    pub fn is_identity(&self) -> bool {
+        self.x.is_zero() && self.y == self.z
+    }
+
+    /// Returns `a` when `choice` is 0 and `b` when `choice` is 1, without branching.
+    fn select(a: &Self, b: &Self, choice: u64) -> Self {
+        Self {
+            x: Fp::select(&a.x, &b.x, choice),
+            y: Fp::select(&a.y, &b.y, choice),
+            z: Fp::select(&a.z, &b.z, choice),
+            t: Fp::select(&a.t, &b.t, choice),
+        }
+    }
+
+    /// Returns `self + other` using the unified `add-2008-hwcd-3` formulas.
+    pub fn add(&self, other: &Self) -> Self {
+        let a = self.y.sub(&self.x).mul(&other.y.sub(&other.x));
+        let b = self.y.add(&self.x).mul(&other.y.add(&other.x));
+        let c = self.t.mul(&D2).mul(&other.t);
+        let d = self.z.mul(&other.z).double();
+        let (e, f, g, h) = (b.sub(&a), d.sub(&c), d.add(&c), b.add(&a));
+        Self {
+            x: e.mul(&f),
+            y: g.mul(&h),
+            z: f.mul(&g),
+            t: e.mul(&h),
+        }
+    }
+
+    /// Returns `2 * self` using the `dbl-2008-hwcd` formulas with `a = -1`.
+    pub fn double(&self) -> Self {
+        let a = self.x.square();
+        let b = self.y.square();
+        let c = self.z.square().double();
+        let e = self.x.add(&self.y).square().sub(&a).sub(&b);
+        let g = b.sub(&a);
+        let f = g.sub(&c);
+        let h = a.add(&b).neg();
+        Self {
+            x: e.mul(&f),
+            y: g.mul(&h),
+            z: f.mul(&g),
+            t: e.mul(&h),
+        }
+    }
+
+    /// Returns `-self`.
+    pub fn neg(&self) -> Self {
+        Self {
+            x: self.x.neg(),
+            y: self.y,
+            z: self.z,
+            t: self.t.neg(),
+        }
+    }
+
+    /// Returns `self - other`.
+    pub fn sub(&self, other: &Self) -> Self {
+        self.add(&other.neg())
+    }
+
+    /// Multiplies the point by `k` with a fixed 4-bit window, in time that depends only on `S`.
+    pub fn mul<const S: usize>(&self, k: &Uint<S>) -> Self {
+        fixed_window(Self::IDENTITY, self, k, Self::add, Self::double)
+    }
+
+    /// Multiplies the point by a public `k`, skipping leading zero bits and absent additions.
+    pub fn mul_vartime<const S: usize>(&self, k: &Uint<S>) -> Self {
+        let mut acc = Self::IDENTITY;
+        for i in (0..k.bits()).rev() {
+            acc = acc.double();
+            if k.bit(i) {
+                acc = acc.add(self);
+            }
+        }
+        acc
+    }
+
+    /// Returns `8 * self`, clearing any torsion component.
+    pub fn mul_by_cofactor(&self) -> Self {
+        self.double().double().double()
+    }
+
+    /// Returns `true` if the point lies in the torsion subgroup of order dividing 8.
+    pub fn is_small_order(&self) -> bool {
+        self.mul_by_cofactor().is_identity()
+    }
+
+    /// Returns `true` if the point lies in the prime-order subgroup generated by the base point.
+    pub fn is_torsion_free(&self) -> bool {
+        self.mul_vartime(&ScalarParams::MODULUS).is_identity()
+    }
+}
+
+impl PartialEq for EdwardsPoint {
+    fn eq(&self, other: &Self) -> bool {
+        self.x.mul(&other.z) == other.x.mul(&self.z) && self.y.mul(&other.z) == other.y.mul(&self.z)
+    }
+}
+
+impl Eq for EdwardsPoint {}
+
+impl ConditionallySelectable for EdwardsPoint {
+    fn conditional_select(a: &Self, b: &Self, choice: Choice) -> Self {
+        Self::select(a, b, choice.to_bit())
+    }
+}
+
+impl Default for EdwardsPoint {
+    fn default() -> Self {
+        Self::IDENTITY
+    }
+}
+
+/// Implements a point operator and its assigning form for all owned/borrowed combinations.
+macro_rules! impl_point_binop {
+    ($trait:ident, $method:ident, $assign_trait:ident, $assign_method:ident) => {
+        impl core::ops::$trait<&EdwardsPoint> for &EdwardsPoint {
+            type Output = EdwardsPoint;
+
+            fn $method(self, rhs: &EdwardsPoint) -> EdwardsPoint {
+                EdwardsPoint::$method(self, rhs)
+            }
+        }
+
+        impl core::ops::$trait<EdwardsPoint> for &EdwardsPoint {
+            type Output = EdwardsPoint;
+
+            fn $method(self, rhs: EdwardsPoint) -> EdwardsPoint {
+                EdwardsPoint::$method(self, &rhs)
+            }
+        }
+
+        impl core::ops::$trait<&EdwardsPoint> for EdwardsPoint {
+            type Output = EdwardsPoint;
+
+            fn $method(self, rhs: &EdwardsPoint) -> EdwardsPoint {
+                EdwardsPoint::$method(&self, rhs)
+            }
+        }
+
+        impl core::ops::$trait<EdwardsPoint> for EdwardsPoint {
+            type Output = EdwardsPoint;
+
+            fn $method(self, rhs: EdwardsPoint) -> EdwardsPoint {
+                EdwardsPoint::$method(&self, &rhs)
+            }
+        }
+
+        impl core::ops::$assign_trait<&EdwardsPoint> for EdwardsPoint {
+            fn $assign_method(&mut self, rhs: &EdwardsPoint) {
+                *self = EdwardsPoint::$method(self, rhs);
+            }
+        }
+
+        impl core::ops::$assign_trait<EdwardsPoint> for EdwardsPoint {
+            fn $assign_method(&mut self, rhs: EdwardsPoint) {
+                *self = EdwardsPoint::$method(self, &rhs);
+            }
+        }
+    };
+}
+
+impl_point_binop!(Add, add, AddAssign, add_assign);
+impl_point_binop!(Sub, sub, SubAssign, sub_assign);
+
+impl core::ops::Neg for EdwardsPoint {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        EdwardsPoint::neg(&self)
+    }
+}
+
+impl core::ops::Neg for &EdwardsPoint {
+    type Output = EdwardsPoint;
+
+    fn neg(self) -> EdwardsPoint {
+        EdwardsPoint::neg(self)
+    }
+}
+
+impl core::ops::Mul<&Scalar> for &EdwardsPoint {
+    type Output = EdwardsPoint;
+
+    fn mul(self, rhs: &Scalar) -> EdwardsPoint {
+        EdwardsPoint::mul(self, &rhs.to_uint())
+    }
+}
+
+impl core::ops::Mul<&Scalar> for EdwardsPoint {
+    type Output = EdwardsPoint;
+
+    fn mul(self, rhs: &Scalar) -> EdwardsPoint {
+        EdwardsPoint::mul(&self, &rhs.to_uint())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn from_hex(hex: &str) -> [u8; 32] {
+        let mut out = [0u8; 32];
+        for (i, byte) in out.iter_mut().enumerate() {
+            *byte = u8::from_str_radix(&hex[2 * i..2 * i + 2], 16).unwrap();
+        }
+        out
+    }
+
+    /// An encoding of a point of order 8.
+    const ORDER_EIGHT: &str = "c7176a703d4dd84fba3c0b760d10670f2a2053fa2c39ccc64ec7fd7792ac037a";
+
+    #[test]
+    fn test_basepoint() {
+        let b = EdwardsPoint::BASEPOINT;
+        let (x, y) = b.to_affine();
+        assert_eq!(EdwardsPoint::from_affine(&x, &y), Some(b));
+        assert_eq!(x.mul(&y), b.t);
+        assert_eq!(
+            b.compress(),
+            from_hex("5866666666666666666666666666666666666666666666666666666666666666")
+        );
+        assert_eq!(EdwardsPoint::decompress(&b.compress()), Some(b));
+        assert!(b.is_torsion_free());
+        assert!(!b.is_small_order());
+        assert!(b.mul(&ScalarParams::MODULUS).is_identity());
+    }
+
+    #[test]
+    fn test_group_laws() {
+        let b = EdwardsPoint::BASEPOINT;
+        let id = EdwardsPoint::IDENTITY;
+        assert_eq!(b.add(&id), b);
+        assert_eq!(id.double(), id);
+        assert!(b.sub(&b).is_identity());
+        assert_eq!(b.add(&b), b.double());
+
+        let b3 = b.double().add(&b);
+        let five = Uint::<1>::from_u64(5);
+        assert_eq!(b3.add(&b.double()), b.mul(&five));
+        assert_eq!(b.mul(&five), b.mul_vartime(&five));
+        assert_eq!(&b * &Scalar::from_u64(5), b.mul(&five));
+
+        let mut acc = b;
+        acc += &b;
+        acc -= b;
+        assert_eq!(acc, b);
+        assert_eq!(-b + b, id);
+        assert_eq!(b.mul(&ScalarParams::MODULUS.sub(&Uint::<1>::ONE)), b.neg());
+    }
+
+    #[test]
+    fn test_rfc8032_public_key() {
+        // Clamped SHA-512 of the secret key in RFC 8032, section 7.1, test 1
+        let a = Scalar::from_le_bytes_wide(&{
+            let mut wide = [0u8; 64];
+            wide[..32].copy_from_slice(&from_hex(
+                "307c83864f2833cb427a2ef1c00a013cfdff2768d980c0a3a520f006904de94f",
+            ));
+            wide
+        })
+        .unwrap();
+        let public = &EdwardsPoint::BASEPOINT * &a;
+        let expected = from_hex("d75a980182b10ab7d54bfed3c964073a0ee172f3daa62325af021a68f707511a");
+        assert_eq!(public.compress(), expected);
+        assert_eq!(EdwardsPoint::decompress(&expected), Some(public));
+    }
+
+    #[test]
+    fn test_torsion() {
+        let t = EdwardsPoint::decompress(&from_hex(ORDER_EIGHT)).unwrap();
+        assert!(t.is_small_order());
+        assert!(!t.is_torsion_free());
+        assert!(!t.mul(&Uint::<1>::from_u64(4)).is_identity());
+
+        let mixed = EdwardsPoint::BASEPOINT.add(&t);
+        assert!(!mixed.is_small_order());
+        assert!(!mixed.is_torsion_free());
+        assert!(mixed.mul_by_cofactor().is_torsion_free());
+
+        let identity = EdwardsPoint::decompress(&from_hex(
+            "0100000000000000000000000000000000000000000000000000000000000000",
+        ))
+        .unwrap();
+        assert!(identity.is_identity());
+        assert!(identity.is_small_order() && identity.is_torsion_free());
+    }
+
+    #[test]
+    fn test_decompress_rejects_invalid_encodings() {
+        // y = p is not canonical
+        let non_canonical =
+            from_hex("edffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff7f");
+        assert_eq!(EdwardsPoint::decompress(&non_canonical), None);
+
+        // y = 1 forces x = 0, which cannot be odd
+        let negative_zero =
+            from_hex("0100000000000000000000000000000000000000000000000000000000000080");
+        assert_eq!(EdwardsPoint::decompress(&negative_zero), None);
+
+        // y = 2 has no matching x
+        let off_curve =
+            from_hex("0200000000000000000000000000000000000000000000000000000000000000");
+        assert_eq!(EdwardsPoint::decompress(&off_curve), None);
+    }
+
+    #[test]
+    fn test_scalar_wide_reduction() {
+        let mut wide = [0u8; 64];
+        for (i, byte) in wide.iter_mut().enumerate() {
+            *byte = i as u8;
+        }
+        let s = Scalar::from_le_bytes_wide(&wide).unwrap();
+        assert_eq!(
+            s.to_uint(),
+            Uint::from_be_hex("0572D0E474B5E0DA7A932112C3D46159CCE628540DB62350A0372DF082623C7A")
+        );
+        assert!(Scalar::from_le_bytes_wide(&[0xFF; 64]).unwrap().to_uint() < ScalarParams::MODULUS);
+    }
+}
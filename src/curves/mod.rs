@@ -0,0 +1,16 @@
+//! Standard elliptic curves.
+//!
+//! The short Weierstrass modules define parameter types for the base and scalar fields, a
+//! [`CurveParams`] implementation and convenient aliases for their elements and points.
+//! [`curve25519`] provides the field `2^255 - 19` together with X25519, and [`edwards25519`] the
+//! twisted Edwards group used by Ed25519. [`bls12_381`] provides the pairing-friendly curve
+//! BLS12-381 with its extension tower and the optimal ate pairing.
+//!
+//! [`CurveParams`]: crate::weierstrass::CurveParams
+
+pub mod bls12_381;
+pub mod curve25519;
+pub mod edwards25519;
+pub mod p256;
+pub mod p384;
+pub mod secp256k1;
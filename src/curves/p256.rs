@@ -0,0 +1,96 @@
+//! NIST P-256 (secp256r1) from FIPS 186-4, appendix D.1.2.3.
+
+use crate::{
+    field::{FieldParams, Fp, Reduction},
+    reduce,
+    uint::Uint,
+    weierstrass::{self, CurveParams},
+};
+
+/// The base field, `p = 2^256 - 2^224 + 2^192 + 2^96 - 1`, using the Solinas reduction.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BaseParams;
+
+impl FieldParams<4> for BaseParams {
+    const MODULUS: Uint<4> =
+        Uint::from_be_hex("FFFFFFFF00000001000000000000000000000000FFFFFFFFFFFFFFFFFFFFFFFF");
+    const REDUCTION: Reduction<4> = Reduction::Special(reduce::reduce_p256);
+}
+
+/// The scalar field, modulo the group order `n`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ScalarParams;
+
+impl FieldParams<4> for ScalarParams {
+    const MODULUS: Uint<4> =
+        Uint::from_be_hex("FFFFFFFF00000000FFFFFFFFFFFFFFFFBCE6FAADA7179E84F3B9CAC2FC632551");
+}
+
+/// Marker type for the P-256 curve.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct P256;
+
+impl CurveParams<4> for P256 {
+    type Base = BaseParams;
+    type Scalar = ScalarParams;
+
+    const A: FieldElement = Fp::from_uint_const(&Uint::from_u64(3)).neg();
+    const B: FieldElement = Fp::from_uint_const(&Uint::from_be_hex(
+        "5AC635D8AA3A93E7B3EBBD55769886BC651D06B0CC53B0F63BCE3C3E27D2604B",
+    ));
+    const GENERATOR: (FieldElement, FieldElement) = (
+        Fp::from_uint_const(&Uint::from_be_hex(
+            "6B17D1F2E12C4247F8BCE6E563A440F277037D812DEB33A0F4A13945D898C296",
+        )),
+        Fp::from_uint_const(&Uint::from_be_hex(
+            "4FE342E2FE1A7F9B8EE7EB4A7C0F9E162BCE33576B315ECECBB6406837BF51F5",
+        )),
+    );
+}
+
+/// An element of the base field.
+pub type FieldElement = Fp<4, BaseParams>;
+
+/// An integer modulo the group order.
+pub type Scalar = Fp<4, ScalarParams>;
+
+/// A P-256 point in affine coordinates.
+pub type AffinePoint = weierstrass::AffinePoint<4, P256>;
+
+/// A P-256 point in projective coordinates.
+pub type ProjectivePoint = weierstrass::ProjectivePoint<4, P256>;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_point(point: &ProjectivePoint, x: &str, y: &str) {
+        let affine = point.to_affine();
+        assert_eq!(affine.x().to_uint(), Uint::from_be_hex(x));
+        assert_eq!(affine.y().to_uint(), Uint::from_be_hex(y));
+    }
+
+    #[test]
+    fn test_small_multiples() {
+        let g = ProjectivePoint::GENERATOR;
+        assert_point(
+            &g.double(),
+            "7CF27B188D034F7E8A52380304B51AC3C08969E277F21B35A60B48FC47669978",
+            "07775510DB8ED040293D9AC69F7430DBBA7DADE63CE982299E04B79D227873D1",
+        );
+        assert_point(
+            &g.mul(&Uint::<1>::from_u64(3)),
+            "5ECBE4D1A6330A44C8F7EF951D4BF165E6C6B721EFADA985FB41661BC6E7FD6C",
+            "8734640C4998FF7E374B06CE1A64A2ECD82AB036384FB83D9A79B127A27D5032",
+        );
+    }
+
+    #[test]
+    fn test_scalar_multiplication_vector() {
+        let k = Uint::<4>::from_u64(112233445566778899);
+        let x = "339150844EC15234807FE862A86BE77977DBFB3AE3D96F4C22795513AEAAB82F";
+        let y = "B1C14DDFDC8EC1B2583F51E85A5EB3A155840F2034730E9B5ADA38B674336A21";
+        assert_point(&ProjectivePoint::GENERATOR.mul(&k), x, y);
+        assert_point(&ProjectivePoint::GENERATOR.mul_vartime(&k), x, y);
+    }
+}
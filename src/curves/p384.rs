@@ -0,0 +1,96 @@
+//! NIST P-384 (secp384r1) from FIPS 186-4, appendix D.1.2.4.
+
+use crate::{
+    field::{FieldParams, Fp},
+    uint::Uint,
+    weierstrass::{self, CurveParams},
+};
+
+/// The base field, `p = 2^384 - 2^128 - 2^96 + 2^32 - 1`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BaseParams;
+
+impl FieldParams<6> for BaseParams {
+    const MODULUS: Uint<6> = Uint::from_be_hex(
+        "FFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFEFFFFFFFF0000000000000000FFFFFFFF",
+    );
+}
+
+/// The scalar field, modulo the group order `n`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ScalarParams;
+
+impl FieldParams<6> for ScalarParams {
+    const MODULUS: Uint<6> = Uint::from_be_hex(
+        "FFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFC7634D81F4372DDF581A0DB248B0A77AECEC196ACCC52973",
+    );
+}
+
+/// Marker type for the P-384 curve.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct P384;
+
+impl CurveParams<6> for P384 {
+    type Base = BaseParams;
+    type Scalar = ScalarParams;
+
+    const A: FieldElement = Fp::from_uint_const(&Uint::from_u64(3)).neg();
+    const B: FieldElement = Fp::from_uint_const(&Uint::from_be_hex(
+        "B3312FA7E23EE7E4988E056BE3F82D19181D9C6EFE8141120314088F5013875AC656398D8A2ED19D2A85C8EDD3EC2AEF",
+    ));
+    const GENERATOR: (FieldElement, FieldElement) = (
+        Fp::from_uint_const(&Uint::from_be_hex(
+            "AA87CA22BE8B05378EB1C71EF320AD746E1D3B628BA79B9859F741E082542A385502F25DBF55296C3A545E3872760AB7",
+        )),
+        Fp::from_uint_const(&Uint::from_be_hex(
+            "3617DE4A96262C6F5D9E98BF9292DC29F8F41DBD289A147CE9DA3113B5F0B8C00A60B1CE1D7E819D7A431D7C90EA0E5F",
+        )),
+    );
+}
+
+/// An element of the base field.
+pub type FieldElement = Fp<6, BaseParams>;
+
+/// An integer modulo the group order.
+pub type Scalar = Fp<6, ScalarParams>;
+
+/// A P-384 point in affine coordinates.
+pub type AffinePoint = weierstrass::AffinePoint<6, P384>;
+
+/// A P-384 point in projective coordinates.
+pub type ProjectivePoint = weierstrass::ProjectivePoint<6, P384>;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_point(point: &ProjectivePoint, x: &str, y: &str) {
+        let affine = point.to_affine();
+        assert_eq!(affine.x().to_uint(), Uint::from_be_hex(x));
+        assert_eq!(affine.y().to_uint(), Uint::from_be_hex(y));
+    }
+
+    #[test]
+    fn test_small_multiples() {
+        let g = ProjectivePoint::GENERATOR;
+        assert_point(
+            &g.double(),
+            "08D999057BA3D2D969260045C55B97F089025959A6F434D651D207D19FB96E9E4FE0E86EBE0E64F85B96A9C75295DF61",
+            "8E80F1FA5B1B3CEDB7BFE8DFFD6DBA74B275D875BC6CC43E904E505F256AB4255FFD43E94D39E22D61501E700A940E80",
+        );
+        assert_point(
+            &g.mul(&Uint::<1>::from_u64(3)),
+            "077A41D4606FFA1464793C7E5FDC7D98CB9D3910202DCD06BEA4F240D3566DA6B408BBAE5026580D02D7E5C70500C831",
+            "C995F7CA0B0C42837D0BBE9602A9FC998520B41C85115AA5F7684C0EDC111EACC24ABD6BE4B5D298B65F28600A2F1DF1",
+        );
+    }
+
+    #[test]
+    fn test_scalar_multiplication_vector() {
+        let k = Uint::<6>::from_u64(112233445566778899);
+        let x = "A499EFE48839BC3ABCD1C5CEDBDD51904F9514DB44F4686DB918983B0C9DC3AEE05A88B72433E9515F91A329F5F4FA60";
+        let y = "3B7CA28EF31F809C2F1BA24AAED847D0F8B406A4B8968542DE139DB5828CA410E615D1182E25B91B1131E230B727D36A";
+        assert_point(&ProjectivePoint::GENERATOR.mul(&k), x, y);
+        assert_point(&ProjectivePoint::GENERATOR.mul_vartime(&k), x, y);
+    }
+}
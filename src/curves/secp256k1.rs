@@ -0,0 +1,94 @@
+//! The secp256k1 curve `y^2 = x^3 + 7` from SEC 2, section 2.4.1.
+
+use crate::{
+    field::{FieldParams, Fp, Reduction},
+    reduce,
+    uint::Uint,
+    weierstrass::{self, CurveParams},
+};
+
+/// The base field, `p = 2^256 - 2^32 - 977`, using the pseudo-Mersenne reduction.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BaseParams;
+
+impl FieldParams<4> for BaseParams {
+    const MODULUS: Uint<4> =
+        Uint::from_be_hex("FFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFEFFFFFC2F");
+    const REDUCTION: Reduction<4> = Reduction::Special(reduce::reduce_secp256k1);
+}
+
+/// The scalar field, modulo the group order `n`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ScalarParams;
+
+impl FieldParams<4> for ScalarParams {
+    const MODULUS: Uint<4> =
+        Uint::from_be_hex("FFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFEBAAEDCE6AF48A03BBFD25E8CD0364141");
+}
+
+/// Marker type for the secp256k1 curve.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Secp256k1;
+
+impl CurveParams<4> for Secp256k1 {
+    type Base = BaseParams;
+    type Scalar = ScalarParams;
+
+    const A: FieldElement = Fp::ZERO;
+    const B: FieldElement = Fp::from_uint_const(&Uint::from_u64(7));
+    const GENERATOR: (FieldElement, FieldElement) = (
+        Fp::from_uint_const(&Uint::from_be_hex(
+            "79BE667EF9DCBBAC55A06295CE870B07029BFCDB2DCE28D959F2815B16F81798",
+        )),
+        Fp::from_uint_const(&Uint::from_be_hex(
+            "483ADA7726A3C4655DA4FBFC0E1108A8FD17B448A68554199C47D08FFB10D4B8",
+        )),
+    );
+}
+
+/// An element of the base field.
+pub type FieldElement = Fp<4, BaseParams>;
+
+/// An integer modulo the group order.
+pub type Scalar = Fp<4, ScalarParams>;
+
+/// A secp256k1 point in affine coordinates.
+pub type AffinePoint = weierstrass::AffinePoint<4, Secp256k1>;
+
+/// A secp256k1 point in projective coordinates.
+pub type ProjectivePoint = weierstrass::ProjectivePoint<4, Secp256k1>;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_point(point: &ProjectivePoint, x: &str, y: &str) {
+        let affine = point.to_affine();
+        assert_eq!(affine.x().to_uint(), Uint::from_be_hex(x));
+        assert_eq!(affine.y().to_uint(), Uint::from_be_hex(y));
+    }
+
+    #[test]
+    fn test_small_multiples() {
+        let g = ProjectivePoint::GENERATOR;
+        assert_point(
+            &g.double(),
+            "C6047F9441ED7D6D3045406E95C07CD85C778E4B8CEF3CA7ABAC09B95C709EE5",
+            "1AE168FEA63DC339A3C58419466CEAEEF7F632653266D0E1236431A950CFE52A",
+        );
+        assert_point(
+            &g.mul(&Uint::<1>::from_u64(3)),
+            "F9308A019258C31049344F85F89D5229B531C845836F99B08601F113BCE036F9",
+            "388F7B0F632DE8140FE337E62A37F3566500A99934C2231B6CB9FD7584B8E672",
+        );
+    }
+
+    #[test]
+    fn test_scalar_multiplication_vector() {
+        let k = Uint::<4>::from_u64(112233445566778899);
+        let x = "A90CC3D3F3E146DAADFC74CA1372207CB4B725AE708CEF713A98EDD73D99EF29";
+        let y = "5A79D6B289610C68BC3B47F3D72F9788A26A06868B4D8E433E1E2AD76FB7DC76";
+        assert_point(&ProjectivePoint::GENERATOR.mul(&k), x, y);
+        assert_point(&ProjectivePoint::GENERATOR.mul_vartime(&k), x, y);
+    }
+}
@@ -0,0 +1,662 @@
+//! ECDSA over prehashed messages (SEC 1, section 4.1).
+//!
+//! nail has no hash functions, so callers hash the message themselves and pass the digest as an
+//! integer already truncated to the bit length of the group order; for SHA-256 with secp256k1 or
+//! P-256 that is simply the big-endian digest as a `Uint<4>`. Per-signature nonces come from a
+//! [`NonceGenerator`]: [`Rfc6979`] derives them deterministically from an [`Hmac`] the caller
+//! plugs in, and [`sign_prehashed_with_nonce`] accepts one directly.
+//!
+//! Verification and public-key recovery compute `u1 * G + u2 * Q` with Shamir's trick.
+
+use core::marker::PhantomData;
+
+use crate::{
+    uint::Uint,
+    weierstrass::{AffinePoint, CurveParams, FieldElement, ProjectivePoint, Scalar},
+};
+
+/// A keyed MAC with `HLEN`-byte output, normally HMAC over the hash that produced the digest.
+///
+/// This is the hook through which [`Rfc6979`] obtains HMAC without nail implementing any hash.
+pub trait Hmac<const HLEN: usize>: Sized {
+    /// Starts a MAC computation under `key`.
+    fn new_keyed(key: &[u8; HLEN]) -> Self;
+
+    /// Absorbs `data`.
+    fn update(&mut self, data: &[u8]);
+
+    /// Returns the tag.
+    fn finalize(self) -> [u8; HLEN];
+}
+
+/// A source of secret per-signature nonces.
+pub trait NonceGenerator<const LIMBS: usize, C: CurveParams<LIMBS>> {
+    /// Returns candidate number `attempt` for signing `digest` with `secret`.
+    ///
+    /// Signing starts at attempt 0 and asks for the next one only in the negligible case that a
+    /// candidate yields `r = 0` or `s = 0`. Candidates must be non-zero, secret and never reused
+    /// for a different digest.
+    fn nonce(
+        &self,
+        secret: &Scalar<LIMBS, C>,
+        digest: &Uint<LIMBS>,
+        attempt: u32,
+    ) -> Scalar<LIMBS, C>;
+}
+
+/// Deterministic nonces from RFC 6979, section 3.2, built on the HMAC `H`.
+///
+/// The same key and digest always produce the same nonce, so signing needs no randomness.
+#[derive(Debug, Clone, Copy)]
+pub struct Rfc6979<H, const HLEN: usize> {
+    _hmac: PhantomData<H>,
+}
+
+impl<H: Hmac<HLEN>, const HLEN: usize> Rfc6979<H, HLEN> {
+    /// Creates the generator.
+    pub const fn new() -> Self {
+        Self { _hmac: PhantomData }
+    }
+}
+
+impl<H: Hmac<HLEN>, const HLEN: usize> Default for Rfc6979<H, HLEN> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Feeds the `rlen`-byte big-endian encoding of `x` (`int2octets`) into `mac`.
+fn update_int<const LIMBS: usize, const HLEN: usize, H: Hmac<HLEN>>(
+    mac: &mut H,
+    x: &Uint<LIMBS>,
+    rlen: usize,
+) {
+    for i in (0..rlen).rev() {
+        mac.update(&[(x.limbs[i / 8] >> (8 * (i % 8))) as u8]);
+    }
+}
+
+/// Computes `HMAC_K(V || sep || x || h)`, or `HMAC_K(V || sep)` when `ints` is `None`.
+fn hmac_round<const LIMBS: usize, const HLEN: usize, H: Hmac<HLEN>>(
+    k: &[u8; HLEN],
+    v: &[u8; HLEN],
+    sep: u8,
+    ints: Option<(&Uint<LIMBS>, &Uint<LIMBS>, usize)>,
+) -> [u8; HLEN] {
+    let mut mac = H::new_keyed(k);
+    mac.update(v);
+    mac.update(&[sep]);
+    if let Some((x, h, rlen)) = ints {
+        update_int(&mut mac, x, rlen);
+        update_int(&mut mac, h, rlen);
+    }
+    mac.finalize()
+}
+
+/// Computes `HMAC_K(V)`.
+fn hmac_v<const HLEN: usize, H: Hmac<HLEN>>(k: &[u8; HLEN], v: &[u8; HLEN]) -> [u8; HLEN] {
+    let mut mac = H::new_keyed(k);
+    mac.update(v);
+    mac.finalize()
+}
+
+impl<const LIMBS: usize, C: CurveParams<LIMBS>, H: Hmac<HLEN>, const HLEN: usize>
+    NonceGenerator<LIMBS, C> for Rfc6979<H, HLEN>
+{
+    fn nonce(
+        &self,
+        secret: &Scalar<LIMBS, C>,
+        digest: &Uint<LIMBS>,
+        attempt: u32,
+    ) -> Scalar<LIMBS, C> {
+        let q = Scalar::<LIMBS, C>::MODULUS;
+        let qlen = q.bits();
+        let rlen = qlen.div_ceil(8);
+        let x = secret.to_uint();
+        let h = Scalar::<LIMBS, C>::from_uint(digest).to_uint();
+
+        let mut v = [0x01u8; HLEN];
+        let mut k = [0x00u8; HLEN];
+        k = hmac_round::<LIMBS, HLEN, H>(&k, &v, 0x00, Some((&x, &h, rlen)));
+        v = hmac_v::<HLEN, H>(&k, &v);
+        k = hmac_round::<LIMBS, HLEN, H>(&k, &v, 0x01, Some((&x, &h, rlen)));
+        v = hmac_v::<HLEN, H>(&k, &v);
+
+        let mut remaining = attempt;
+        loop {
+            // Collect rlen bytes of output and keep the leftmost qlen bits (bits2int)
+            let mut t = Uint::<LIMBS>::ZERO;
+            let mut filled = 0;
+            while filled < rlen {
+                v = hmac_v::<HLEN, H>(&k, &v);
+                for &byte in v.iter().take(rlen - filled) {
+                    t = t.shl(8);
+                    t.limbs[0] |= byte as u64;
+                }
+                filled += HLEN.min(rlen - filled);
+            }
+            let t = t.shr(rlen * 8 - qlen);
+
+            if !t.is_zero() && t < q {
+                if remaining == 0 {
+                    return Scalar::<LIMBS, C>::from_uint(&t);
+                }
+                remaining -= 1;
+            }
+            k = hmac_round::<LIMBS, HLEN, H>(&k, &v, 0x00, None);
+            v = hmac_v::<HLEN, H>(&k, &v);
+        }
+    }
+}
+
+/// An ECDSA signature `(r, s)` with both components non-zero.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Signature<const LIMBS: usize, C: CurveParams<LIMBS>> {
+    r: Scalar<LIMBS, C>,
+    s: Scalar<LIMBS, C>,
+}
+
+impl<const LIMBS: usize, C: CurveParams<LIMBS>> Signature<LIMBS, C> {
+    /// Creates a signature, or returns `None` if either component is zero.
+    pub fn new(r: Scalar<LIMBS, C>, s: Scalar<LIMBS, C>) -> Option<Self> {
+        if r.is_zero() || s.is_zero() {
+            None
+        } else {
+            Some(Self { r, s })
+        }
+    }
+
+    /// Returns `r`.
+    pub fn r(&self) -> Scalar<LIMBS, C> {
+        self.r
+    }
+
+    /// Returns `s`.
+    pub fn s(&self) -> Scalar<LIMBS, C> {
+        self.s
+    }
+
+    /// Returns `true` if `s <= n / 2`, the canonical form required by e.g. Bitcoin.
+    pub fn is_low_s(&self) -> bool {
+        self.s.to_uint() <= Scalar::<LIMBS, C>::MODULUS.shr(1)
+    }
+
+    /// Returns the equivalent signature with `s` replaced by `n - s` if it is in the upper half.
+    ///
+    /// Negating `s` corresponds to negating the nonce point, so a [`RecoveryId`] produced with the
+    /// original signature must have its y parity flipped to match.
+    pub fn normalize_s(&self) -> Self {
+        if self.is_low_s() {
+            *self
+        } else {
+            Self {
+                r: self.r,
+                s: self.s.neg(),
+            }
+        }
+    }
+
+    /// Decodes the big-endian concatenation `r || s` of exactly `LIMBS * 16` bytes.
+    ///
+    /// Returns `None` for the wrong length or for components that are zero or not below `n`.
+    pub fn from_be_bytes(bytes: &[u8]) -> Option<Self> {
+        if bytes.len() != LIMBS * 16 {
+            return None;
+        }
+        let (r, s) = bytes.split_at(LIMBS * 8);
+        Self::new(
+            Scalar::<LIMBS, C>::from_be_bytes(r)?,
+            Scalar::<LIMBS, C>::from_be_bytes(s)?,
+        )
+    }
+
+    /// Writes `r || s` in big-endian into `out`, which must be `LIMBS * 16` bytes long.
+    pub fn write_be_bytes(&self, out: &mut [u8]) {
+        assert_eq!(
+            out.len(),
+            LIMBS * 16,
+            "output length must be LIMBS * 16 bytes"
+        );
+        let (r, s) = out.split_at_mut(LIMBS * 8);
+        self.r.write_be_bytes(r);
+        self.s.write_be_bytes(s);
+    }
+}
+
+/// Identifies which of the up to four candidate nonce points produced a signature, allowing the
+/// public key to be recovered (the `recid` of secp256k1 signatures).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct RecoveryId(u8);
+
+impl RecoveryId {
+    /// Creates a recovery id from the parity of the nonce point's y-coordinate and whether its
+    /// x-coordinate was reduced modulo `n` to obtain `r`.
+    pub const fn new(is_y_odd: bool, is_x_reduced: bool) -> Self {
+        Self(is_y_odd as u8 | (is_x_reduced as u8) << 1)
+    }
+
+    /// Parses the byte form, which must be below 4.
+    pub const fn from_byte(byte: u8) -> Option<Self> {
+        if byte < 4 {
+            Some(Self(byte))
+        } else {
+            None
+        }
+    }
+
+    /// Returns the byte form in `0..4`.
+    pub const fn to_byte(self) -> u8 {
+        self.0
+    }
+
+    /// Returns `true` if the nonce point has an odd y-coordinate.
+    pub const fn is_y_odd(self) -> bool {
+        self.0 & 1 == 1
+    }
+
+    /// Returns `true` if the nonce point's x-coordinate was at least `n`.
+    pub const fn is_x_reduced(self) -> bool {
+        self.0 & 2 == 2
+    }
+
+    /// Returns the id matching the signature after [`Signature::normalize_s`] negated `s`.
+    pub const fn flip_y(self) -> Self {
+        Self(self.0 ^ 1)
+    }
+}
+
+/// Signs `digest` with `secret`, drawing nonces from `nonces`.
+///
+/// Returns `None` only if `secret` is zero. The signature is not normalized to low-S; see
+/// [`Signature::normalize_s`].
+pub fn sign_prehashed<const LIMBS: usize, C: CurveParams<LIMBS>, N: NonceGenerator<LIMBS, C>>(
+    secret: &Scalar<LIMBS, C>,
+    digest: &Uint<LIMBS>,
+    nonces: &N,
+) -> Option<(Signature<LIMBS, C>, RecoveryId)> {
+    if secret.is_zero() {
+        return None;
+    }
+    (0..).find_map(|attempt| {
+        sign_prehashed_with_nonce(secret, digest, &nonces.nonce(secret, digest, attempt))
+    })
+}
+
+/// Signs `digest` with `secret` using the caller-provided `nonce`.
+///
+/// Returns `None` if the secret or nonce is zero, or in the negligible case that `r` or `s` is
+/// zero; retry with a fresh nonce. Reusing a nonce for two digests reveals the secret key.
+pub fn sign_prehashed_with_nonce<const LIMBS: usize, C: CurveParams<LIMBS>>(
+    secret: &Scalar<LIMBS, C>,
+    digest: &Uint<LIMBS>,
+    nonce: &Scalar<LIMBS, C>,
+) -> Option<(Signature<LIMBS, C>, RecoveryId)> {
+    if secret.is_zero() {
+        return None;
+    }
+    let k_inv = nonce.invert()?;
+    let point = ProjectivePoint::<LIMBS, C>::GENERATOR
+        .mul(&nonce.to_uint())
+        .to_affine();
+    let x = point.x().to_uint();
+    let r = Scalar::<LIMBS, C>::from_uint(&x);
+    let e = Scalar::<LIMBS, C>::from_uint(digest);
+    let s = k_inv.mul(&e.add(&r.mul(secret)));
+
+    let recovery_id = RecoveryId::new(
+        point.y().to_uint().is_odd(),
+        x >= Scalar::<LIMBS, C>::MODULUS,
+    );
+    Some((Signature::new(r, s)?, recovery_id))
+}
+
+/// Verifies `signature` on `digest` under `public_key`.
+///
+/// High-S signatures are accepted; check [`Signature::is_low_s`] where malleability matters.
+pub fn verify_prehashed<const LIMBS: usize, C: CurveParams<LIMBS>>(
+    public_key: &AffinePoint<LIMBS, C>,
+    digest: &Uint<LIMBS>,
+    signature: &Signature<LIMBS, C>,
+) -> bool {
+    if public_key.is_identity() || !public_key.is_on_curve() {
+        return false;
+    }
+    let w = match signature.s.invert() {
+        Some(w) => w,
+        None => return false,
+    };
+    let e = Scalar::<LIMBS, C>::from_uint(digest);
+    let u1 = e.mul(&w).to_uint();
+    let u2 = signature.r.mul(&w).to_uint();
+
+    let point =
+        ProjectivePoint::GENERATOR.double_mul_vartime(&u1, &public_key.to_projective(), &u2);
+    if point.is_identity() {
+        return false;
+    }
+    Scalar::<LIMBS, C>::from_uint(&point.to_affine().x().to_uint()) == signature.r
+}
+
+/// Recovers the public key that produced `signature` on `digest`, given its recovery id.
+///
+/// Returns `None` if no valid key corresponds to the inputs.
+pub fn recover_prehashed<const LIMBS: usize, C: CurveParams<LIMBS>>(
+    digest: &Uint<LIMBS>,
+    signature: &Signature<LIMBS, C>,
+    recovery_id: RecoveryId,
+) -> Option<AffinePoint<LIMBS, C>> {
+    let mut x = signature.r.to_uint();
+    if recovery_id.is_x_reduced() {
+        let (sum, overflow) = x.overflowing_add(&Scalar::<LIMBS, C>::MODULUS);
+        if overflow {
+            return None;
+        }
+        x = sum;
+    }
+    let x = FieldElement::<LIMBS, C>::from_canonical(&x)?;
+    let nonce_point = AffinePoint::<LIMBS, C>::decompress(&x, recovery_id.is_y_odd())?;
+
+    // Q = r^-1 (s R - e G)
+    let r_inv = signature.r.invert()?;
+    let e = Scalar::<LIMBS, C>::from_uint(digest);
+    let u1 = e.neg().mul(&r_inv).to_uint();
+    let u2 = signature.s.mul(&r_inv).to_uint();
+    let key = ProjectivePoint::GENERATOR.double_mul_vartime(&u1, &nonce_point.to_projective(), &u2);
+    if key.is_identity() {
+        None
+    } else {
+        Some(key.to_affine())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::curves::{p256, secp256k1};
+
+    /// Minimal SHA-256 for HMAC in the RFC 6979 tests.
+    #[derive(Clone)]
+    struct Sha256 {
+        state: [u32; 8],
+        buffer: [u8; 64],
+        buffered: usize,
+        length: u64,
+    }
+
+    const K: [u32; 64] = [
+        0x428a2f98, 0x71374491, 0xb5c0fbcf, 0xe9b5dba5, 0x3956c25b, 0x59f111f1, 0x923f82a4,
+        0xab1c5ed5, 0xd807aa98, 0x12835b01, 0x243185be, 0x550c7dc3, 0x72be5d74, 0x80deb1fe,
+        0x9bdc06a7, 0xc19bf174, 0xe49b69c1, 0xefbe4786, 0x0fc19dc6, 0x240ca1cc, 0x2de92c6f,
+        0x4a7484aa, 0x5cb0a9dc, 0x76f988da, 0x983e5152, 0xa831c66d, 0xb00327c8, 0xbf597fc7,
+        0xc6e00bf3, 0xd5a79147, 0x06ca6351, 0x14292967, 0x27b70a85, 0x2e1b2138, 0x4d2c6dfc,
+        0x53380d13, 0x650a7354, 0x766a0abb, 0x81c2c92e, 0x92722c85, 0xa2bfe8a1, 0xa81a664b,
+        0xc24b8b70, 0xc76c51a3, 0xd192e819, 0xd6990624, 0xf40e3585, 0x106aa070, 0x19a4c116,
+        0x1e376c08, 0x2748774c, 0x34b0bcb5, 0x391c0cb3, 0x4ed8aa4a, 0x5b9cca4f, 0x682e6ff3,
+        0x748f82ee, 0x78a5636f, 0x84c87814, 0x8cc70208, 0x90befffa, 0xa4506ceb, 0xbef9a3f7,
+        0xc67178f2,
+    ];
+
+    impl Sha256 {
+        fn new() -> Self {
+            Self {
+                state: [
+                    0x6a09e667, 0xbb67ae85, 0x3c6ef372, 0xa54ff53a, 0x510e527f, 0x9b05688c,
+                    0x1f83d9ab, 0x5be0cd19,
+                ],
+                buffer: [0; 64],
+                buffered: 0,
+                length: 0,
+            }
+        }
+
+        fn compress(&mut self) {
+            let mut w = [0u32; 64];
+            for (i, chunk) in self.buffer.chunks(4).enumerate() {
+                w[i] = u32::from_be_bytes(chunk.try_into().unwrap());
+            }
+            for i in 16..64 {
+                let s0 = w[i - 15].rotate_right(7) ^ w[i - 15].rotate_right(18) ^ (w[i - 15] >> 3);
+                let s1 = w[i - 2].rotate_right(17) ^ w[i - 2].rotate_right(19) ^ (w[i - 2] >> 10);
+                w[i] = w[i - 16]
+                    .wrapping_add(s0)
+                    .wrapping_add(w[i - 7])
+                    .wrapping_add(s1);
+            }
+            let [mut a, mut b, mut c, mut d, mut e, mut f, mut g, mut h] = self.state;
+            for i in 0..64 {
+                let s1 = e.rotate_right(6) ^ e.rotate_right(11) ^ e.rotate_right(25);
+                let ch = (e & f) ^ (!e & g);
+                let t1 = h
+                    .wrapping_add(s1)
+                    .wrapping_add(ch)
+                    .wrapping_add(K[i])
+                    .wrapping_add(w[i]);
+                let s0 = a.rotate_right(2) ^ a.rotate_right(13) ^ a.rotate_right(22);
+                let maj = (a & b) ^ (a & c) ^ (b & c);
+                let t2 = s0.wrapping_add(maj);
+                (h, g, f, e, d, c, b, a) =
+                    (g, f, e, d.wrapping_add(t1), c, b, a, t1.wrapping_add(t2));
+            }
+            for (s, v) in self.state.iter_mut().zip([a, b, c, d, e, f, g, h]) {
+                *s = s.wrapping_add(v);
+            }
+        }
+
+        fn update(&mut self, data: &[u8]) {
+            for &byte in data {
+                self.buffer[self.buffered] = byte;
+                self.buffered += 1;
+                if self.buffered == 64 {
+                    self.compress();
+                    self.buffered = 0;
+                }
+            }
+            self.length += data.len() as u64;
+        }
+
+        fn finalize(mut self) -> [u8; 32] {
+            let bit_length = self.length * 8;
+            self.update(&[0x80]);
+            while self.buffered != 56 {
+                self.update(&[0]);
+            }
+            self.update(&bit_length.to_be_bytes());
+            let mut out = [0u8; 32];
+            for (chunk, word) in out.chunks_mut(4).zip(self.state) {
+                chunk.copy_from_slice(&word.to_be_bytes());
+            }
+            out
+        }
+    }
+
+    struct HmacSha256 {
+        inner: Sha256,
+        outer: Sha256,
+    }
+
+    impl Hmac<32> for HmacSha256 {
+        fn new_keyed(key: &[u8; 32]) -> Self {
+            let (mut inner, mut outer) = (Sha256::new(), Sha256::new());
+            let mut ipad = [0x36u8; 64];
+            let mut opad = [0x5cu8; 64];
+            for i in 0..32 {
+                ipad[i] ^= key[i];
+                opad[i] ^= key[i];
+            }
+            inner.update(&ipad);
+            outer.update(&opad);
+            Self { inner, outer }
+        }
+
+        fn update(&mut self, data: &[u8]) {
+            self.inner.update(data);
+        }
+
+        fn finalize(mut self) -> [u8; 32] {
+            self.outer.update(&self.inner.finalize());
+            self.outer.finalize()
+        }
+    }
+
+    const RFC6979: Rfc6979<HmacSha256, 32> = Rfc6979::new();
+
+    #[test]
+    fn test_sha256() {
+        let mut sha = Sha256::new();
+        sha.update(b"sample");
+        let digest = Uint::<4>::from_be_bytes(&sha.finalize()).unwrap();
+        assert_eq!(
+            digest,
+            Uint::from_be_hex("AF2BDBE1AA9B6EC1E2ADE1D694F41FC71A831D0268E9891562113D8A62ADD1BF")
+        );
+    }
+
+    #[test]
+    fn test_rfc6979_p256_sample() {
+        // RFC 6979, appendix A.2.5, SHA-256, message "sample"
+        let secret = p256::Scalar::from_uint(&Uint::from_be_hex(
+            "C9AFA9D845BA75166B5C215767B1D6934E50C3DB36E89B127B8A622B120F6721",
+        ));
+        let digest =
+            Uint::from_be_hex("AF2BDBE1AA9B6EC1E2ADE1D694F41FC71A831D0268E9891562113D8A62ADD1BF");
+
+        let k = NonceGenerator::<4, p256::P256>::nonce(&RFC6979, &secret, &digest, 0);
+        assert_eq!(
+            k.to_uint(),
+            Uint::from_be_hex("A6E3C57DD01ABE90086538398355DD4C3B17AA873382B0F24D6129493D8AAD60")
+        );
+
+        let (signature, recovery_id) = sign_prehashed(&secret, &digest, &RFC6979).unwrap();
+        assert_eq!(
+            signature.r().to_uint(),
+            Uint::from_be_hex("EFD48B2AACB6A8FD1140DD9CD45E81D69D2C877B56AAF991C34D0EA84EAF3716")
+        );
+        assert_eq!(
+            signature.s().to_uint(),
+            Uint::from_be_hex("F7CB1C942D657C41D436C7A1B6E29F65F3E900DBB9AFF4064DC4AB2F843ACDA8")
+        );
+        assert!(!signature.is_low_s());
+
+        let public_key = p256::ProjectivePoint::GENERATOR
+            .mul(&secret.to_uint())
+            .to_affine();
+        assert_eq!(
+            public_key.x().to_uint(),
+            Uint::from_be_hex("60FED4BA255A9D31C961EB74C6356D68C049B8923B61FA6CE669622E60F29FB6")
+        );
+        assert!(verify_prehashed(&public_key, &digest, &signature));
+        assert!(verify_prehashed(
+            &public_key,
+            &digest,
+            &signature.normalize_s()
+        ));
+        assert_eq!(
+            recover_prehashed(&digest, &signature, recovery_id),
+            Some(public_key)
+        );
+
+        let other_digest = digest.add(&Uint::<1>::ONE);
+        assert!(!verify_prehashed(&public_key, &other_digest, &signature));
+        assert!(!verify_prehashed(
+            &p256::AffinePoint::GENERATOR,
+            &digest,
+            &signature
+        ));
+        assert!(!verify_prehashed(
+            &p256::AffinePoint::IDENTITY,
+            &digest,
+            &signature
+        ));
+    }
+
+    #[test]
+    fn test_secp256k1_low_s_and_recovery() {
+        // Secret key 1 signing SHA-256("Satoshi Nakamoto"), a widely used RFC 6979 vector
+        let secret = secp256k1::Scalar::ONE;
+        let digest =
+            Uint::from_be_hex("A0DC65FFCA799873CBEA0AC274015B9526505DAAAED385155425F7337704883E");
+
+        let (signature, recovery_id) = sign_prehashed(&secret, &digest, &RFC6979).unwrap();
+        assert_eq!(
+            signature.r().to_uint(),
+            Uint::from_be_hex("934B1EA10A4B3C1757E2B0C017D0B6143CE3C9A7E6A4A49860D7A6AB210EE3D8")
+        );
+        assert!(!signature.is_low_s());
+
+        let low = signature.normalize_s();
+        assert!(low.is_low_s());
+        assert_eq!(
+            low.s().to_uint(),
+            Uint::from_be_hex("2442CE9D2B916064108014783E923EC36B49743E2FFA1C4496F01A512AAFD9E5")
+        );
+        assert_eq!(low.normalize_s(), low);
+
+        let public_key = secp256k1::AffinePoint::GENERATOR;
+        assert!(verify_prehashed(&public_key, &digest, &low));
+        assert_eq!(
+            recover_prehashed(&digest, &signature, recovery_id),
+            Some(public_key)
+        );
+        assert_eq!(
+            recover_prehashed(&digest, &low, recovery_id.flip_y()),
+            Some(public_key)
+        );
+        assert_ne!(
+            recover_prehashed(&digest, &low, recovery_id),
+            Some(public_key)
+        );
+    }
+
+    #[test]
+    fn test_caller_provided_nonce() {
+        let secret = secp256k1::Scalar::from_u64(0xC0FFEE);
+        let digest = Uint::<4>::from_u64(42);
+        let public_key = secp256k1::ProjectivePoint::GENERATOR
+            .mul(&secret.to_uint())
+            .to_affine();
+
+        for nonce in [1u64, 2, 0xDEAD_BEEF] {
+            let nonce = secp256k1::Scalar::from_u64(nonce);
+            let (signature, recovery_id) =
+                sign_prehashed_with_nonce(&secret, &digest, &nonce).unwrap();
+            assert!(verify_prehashed(&public_key, &digest, &signature));
+            assert_eq!(
+                recover_prehashed(&digest, &signature, recovery_id),
+                Some(public_key)
+            );
+        }
+        assert!(sign_prehashed_with_nonce::<4, secp256k1::Secp256k1>(
+            &secret,
+            &digest,
+            &secp256k1::Scalar::ZERO
+        )
+        .is_none());
+        assert!(sign_prehashed::<4, secp256k1::Secp256k1, _>(
+            &secp256k1::Scalar::ZERO,
+            &digest,
+            &RFC6979
+        )
+        .is_none());
+    }
+
+    #[test]
+    fn test_signature_encoding() {
+        let secret = p256::Scalar::from_u64(7);
+        let (signature, _) = sign_prehashed(&secret, &Uint::from_u64(1), &RFC6979).unwrap();
+        let mut bytes = [0u8; 64];
+        signature.write_be_bytes(&mut bytes);
+        assert_eq!(
+            Signature::<4, p256::P256>::from_be_bytes(&bytes),
+            Some(signature)
+        );
+        assert_eq!(Signature::<4, p256::P256>::from_be_bytes(&[0u8; 64]), None);
+        assert_eq!(
+            Signature::<4, p256::P256>::from_be_bytes(&bytes[..32]),
+            None
+        );
+
+        assert_eq!(RecoveryId::from_byte(3), Some(RecoveryId::new(true, true)));
+        assert_eq!(RecoveryId::from_byte(4), None);
+        assert_eq!(RecoveryId::new(true, false).flip_y().to_byte(), 0);
+    }
+}
@@ -0,0 +1,725 @@
+//! Extension fields over [`Fp`].
+//!
+//! [`Fp2`] is the quadratic extension `Fp[u]/(u^2 - β)`, [`Fp6`] the cubic extension
+//! `Fp2[v]/(v^3 - ξ)` and [`Fp12`] the quadratic extension `Fp6[w]/(w^2 - v)`, the tower used by
+//! pairing-friendly curves such as BN254 and BLS12-381. Each level is enabled by implementing the
+//! matching parameter trait on the prime field's [`FieldParams`] marker type:
+//!
+//! - [`Fp2Params`]: a quadratic non-residue `β` of `Fp`;
+//! - [`Fp6Params`]: a cubic non-residue `ξ` of `Fp2` and the Frobenius constants for `v`;
+//! - [`Fp12Params`]: the Frobenius constant for `w`, which requires `ξ` to also be a non-square.
+//!
+//! The Frobenius constants cannot be derived during constant evaluation for every reduction
+//! backend, so they are part of the parameters; `frobenius_map` agreeing with `pow_vartime(p)` is
+//! a cheap check that they are right.
+//!
+//! ```rust
+//! use nail::{
+//!     extension::{Fp2, Fp2Params},
+//!     FieldParams, Fp, Uint,
+//! };
+//!
+//! #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+//! struct P64;
+//!
+//! impl FieldParams<1> for P64 {
+//!     const MODULUS: Uint<1> = Uint::from_u64(0xFFFF_FFFF_FFFF_FFC5);
+//! }
+//!
+//! // 2 is a non-square modulo p = 5 (mod 8).
+//! impl Fp2Params<1> for P64 {
+//!     const FP2_NONRESIDUE: Fp<1, P64> = Fp::from_uint_const(&Uint::from_u64(2));
+//! }
+//!
+//! let a = Fp2::<1, P64>::new(Fp::from_u64(3), Fp::from_u64(5));
+//! assert_eq!(a * a.invert().unwrap(), Fp2::ONE);
+//! assert_eq!(a.square().sqrt().map(|r| r == a || r == -a), Some(true));
+//! ```
+
+use core::fmt;
+
+use crate::{
+    field::{FieldParams, Fp},
+    uint::Uint,
+};
+
+/// Parameters of the quadratic extension `Fp2 = Fp[u]/(u^2 - β)`.
+pub trait Fp2Params<const LIMBS: usize>: FieldParams<LIMBS> {
+    /// The quadratic non-residue `β = u^2`.
+    const FP2_NONRESIDUE: Fp<LIMBS, Self>;
+}
+
+/// Parameters of the cubic extension `Fp6 = Fp2[v]/(v^3 - ξ)`.
+///
+/// The field prime must be `1 (mod 3)`.
+pub trait Fp6Params<const LIMBS: usize>: Fp2Params<LIMBS> {
+    /// The cubic non-residue `ξ = v^3`.
+    const FP6_NONRESIDUE: Fp2<LIMBS, Self>;
+
+    /// `ξ^((p - 1) / 3)`, so that `v^p = FROBENIUS_V * v`.
+    const FROBENIUS_V: Fp2<LIMBS, Self>;
+
+    /// `ξ^(2 (p - 1) / 3)`, so that `(v^2)^p = FROBENIUS_V2 * v^2`.
+    const FROBENIUS_V2: Fp2<LIMBS, Self>;
+}
+
+/// Parameters of the quadratic extension `Fp12 = Fp6[w]/(w^2 - v)`.
+///
+/// `ξ` must be neither a square nor a cube in `Fp2`, and the field prime must be `1 (mod 6)`.
+pub trait Fp12Params<const LIMBS: usize>: Fp6Params<LIMBS> {
+    /// `ξ^((p - 1) / 6)`, so that `w^p = FROBENIUS_W * w`.
+    const FROBENIUS_W: Fp2<LIMBS, Self>;
+}
+
+/// An element `c0 + c1 * u` of the quadratic extension described by `P`.
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct Fp2<const LIMBS: usize, P: Fp2Params<LIMBS>> {
+    pub c0: Fp<LIMBS, P>,
+    pub c1: Fp<LIMBS, P>,
+}
+
+impl<const LIMBS: usize, P: Fp2Params<LIMBS>> Fp2<LIMBS, P> {
+    /// The additive identity.
+    pub const ZERO: Self = Self::new(Fp::ZERO, Fp::ZERO);
+
+    /// The multiplicative identity.
+    pub const ONE: Self = Self::new(Fp::ONE, Fp::ZERO);
+
+    /// `β = -1`, the common choice for `p = 3 (mod 4)`, turns the multiplications by `β` into
+    /// negations.
+    const BETA_IS_MINUS_ONE: bool = Fp::<LIMBS, P>::ONE.add(&P::FP2_NONRESIDUE).is_zero();
+
+    /// Creates `c0 + c1 * u`.
+    pub const fn new(c0: Fp<LIMBS, P>, c1: Fp<LIMBS, P>) -> Self {
+        Self { c0, c1 }
+    }
+
+    /// Embeds a base field element.
+    pub const fn from_base(c0: Fp<LIMBS, P>) -> Self {
+        Self::new(c0, Fp::ZERO)
+    }
+
+    /// Returns `true` if the element is zero.
+    pub const fn is_zero(&self) -> bool {
+        self.c0.is_zero() && self.c1.is_zero()
+    }
+
+    /// Returns `self + other`.
+    pub const fn add(&self, other: &Self) -> Self {
+        Self::new(self.c0.add(&other.c0), self.c1.add(&other.c1))
+    }
+
+    /// Returns `self - other`.
+    pub const fn sub(&self, other: &Self) -> Self {
+        Self::new(self.c0.sub(&other.c0), self.c1.sub(&other.c1))
+    }
+
+    /// Returns `2 * self`.
+    pub const fn double(&self) -> Self {
+        Self::new(self.c0.double(), self.c1.double())
+    }
+
+    /// Returns `-self`.
+    pub const fn neg(&self) -> Self {
+        Self::new(self.c0.neg(), self.c1.neg())
+    }
+
+    /// Returns `a` when `choice` is 0 and `b` when `choice` is 1, without branching.
+    pub(crate) const fn select(a: &Self, b: &Self, choice: u64) -> Self {
+        Self::new(
+            Fp::select(&a.c0, &b.c0, choice),
+            Fp::select(&a.c1, &b.c1, choice),
+        )
+    }
+
+    /// Returns `β * x`.
+    fn mul_by_beta(x: &Fp<LIMBS, P>) -> Fp<LIMBS, P> {
+        if Self::BETA_IS_MINUS_ONE {
+            x.neg()
+        } else {
+            x.mul(&P::FP2_NONRESIDUE)
+        }
+    }
+
+    /// Returns `self * other` with three base field multiplications (Karatsuba).
+    pub fn mul(&self, other: &Self) -> Self {
+        let v0 = self.c0.mul(&other.c0);
+        let v1 = self.c1.mul(&other.c1);
+        let cross = self.c0.add(&self.c1).mul(&other.c0.add(&other.c1));
+        Self::new(v0.add(&Self::mul_by_beta(&v1)), cross.sub(&v0).sub(&v1))
+    }
+
+    /// Returns `self^2` with two base field multiplications ("complex" squaring).
+    pub fn square(&self) -> Self {
+        let v = self.c0.mul(&self.c1);
+        let c0 = self
+            .c0
+            .add(&self.c1)
+            .mul(&self.c0.add(&Self::mul_by_beta(&self.c1)))
+            .sub(&v)
+            .sub(&Self::mul_by_beta(&v));
+        Self::new(c0, v.double())
+    }
+
+    /// Multiplies both coefficients by a base field element.
+    pub fn mul_by_base(&self, k: &Fp<LIMBS, P>) -> Self {
+        Self::new(self.c0.mul(k), self.c1.mul(k))
+    }
+
+    /// Returns the conjugate `c0 - c1 * u`.
+    pub const fn conjugate(&self) -> Self {
+        Self::new(self.c0, self.c1.neg())
+    }
+
+    /// Returns `self^p`, which is the conjugate since `u^p = -u`.
+    pub const fn frobenius_map(&self) -> Self {
+        self.conjugate()
+    }
+
+    /// Returns the norm `self * conjugate(self) = c0^2 - β c1^2`, an element of `Fp`.
+    pub fn norm(&self) -> Fp<LIMBS, P> {
+        self.c0.square().sub(&Self::mul_by_beta(&self.c1.square()))
+    }
+
+    /// Returns `self^-1` as `conjugate(self) / norm(self)`, or `None` for zero.
+    pub fn invert(&self) -> Option<Self> {
+        let t = self.norm().invert()?;
+        Some(Self::new(self.c0.mul(&t), self.c1.mul(&t).neg()))
+    }
+
+    /// Returns a square root, or `None` if the element is not a square.
+    ///
+    /// Reduces to square roots in `Fp` through the norm: with `n = sqrt(norm(self))`, the root is
+    /// `x0 + c1 / (2 x0) * u` where `x0^2` is `(c0 + n) / 2` or `(c0 - n) / 2`. Variable-time.
+    pub fn sqrt(&self) -> Option<Self> {
+        if self.c1.is_zero() {
+            // Either c0 is a square in Fp, or c0 / β is and the root is a multiple of u.
+            return match self.c0.sqrt() {
+                Some(root) => Some(Self::from_base(root)),
+                None => {
+                    let root = self.c0.mul(&P::FP2_NONRESIDUE.invert()?).sqrt()?;
+                    Some(Self::new(Fp::ZERO, root))
+                }
+            };
+        }
+        let n = self.norm().sqrt()?;
+        let half = Fp::<LIMBS, P>::from_u64(2).invert()?;
+        let x0 = match self.c0.add(&n).mul(&half).sqrt() {
+            Some(x0) => x0,
+            None => self.c0.sub(&n).mul(&half).sqrt()?,
+        };
+        let root = Self::new(x0, self.c1.mul(&x0.double().invert()?));
+        (root.square() == *self).then_some(root)
+    }
+
+    /// Raises `self` to a public exponent with left-to-right square-and-multiply.
+    pub fn pow_vartime<const E: usize>(&self, exp: &Uint<E>) -> Self {
+        let mut acc = Self::ONE;
+        for i in (0..exp.bits()).rev() {
+            acc = acc.square();
+            if exp.bit(i) {
+                acc = acc.mul(self);
+            }
+        }
+        acc
+    }
+}
+
+/// An element `c0 + c1 * v + c2 * v^2` of the cubic extension of [`Fp2`].
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct Fp6<const LIMBS: usize, P: Fp6Params<LIMBS>> {
+    pub c0: Fp2<LIMBS, P>,
+    pub c1: Fp2<LIMBS, P>,
+    pub c2: Fp2<LIMBS, P>,
+}
+
+impl<const LIMBS: usize, P: Fp6Params<LIMBS>> Fp6<LIMBS, P> {
+    /// The additive identity.
+    pub const ZERO: Self = Self::new(Fp2::ZERO, Fp2::ZERO, Fp2::ZERO);
+
+    /// The multiplicative identity.
+    pub const ONE: Self = Self::new(Fp2::ONE, Fp2::ZERO, Fp2::ZERO);
+
+    /// Creates `c0 + c1 * v + c2 * v^2`.
+    pub const fn new(c0: Fp2<LIMBS, P>, c1: Fp2<LIMBS, P>, c2: Fp2<LIMBS, P>) -> Self {
+        Self { c0, c1, c2 }
+    }
+
+    /// Returns `true` if the element is zero.
+    pub const fn is_zero(&self) -> bool {
+        self.c0.is_zero() && self.c1.is_zero() && self.c2.is_zero()
+    }
+
+    /// Returns `self + other`.
+    pub const fn add(&self, other: &Self) -> Self {
+        Self::new(
+            self.c0.add(&other.c0),
+            self.c1.add(&other.c1),
+            self.c2.add(&other.c2),
+        )
+    }
+
+    /// Returns `self - other`.
+    pub const fn sub(&self, other: &Self) -> Self {
+        Self::new(
+            self.c0.sub(&other.c0),
+            self.c1.sub(&other.c1),
+            self.c2.sub(&other.c2),
+        )
+    }
+
+    /// Returns `2 * self`.
+    pub const fn double(&self) -> Self {
+        Self::new(self.c0.double(), self.c1.double(), self.c2.double())
+    }
+
+    /// Returns `-self`.
+    pub const fn neg(&self) -> Self {
+        Self::new(self.c0.neg(), self.c1.neg(), self.c2.neg())
+    }
+
+    /// Returns `ξ * x`.
+    fn mul_by_xi(x: &Fp2<LIMBS, P>) -> Fp2<LIMBS, P> {
+        x.mul(&P::FP6_NONRESIDUE)
+    }
+
+    /// Returns `self * other` with six [`Fp2`] multiplications (Karatsuba).
+    pub fn mul(&self, other: &Self) -> Self {
+        let (a0, a1, a2) = (&self.c0, &self.c1, &self.c2);
+        let (b0, b1, b2) = (&other.c0, &other.c1, &other.c2);
+        let v0 = a0.mul(b0);
+        let v1 = a1.mul(b1);
+        let v2 = a2.mul(b2);
+
+        let c0 = Self::mul_by_xi(&a1.add(a2).mul(&b1.add(b2)).sub(&v1).sub(&v2)).add(&v0);
+        let c1 = a0
+            .add(a1)
+            .mul(&b0.add(b1))
+            .sub(&v0)
+            .sub(&v1)
+            .add(&Self::mul_by_xi(&v2));
+        let c2 = a0.add(a2).mul(&b0.add(b2)).sub(&v0).sub(&v2).add(&v1);
+        Self::new(c0, c1, c2)
+    }
+
+    /// Returns `self^2`.
+    pub fn square(&self) -> Self {
+        self.mul(self)
+    }
+
+    /// Multiplies every coefficient by an [`Fp2`] element.
+    pub fn mul_by_fp2(&self, k: &Fp2<LIMBS, P>) -> Self {
+        Self::new(self.c0.mul(k), self.c1.mul(k), self.c2.mul(k))
+    }
+
+    /// Multiplies by `v`, which only rotates the coefficients.
+    pub fn mul_by_nonresidue(&self) -> Self {
+        Self::new(Self::mul_by_xi(&self.c2), self.c0, self.c1)
+    }
+
+    /// Returns `self^p`.
+    pub fn frobenius_map(&self) -> Self {
+        Self::new(
+            self.c0.frobenius_map(),
+            self.c1.frobenius_map().mul(&P::FROBENIUS_V),
+            self.c2.frobenius_map().mul(&P::FROBENIUS_V2),
+        )
+    }
+
+    /// Returns `self^-1`, or `None` for zero.
+    pub fn invert(&self) -> Option<Self> {
+        let (c0, c1, c2) = (&self.c0, &self.c1, &self.c2);
+        let t0 = c0.square().sub(&Self::mul_by_xi(&c1.mul(c2)));
+        let t1 = Self::mul_by_xi(&c2.square()).sub(&c0.mul(c1));
+        let t2 = c1.square().sub(&c0.mul(c2));
+        let norm = Self::mul_by_xi(&c2.mul(&t1).add(&c1.mul(&t2))).add(&c0.mul(&t0));
+        let norm_inv = norm.invert()?;
+        Some(Self::new(
+            t0.mul(&norm_inv),
+            t1.mul(&norm_inv),
+            t2.mul(&norm_inv),
+        ))
+    }
+}
+
+/// An element `c0 + c1 * w` of the quadratic extension of [`Fp6`].
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct Fp12<const LIMBS: usize, P: Fp12Params<LIMBS>> {
+    pub c0: Fp6<LIMBS, P>,
+    pub c1: Fp6<LIMBS, P>,
+}
+
+impl<const LIMBS: usize, P: Fp12Params<LIMBS>> Fp12<LIMBS, P> {
+    /// The additive identity.
+    pub const ZERO: Self = Self::new(Fp6::ZERO, Fp6::ZERO);
+
+    /// The multiplicative identity.
+    pub const ONE: Self = Self::new(Fp6::ONE, Fp6::ZERO);
+
+    /// Creates `c0 + c1 * w`.
+    pub const fn new(c0: Fp6<LIMBS, P>, c1: Fp6<LIMBS, P>) -> Self {
+        Self { c0, c1 }
+    }
+
+    /// Returns `true` if the element is zero.
+    pub const fn is_zero(&self) -> bool {
+        self.c0.is_zero() && self.c1.is_zero()
+    }
+
+    /// Returns `self + other`.
+    pub const fn add(&self, other: &Self) -> Self {
+        Self::new(self.c0.add(&other.c0), self.c1.add(&other.c1))
+    }
+
+    /// Returns `self - other`.
+    pub const fn sub(&self, other: &Self) -> Self {
+        Self::new(self.c0.sub(&other.c0), self.c1.sub(&other.c1))
+    }
+
+    /// Returns `-self`.
+    pub const fn neg(&self) -> Self {
+        Self::new(self.c0.neg(), self.c1.neg())
+    }
+
+    /// Returns `self * other` with three [`Fp6`] multiplications (Karatsuba).
+    pub fn mul(&self, other: &Self) -> Self {
+        let v0 = self.c0.mul(&other.c0);
+        let v1 = self.c1.mul(&other.c1);
+        let cross = self.c0.add(&self.c1).mul(&other.c0.add(&other.c1));
+        Self::new(v1.mul_by_nonresidue().add(&v0), cross.sub(&v0).sub(&v1))
+    }
+
+    /// Returns `self^2` with two [`Fp6`] multiplications.
+    pub fn square(&self) -> Self {
+        let t = self.c0.mul(&self.c1);
+        let c0 = self
+            .c0
+            .add(&self.c1)
+            .mul(&self.c0.add(&self.c1.mul_by_nonresidue()))
+            .sub(&t)
+            .sub(&t.mul_by_nonresidue());
+        Self::new(c0, t.double())
+    }
+
+    /// Returns the conjugate `c0 - c1 * w`, which equals `self^(p^6)`.
+    pub const fn conjugate(&self) -> Self {
+        Self::new(self.c0, self.c1.neg())
+    }
+
+    /// Returns `self^p`.
+    pub fn frobenius_map(&self) -> Self {
+        Self::new(
+            self.c0.frobenius_map(),
+            self.c1.frobenius_map().mul_by_fp2(&P::FROBENIUS_W),
+        )
+    }
+
+    /// Returns `self^-1` through the norm `c0^2 - v c1^2`, or `None` for zero.
+    pub fn invert(&self) -> Option<Self> {
+        let t = self
+            .c0
+            .square()
+            .sub(&self.c1.square().mul_by_nonresidue())
+            .invert()?;
+        Some(Self::new(self.c0.mul(&t), self.c1.mul(&t).neg()))
+    }
+
+    /// Raises `self` to a public exponent with left-to-right square-and-multiply.
+    pub fn pow_vartime<const E: usize>(&self, exp: &Uint<E>) -> Self {
+        let mut acc = Self::ONE;
+        for i in (0..exp.bits()).rev() {
+            acc = acc.square();
+            if exp.bit(i) {
+                acc = acc.mul(self);
+            }
+        }
+        acc
+    }
+}
+
+impl<const LIMBS: usize, P: Fp2Params<LIMBS>> Default for Fp2<LIMBS, P> {
+    fn default() -> Self {
+        Self::ZERO
+    }
+}
+
+impl<const LIMBS: usize, P: Fp6Params<LIMBS>> Default for Fp6<LIMBS, P> {
+    fn default() -> Self {
+        Self::ZERO
+    }
+}
+
+impl<const LIMBS: usize, P: Fp12Params<LIMBS>> Default for Fp12<LIMBS, P> {
+    fn default() -> Self {
+        Self::ZERO
+    }
+}
+
+impl<const LIMBS: usize, P: Fp2Params<LIMBS>> fmt::Debug for Fp2<LIMBS, P> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Fp2({:#x} + {:#x} * u)",
+            self.c0.to_uint(),
+            self.c1.to_uint()
+        )
+    }
+}
+
+impl<const LIMBS: usize, P: Fp6Params<LIMBS>> fmt::Debug for Fp6<LIMBS, P> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Fp6({:?} + {:?} * v + {:?} * v^2)",
+            self.c0, self.c1, self.c2
+        )
+    }
+}
+
+impl<const LIMBS: usize, P: Fp12Params<LIMBS>> fmt::Debug for Fp12<LIMBS, P> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Fp12({:?} + {:?} * w)", self.c0, self.c1)
+    }
+}
+
+/// Implements `+`, `-`, `*`, their assigning forms and unary `-` for an extension field type.
+macro_rules! impl_extension_ops {
+    ($ty:ident, $params:ident) => {
+        impl_extension_ops!(@binop $ty, $params, Add, add, AddAssign, add_assign);
+        impl_extension_ops!(@binop $ty, $params, Sub, sub, SubAssign, sub_assign);
+        impl_extension_ops!(@binop $ty, $params, Mul, mul, MulAssign, mul_assign);
+
+        impl<const LIMBS: usize, P: $params<LIMBS>> core::ops::Neg for $ty<LIMBS, P> {
+            type Output = Self;
+
+            fn neg(self) -> Self {
+                $ty::neg(&self)
+            }
+        }
+
+        impl<const LIMBS: usize, P: $params<LIMBS>> core::ops::Neg for &$ty<LIMBS, P> {
+            type Output = $ty<LIMBS, P>;
+
+            fn neg(self) -> $ty<LIMBS, P> {
+                $ty::neg(self)
+            }
+        }
+    };
+    (@binop $ty:ident, $params:ident, $trait:ident, $method:ident, $assign_trait:ident, $assign_method:ident) => {
+        impl<const LIMBS: usize, P: $params<LIMBS>> core::ops::$trait<&$ty<LIMBS, P>>
+            for &$ty<LIMBS, P>
+        {
+            type Output = $ty<LIMBS, P>;
+
+            fn $method(self, rhs: &$ty<LIMBS, P>) -> $ty<LIMBS, P> {
+                $ty::$method(self, rhs)
+            }
+        }
+
+        impl<const LIMBS: usize, P: $params<LIMBS>> core::ops::$trait<$ty<LIMBS, P>>
+            for &$ty<LIMBS, P>
+        {
+            type Output = $ty<LIMBS, P>;
+
+            fn $method(self, rhs: $ty<LIMBS, P>) -> $ty<LIMBS, P> {
+                $ty::$method(self, &rhs)
+            }
+        }
+
+        impl<const LIMBS: usize, P: $params<LIMBS>> core::ops::$trait<&$ty<LIMBS, P>>
+            for $ty<LIMBS, P>
+        {
+            type Output = $ty<LIMBS, P>;
+
+            fn $method(self, rhs: &$ty<LIMBS, P>) -> $ty<LIMBS, P> {
+                $ty::$method(&self, rhs)
+            }
+        }
+
+        impl<const LIMBS: usize, P: $params<LIMBS>> core::ops::$trait<$ty<LIMBS, P>>
+            for $ty<LIMBS, P>
+        {
+            type Output = $ty<LIMBS, P>;
+
+            fn $method(self, rhs: $ty<LIMBS, P>) -> $ty<LIMBS, P> {
+                $ty::$method(&self, &rhs)
+            }
+        }
+
+        impl<const LIMBS: usize, P: $params<LIMBS>> core::ops::$assign_trait<&$ty<LIMBS, P>>
+            for $ty<LIMBS, P>
+        {
+            fn $assign_method(&mut self, rhs: &$ty<LIMBS, P>) {
+                *self = $ty::$method(self, rhs);
+            }
+        }
+
+        impl<const LIMBS: usize, P: $params<LIMBS>> core::ops::$assign_trait<$ty<LIMBS, P>>
+            for $ty<LIMBS, P>
+        {
+            fn $assign_method(&mut self, rhs: $ty<LIMBS, P>) {
+                *self = $ty::$method(self, &rhs);
+            }
+        }
+    };
+}
+
+impl_extension_ops!(Fp2, Fp2Params);
+impl_extension_ops!(Fp6, Fp6Params);
+impl_extension_ops!(Fp12, Fp12Params);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::curves::bls12_381;
+
+    /// `2^64 - 59` with `β = 2`, exercising the multiplications by a generic `β`.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    struct P64;
+
+    impl FieldParams<1> for P64 {
+        const MODULUS: Uint<1> = Uint::from_u64(0xFFFF_FFFF_FFFF_FFC5);
+    }
+
+    impl Fp2Params<1> for P64 {
+        const FP2_NONRESIDUE: Fp<1, P64> = Fp::from_uint_const(&Uint::from_u64(2));
+    }
+
+    /// The BN254 base field with `β = -1` and `ξ = 9 + u`.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    struct Bn254Base;
+
+    const fn bn(hex: &str) -> Fp<4, Bn254Base> {
+        Fp::from_uint_const(&Uint::from_be_hex(hex))
+    }
+
+    impl FieldParams<4> for Bn254Base {
+        const MODULUS: Uint<4> =
+            Uint::from_be_hex("30644e72e131a029b85045b68181585d97816a916871ca8d3c208c16d87cfd47");
+    }
+
+    impl Fp2Params<4> for Bn254Base {
+        const FP2_NONRESIDUE: Fp<4, Self> = Fp::ONE.neg();
+    }
+
+    impl Fp6Params<4> for Bn254Base {
+        const FP6_NONRESIDUE: Fp2<4, Self> =
+            Fp2::new(Fp::from_uint_const(&Uint::from_u64(9)), Fp::ONE);
+        const FROBENIUS_V: Fp2<4, Self> = Fp2::new(
+            bn("2fb347984f7911f74c0bec3cf559b143b78cc310c2c3330c99e39557176f553d"),
+            bn("16c9e55061ebae204ba4cc8bd75a079432ae2a1d0b7c9dce1665d51c640fcba2"),
+        );
+        const FROBENIUS_V2: Fp2<4, Self> = Fp2::new(
+            bn("05b54f5e64eea80180f3c0b75a181e84d33365f7be94ec72848a1f55921ea762"),
+            bn("2c145edbe7fd8aee9f3a80b03b0b1c923685d2ea1bdec763c13b4711cd2b8126"),
+        );
+    }
+
+    impl Fp12Params<4> for Bn254Base {
+        const FROBENIUS_W: Fp2<4, Self> = Fp2::new(
+            bn("1284b71c2865a7dfe8b99fdd76e68b605c521e08292f2176d60b35dadcc9e470"),
+            bn("246996f3b4fae7e6a6327cfe12150b8e747992778eeec7e5ca5cf05f80f362ac"),
+        );
+    }
+
+    fn sample_fp2<const LIMBS: usize, P: Fp2Params<LIMBS>>(seed: u64) -> Fp2<LIMBS, P> {
+        Fp2::new(
+            Fp::from_u64(seed).pow_vartime(&Uint::<1>::from_u64(101)),
+            Fp::from_u64(seed + 1).pow_vartime(&Uint::<1>::from_u64(103)),
+        )
+    }
+
+    fn sample_fp12<const LIMBS: usize, P: Fp12Params<LIMBS>>(seed: u64) -> Fp12<LIMBS, P> {
+        let fp6 = |s| Fp6::new(sample_fp2(s), sample_fp2(s + 2), sample_fp2(s + 4));
+        Fp12::new(fp6(seed), fp6(seed + 6))
+    }
+
+    fn check_fp2<const LIMBS: usize, P: Fp2Params<LIMBS>>() {
+        let u = Fp2::<LIMBS, P>::new(Fp::ZERO, Fp::ONE);
+        assert_eq!(u.square(), Fp2::from_base(P::FP2_NONRESIDUE));
+        assert_eq!(u.conjugate(), -u);
+
+        let (a, b) = (sample_fp2::<LIMBS, P>(3), sample_fp2::<LIMBS, P>(7));
+        assert_eq!(a.square(), a * a);
+        assert_eq!(a * b, b * a);
+        assert_eq!(a * a.invert().unwrap(), Fp2::ONE);
+        assert_eq!(Fp2::from_base(a.norm()), a * a.conjugate());
+        assert_eq!(a.frobenius_map(), a.pow_vartime(&P::MODULUS));
+        assert!(Fp2::<LIMBS, P>::ZERO.invert().is_none());
+
+        for x in [
+            a,
+            b,
+            u,
+            Fp2::from_base(a.c0),
+            Fp2::from_base(P::FP2_NONRESIDUE),
+            Fp2::ZERO,
+        ] {
+            let root = x.square().sqrt().unwrap();
+            assert!(root == x || root == -x);
+        }
+        // An element of Fp2 is a square exactly when its norm is a square in Fp.
+        for seed in 10..20 {
+            let x = sample_fp2::<LIMBS, P>(seed);
+            let root = x.sqrt();
+            assert_eq!(root.is_some(), x.norm().legendre() == 1);
+            assert!(root.is_none_or(|r| r.square() == x));
+        }
+    }
+
+    fn check_tower<const LIMBS: usize, P: Fp12Params<LIMBS>>() {
+        let v = Fp6::<LIMBS, P>::new(Fp2::ZERO, Fp2::ONE, Fp2::ZERO);
+        assert_eq!(
+            v.square() * v,
+            Fp6::new(P::FP6_NONRESIDUE, Fp2::ZERO, Fp2::ZERO)
+        );
+        let w = Fp12::<LIMBS, P>::new(Fp6::ZERO, Fp6::ONE);
+        assert_eq!(w.square(), Fp12::new(v, Fp6::ZERO));
+
+        let (a, b, c) = (sample_fp12::<LIMBS, P>(1), sample_fp12(20), sample_fp12(40));
+        assert_eq!(a.square(), a * a);
+        assert_eq!(a.c0.square(), a.c0 * a.c0);
+        assert_eq!((a * b) * c, a * (b * c));
+        assert_eq!(a * (b + c), a * b + a * c);
+        assert_eq!(a * a.invert().unwrap(), Fp12::ONE);
+        assert_eq!(a.c0 * a.c0.invert().unwrap(), Fp6::ONE);
+        assert!(Fp12::<LIMBS, P>::ZERO.invert().is_none());
+
+        // The Frobenius constants must agree with exponentiation by p.
+        assert_eq!(a.frobenius_map(), a.pow_vartime(&P::MODULUS));
+        let mut f = a;
+        for _ in 0..6 {
+            f = f.frobenius_map();
+        }
+        assert_eq!(f, a.conjugate());
+    }
+
+    #[test]
+    fn test_fp2() {
+        check_fp2::<1, P64>();
+        check_fp2::<4, Bn254Base>();
+        check_fp2::<6, bls12_381::BaseParams>();
+    }
+
+    #[test]
+    fn test_tower() {
+        check_tower::<4, Bn254Base>();
+        check_tower::<6, bls12_381::BaseParams>();
+    }
+
+    #[test]
+    fn test_operators_and_debug() {
+        let a = sample_fp2::<1, P64>(3);
+        let mut b = a;
+        b += &a;
+        b -= a;
+        b *= Fp2::ONE;
+        assert_eq!(b, a);
+        assert_eq!(a + a, a.double());
+        assert_eq!(
+            format!("{:?}", Fp2::<1, P64>::ONE),
+            "Fp2(0x0000000000000001 + 0x0000000000000000 * u)"
+        );
+        assert_eq!(Fp12::<4, Bn254Base>::default(), Fp12::ZERO);
+    }
+}
@@ -0,0 +1,704 @@
+//! Prime field elements with a compile-time modulus.
+//!
+//! A field is declared by implementing [`FieldParams`] on a zero-sized marker type; [`Fp`] then
+//! provides the arithmetic. By default elements are stored in Montgomery form, with the Montgomery
+//! constants derived from the modulus at compile time. Primes of a special shape can instead select
+//! one of the routines in [`crate::reduce`] through [`FieldParams::REDUCTION`].
+//!
+//! ```rust
+//! use nail::{FieldParams, Fp, Uint};
+//!
+//! #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+//! struct Secp256k1Base;
+//!
+//! impl FieldParams<4> for Secp256k1Base {
+//!     const MODULUS: Uint<4> =
+//!         Uint::from_be_hex("FFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFEFFFFFC2F");
+//! }
+//!
+//! type Fe = Fp<4, Secp256k1Base>;
+//!
+//! let x = Fe::from_u64(7);
+//! let y = x * x.invert().unwrap();
+//! assert_eq!(y, Fe::ONE);
+//! ```
+
+use core::{fmt, marker::PhantomData};
+
+use crate::{
+    ct::{fixed_window, Choice, ConditionallySelectable},
+    modular::{self, SqrtField},
+    monty::MontyParams,
+    uint::Uint,
+};
+
+/// How an [`Fp`] reduces products and which representation its elements use.
+#[derive(Debug, Clone, Copy)]
+pub enum Reduction<const LIMBS: usize> {
+    /// Montgomery multiplication; elements are stored in Montgomery form.
+    Montgomery(MontyParams<LIMBS>),
+    /// A special-form reduction of the double-width product, such as those in
+    /// [`crate::reduce`]; elements are stored in canonical form.
+    ///
+    /// The function must return the fully reduced residue of `lo + hi * 2^(64 * LIMBS)`.
+    Special(fn(&Uint<LIMBS>, &Uint<LIMBS>) -> Uint<LIMBS>),
+}
+
+/// Compile-time description of a prime field.
+///
+/// Only the modulus is required; it must be an odd prime. The reduction defaults to Montgomery
+/// multiplication with constants computed during constant evaluation.
+pub trait FieldParams<const LIMBS: usize>: Copy + fmt::Debug + Eq + Send + Sync + 'static {
+    /// The field modulus `p`.
+    const MODULUS: Uint<LIMBS>;
+
+    /// The reduction backend used for multiplication.
+    const REDUCTION: Reduction<LIMBS> = Reduction::Montgomery(MontyParams::new(&Self::MODULUS));
+}
+
+/// An element of the prime field described by `P`.
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Fp<const LIMBS: usize, P: FieldParams<LIMBS>> {
+    /// The element in the backend's representation, always fully reduced.
+    repr: Uint<LIMBS>,
+    _params: PhantomData<P>,
+}
+
+impl<const LIMBS: usize, P: FieldParams<LIMBS>> Fp<LIMBS, P> {
+    /// The field modulus.
+    pub const MODULUS: Uint<LIMBS> = P::MODULUS;
+
+    /// The additive identity.
+    pub const ZERO: Self = Self::from_repr(Uint::ZERO);
+
+    /// The multiplicative identity.
+    pub const ONE: Self = Self::from_repr(match P::REDUCTION {
+        Reduction::Montgomery(monty) => *monty.one(),
+        Reduction::Special(_) => Uint::ONE,
+    });
+
+    const fn from_repr(repr: Uint<LIMBS>) -> Self {
+        Self {
+            repr,
+            _params: PhantomData,
+        }
+    }
+
+    /// Creates an element from an integer, reducing it modulo `p`.
+    pub fn from_uint(x: &Uint<LIMBS>) -> Self {
+        Self::from_repr(match P::REDUCTION {
+            Reduction::Montgomery(monty) => monty.to_monty(x),
+            Reduction::Special(reduce) => reduce(x, &Uint::ZERO),
+        })
+    }
+
+    /// Creates an element from an integer already below `p` in a `const` context.
+    ///
+    /// Intended for constants such as curve coefficients; panics if `x >= p`.
+    pub const fn from_uint_const(x: &Uint<LIMBS>) -> Self {
+        let (_, below) = x.overflowing_sub(&P::MODULUS);
+        assert!(below, "value is not reduced modulo the field prime");
+        Self::from_repr(match P::REDUCTION {
+            Reduction::Montgomery(monty) => monty.to_monty(x),
+            Reduction::Special(_) => *x,
+        })
+    }
+
+    /// Creates an element from a `u64`, reducing it modulo `p`.
+    pub fn from_u64(x: u64) -> Self {
+        Self::from_uint(&Uint::from_u64(x))
+    }
+
+    /// Reduces the double-width integer `lo + hi * 2^(64 * LIMBS)` modulo `p`.
+    pub fn from_wide(lo: &Uint<LIMBS>, hi: &Uint<LIMBS>) -> Self {
+        Self::from_repr(match P::REDUCTION {
+            // to_monty(hi) is the integer hi * R mod p; converting it once more gives its own form
+            Reduction::Montgomery(monty) => {
+                monty.add(&monty.to_monty(lo), &monty.to_monty(&monty.to_monty(hi)))
+            }
+            Reduction::Special(reduce) => reduce(lo, hi),
+        })
+    }
+
+    /// Creates an element from its canonical integer representation.
+    ///
+    /// Returns `None` if `x >= p`.
+    pub fn from_canonical(x: &Uint<LIMBS>) -> Option<Self> {
+        if *x < P::MODULUS {
+            Some(Self::from_uint(x))
+        } else {
+            None
+        }
+    }
+
+    /// Returns the canonical integer representation in `[0, p)`.
+    pub fn to_uint(&self) -> Uint<LIMBS> {
+        match P::REDUCTION {
+            Reduction::Montgomery(monty) => monty.from_monty(&self.repr),
+            Reduction::Special(_) => self.repr,
+        }
+    }
+
+    /// Decodes a canonical big-endian encoding of exactly `LIMBS * 8` bytes.
+    ///
+    /// Returns `None` for the wrong length or for values that are not reduced modulo `p`.
+    pub fn from_be_bytes(bytes: &[u8]) -> Option<Self> {
+        if bytes.len() != LIMBS * 8 {
+            return None;
+        }
+        Self::from_canonical(&Uint::from_be_bytes(bytes)?)
+    }
+
+    /// Decodes a canonical little-endian encoding of exactly `LIMBS * 8` bytes.
+    ///
+    /// Returns `None` for the wrong length or for values that are not reduced modulo `p`.
+    pub fn from_le_bytes(bytes: &[u8]) -> Option<Self> {
+        if bytes.len() != LIMBS * 8 {
+            return None;
+        }
+        Self::from_canonical(&Uint::from_le_bytes(bytes)?)
+    }
+
+    /// Reduces a little-endian integer of exactly `LIMBS * 16` bytes modulo `p`, as used to derive
+    /// nearly uniform elements from hash output.
+    ///
+    /// Returns `None` for the wrong length.
+    pub fn from_le_bytes_wide(bytes: &[u8]) -> Option<Self> {
+        if bytes.len() != LIMBS * 16 {
+            return None;
+        }
+        let (lo, hi) = bytes.split_at(LIMBS * 8);
+        Some(Self::from_wide(
+            &Uint::from_le_bytes(lo)?,
+            &Uint::from_le_bytes(hi)?,
+        ))
+    }
+
+    /// Writes the canonical big-endian encoding into `out`, which must be `LIMBS * 8` bytes long.
+    pub fn write_be_bytes(&self, out: &mut [u8]) {
+        self.to_uint().write_be_bytes(out);
+    }
+
+    /// Writes the canonical little-endian encoding into `out`, which must be `LIMBS * 8` bytes
+    /// long.
+    pub fn write_le_bytes(&self, out: &mut [u8]) {
+        self.to_uint().write_le_bytes(out);
+    }
+
+    /// Returns `true` if the element is zero.
+    pub const fn is_zero(&self) -> bool {
+        self.repr.is_zero()
+    }
+
+    /// Returns `self + other`.
+    pub const fn add(&self, other: &Self) -> Self {
+        Self::from_repr(self.repr.add_mod(&other.repr, &P::MODULUS))
+    }
+
+    /// Returns `self - other`.
+    pub const fn sub(&self, other: &Self) -> Self {
+        Self::from_repr(self.repr.sub_mod(&other.repr, &P::MODULUS))
+    }
+
+    /// Returns `a` when `choice` is 0 and `b` when `choice` is 1, without branching.
+    pub(crate) const fn select(a: &Self, b: &Self, choice: u64) -> Self {
+        Self::from_repr(Uint::select(&a.repr, &b.repr, choice))
+    }
+
+    /// Returns `self * other`.
+    pub fn mul(&self, other: &Self) -> Self {
+        Self::from_repr(match P::REDUCTION {
+            Reduction::Montgomery(monty) => monty.mul(&self.repr, &other.repr),
+            Reduction::Special(reduce) => {
+                let (lo, hi) = self.repr.widening_mul(&other.repr);
+                reduce(&lo, &hi)
+            }
+        })
+    }
+
+    /// Returns `self^2`.
+    pub fn square(&self) -> Self {
+        self.mul(self)
+    }
+
+    /// Returns `2 * self`.
+    pub const fn double(&self) -> Self {
+        self.add(self)
+    }
+
+    /// Returns `-self`.
+    pub const fn neg(&self) -> Self {
+        Self::from_repr(self.repr.neg_mod(&P::MODULUS))
+    }
+
+    /// Raises the element to `exp` in time that depends only on the exponent width.
+    ///
+    /// Uses a fixed 4-bit window, like [`MontyParams::pow`].
+    pub fn pow<const E: usize>(&self, exp: &Uint<E>) -> Self {
+        fixed_window(Self::ONE, self, exp, Self::mul, Self::square)
+    }
+
+    /// Raises the element to a public exponent, skipping its leading zero bits.
+    pub fn pow_vartime<const E: usize>(&self, exp: &Uint<E>) -> Self {
+        let mut acc = Self::ONE;
+        for i in (0..exp.bits()).rev() {
+            acc = acc.square();
+            if exp.bit(i) {
+                acc = acc.mul(self);
+            }
+        }
+        acc
+    }
+
+    /// Returns the multiplicative inverse, or `None` for zero.
+    ///
+    /// Computed as `self^(p - 2)` by Fermat's little theorem.
+    pub fn invert(&self) -> Option<Self> {
+        let inverse = self.pow(&P::MODULUS.sub(&Uint::<1>::from_u64(2)));
+        if self.is_zero() {
+            None
+        } else {
+            Some(inverse)
+        }
+    }
+
+    /// Returns the Legendre symbol: `1` for a non-zero square, `-1` for a non-square and `0` for
+    /// zero.
+    pub fn legendre(&self) -> i8 {
+        modular::legendre(self, &P::MODULUS)
+    }
+
+    /// Returns a square root of the element, or `None` if it is not a square.
+    ///
+    /// Uses the `(p + 1) / 4` exponent when `p = 3 mod 4`, Atkin's method when `p = 5 mod 8` and
+    /// Tonelli–Shanks otherwise. Only the Tonelli–Shanks path is variable-time.
+    pub fn sqrt(&self) -> Option<Self> {
+        modular::sqrt(self, &P::MODULUS)
+    }
+}
+
+impl<const LIMBS: usize, P: FieldParams<LIMBS>> SqrtField for Fp<LIMBS, P> {
+    fn constant(&self, x: u64) -> Self {
+        Self::from_u64(x)
+    }
+
+    fn mul(&self, other: &Self) -> Self {
+        Fp::mul(self, other)
+    }
+
+    fn sub(&self, other: &Self) -> Self {
+        Fp::sub(self, other)
+    }
+
+    fn square(&self) -> Self {
+        Fp::square(self)
+    }
+
+    fn pow_vartime<const E: usize>(&self, exp: &Uint<E>) -> Self {
+        Fp::pow_vartime(self, exp)
+    }
+
+    fn is_zero(&self) -> bool {
+        Fp::is_zero(self)
+    }
+}
+
+impl<const LIMBS: usize, P: FieldParams<LIMBS>> ConditionallySelectable for Fp<LIMBS, P> {
+    fn conditional_select(a: &Self, b: &Self, choice: Choice) -> Self {
+        Self::select(a, b, choice.to_bit())
+    }
+}
+
+impl<const LIMBS: usize, P: FieldParams<LIMBS>> Default for Fp<LIMBS, P> {
+    fn default() -> Self {
+        Self::ZERO
+    }
+}
+
+impl<const LIMBS: usize, P: FieldParams<LIMBS>> fmt::Debug for Fp<LIMBS, P> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Fp({:#x})", self.to_uint())
+    }
+}
+
+impl<const LIMBS: usize, P: FieldParams<LIMBS>> fmt::Display for Fp<LIMBS, P> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:#x}", self.to_uint())
+    }
+}
+
+/// Implements a binary operator and its assigning form for all owned/borrowed combinations.
+macro_rules! impl_field_binop {
+    ($trait:ident, $method:ident, $assign_trait:ident, $assign_method:ident) => {
+        impl<const LIMBS: usize, P: FieldParams<LIMBS>> core::ops::$trait<&Fp<LIMBS, P>>
+            for &Fp<LIMBS, P>
+        {
+            type Output = Fp<LIMBS, P>;
+
+            fn $method(self, rhs: &Fp<LIMBS, P>) -> Fp<LIMBS, P> {
+                Fp::$method(self, rhs)
+            }
+        }
+
+        impl<const LIMBS: usize, P: FieldParams<LIMBS>> core::ops::$trait<Fp<LIMBS, P>>
+            for &Fp<LIMBS, P>
+        {
+            type Output = Fp<LIMBS, P>;
+
+            fn $method(self, rhs: Fp<LIMBS, P>) -> Fp<LIMBS, P> {
+                Fp::$method(self, &rhs)
+            }
+        }
+
+        impl<const LIMBS: usize, P: FieldParams<LIMBS>> core::ops::$trait<&Fp<LIMBS, P>>
+            for Fp<LIMBS, P>
+        {
+            type Output = Fp<LIMBS, P>;
+
+            fn $method(self, rhs: &Fp<LIMBS, P>) -> Fp<LIMBS, P> {
+                Fp::$method(&self, rhs)
+            }
+        }
+
+        impl<const LIMBS: usize, P: FieldParams<LIMBS>> core::ops::$trait<Fp<LIMBS, P>>
+            for Fp<LIMBS, P>
+        {
+            type Output = Fp<LIMBS, P>;
+
+            fn $method(self, rhs: Fp<LIMBS, P>) -> Fp<LIMBS, P> {
+                Fp::$method(&self, &rhs)
+            }
+        }
+
+        impl<const LIMBS: usize, P: FieldParams<LIMBS>> core::ops::$assign_trait<&Fp<LIMBS, P>>
+            for Fp<LIMBS, P>
+        {
+            fn $assign_method(&mut self, rhs: &Fp<LIMBS, P>) {
+                *self = Fp::$method(self, rhs);
+            }
+        }
+
+        impl<const LIMBS: usize, P: FieldParams<LIMBS>> core::ops::$assign_trait<Fp<LIMBS, P>>
+            for Fp<LIMBS, P>
+        {
+            fn $assign_method(&mut self, rhs: Fp<LIMBS, P>) {
+                *self = Fp::$method(self, &rhs);
+            }
+        }
+    };
+}
+
+impl_field_binop!(Add, add, AddAssign, add_assign);
+impl_field_binop!(Sub, sub, SubAssign, sub_assign);
+impl_field_binop!(Mul, mul, MulAssign, mul_assign);
+
+impl<const LIMBS: usize, P: FieldParams<LIMBS>> core::ops::Neg for Fp<LIMBS, P> {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Fp::neg(&self)
+    }
+}
+
+impl<const LIMBS: usize, P: FieldParams<LIMBS>> core::ops::Neg for &Fp<LIMBS, P> {
+    type Output = Fp<LIMBS, P>;
+
+    fn neg(self) -> Fp<LIMBS, P> {
+        Fp::neg(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// 2^64 - 59, congruent to 1 mod 4 so `sqrt` takes the Tonelli–Shanks path.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    struct P64;
+
+    impl FieldParams<1> for P64 {
+        const MODULUS: Uint<1> = Uint::from_u64(0xFFFF_FFFF_FFFF_FFC5);
+    }
+
+    /// The secp256k1 base field, congruent to 3 mod 4.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    struct Secp256k1Base;
+
+    impl FieldParams<4> for Secp256k1Base {
+        const MODULUS: Uint<4> =
+            Uint::from_be_hex("FFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFEFFFFFC2F");
+    }
+
+    /// The BN254 scalar field, with 2-adicity 28.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    struct Bn254Scalar;
+
+    impl FieldParams<4> for Bn254Scalar {
+        const MODULUS: Uint<4> =
+            Uint::from_be_hex("30644E72E131A029B85045B68181585D2833E84879B9709143E1F593F0000001");
+    }
+
+    /// The secp256k1 base field again, reduced with the pseudo-Mersenne routine.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    struct Secp256k1Special;
+
+    impl FieldParams<4> for Secp256k1Special {
+        const MODULUS: Uint<4> = Secp256k1Base::MODULUS;
+        const REDUCTION: Reduction<4> = Reduction::Special(crate::reduce::reduce_secp256k1);
+    }
+
+    /// The Goldilocks field `2^64 - 2^32 + 1` with its dedicated reduction.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    struct Goldilocks;
+
+    impl FieldParams<1> for Goldilocks {
+        const MODULUS: Uint<1> = Uint::from_u64(0xFFFF_FFFF_0000_0001);
+        const REDUCTION: Reduction<1> = Reduction::Special(crate::reduce::reduce_goldilocks);
+    }
+
+    /// The Curve25519 base field `2^255 - 19`, congruent to 5 mod 8.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    struct P25519;
+
+    impl FieldParams<4> for P25519 {
+        const MODULUS: Uint<4> =
+            Uint::from_be_hex("7FFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFED");
+    }
+
+    type F64 = Fp<1, P64>;
+    type Fk = Fp<4, Secp256k1Base>;
+    type Fr = Fp<4, Bn254Scalar>;
+
+    const P64_VALUE: u64 = 0xFFFF_FFFF_FFFF_FFC5;
+
+    #[test]
+    fn test_identities() {
+        assert!(Fk::ZERO.is_zero());
+        assert_eq!(Fk::ONE.to_uint(), Uint::ONE);
+        assert_eq!(Fk::from_u64(1), Fk::ONE);
+        assert_eq!(Fk::default(), Fk::ZERO);
+        assert_eq!(Fk::ONE.neg() + Fk::ONE, Fk::ZERO);
+    }
+
+    #[test]
+    fn test_arithmetic_matches_u128() {
+        let values = [
+            0,
+            1,
+            2,
+            3,
+            0x1234_5678_9ABC_DEF0,
+            P64_VALUE - 1,
+            P64_VALUE - 2,
+        ];
+        for &a in &values {
+            for &b in &values {
+                let (fa, fb) = (F64::from_u64(a), F64::from_u64(b));
+                let p = P64_VALUE as u128;
+                assert_eq!(
+                    (fa + fb).to_uint().limbs[0] as u128,
+                    (a as u128 + b as u128) % p
+                );
+                assert_eq!(
+                    (fa - fb).to_uint().limbs[0] as u128,
+                    (a as u128 + p - b as u128) % p
+                );
+                assert_eq!(
+                    (fa * fb).to_uint().limbs[0] as u128,
+                    (a as u128 * b as u128) % p
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_reduction_on_input() {
+        let p_plus_one = Fk::MODULUS.add(&Uint::<1>::ONE);
+        assert_eq!(Fk::from_uint(&p_plus_one), Fk::ONE);
+        assert_eq!(Fk::from_uint(&Fk::MODULUS), Fk::ZERO);
+        assert!(Fk::from_canonical(&Fk::MODULUS).is_none());
+        assert!(Fk::from_canonical(&Fk::MODULUS.sub(&Uint::<1>::ONE)).is_some());
+    }
+
+    #[test]
+    fn test_wide_reduction() {
+        let (lo, hi) = (0xDEAD_BEEF_0123_4567u64, 0xFFFF_FFFF_FFFF_FFFFu64);
+        let wide = ((hi as u128) << 64) | lo as u128;
+
+        let expected = (wide % P64_VALUE as u128) as u64;
+        let x = F64::from_wide(&Uint::from_u64(lo), &Uint::from_u64(hi));
+        assert_eq!(x.to_uint().limbs[0], expected);
+
+        let goldilocks = 0xFFFF_FFFF_0000_0001u64;
+        let y = Fp::<1, Goldilocks>::from_wide(&Uint::from_u64(lo), &Uint::from_u64(hi));
+        assert_eq!(y.to_uint().limbs[0], (wide % goldilocks as u128) as u64);
+
+        let mut bytes = [0u8; 16];
+        bytes[..8].copy_from_slice(&lo.to_le_bytes());
+        bytes[8..].copy_from_slice(&hi.to_le_bytes());
+        assert_eq!(F64::from_le_bytes_wide(&bytes), Some(x));
+        assert_eq!(F64::from_le_bytes_wide(&bytes[..8]), None);
+    }
+
+    #[test]
+    fn test_operators_and_assign() {
+        let a = Fk::from_u64(10);
+        let b = Fk::from_u64(4);
+        let mut c = a;
+        c += b;
+        assert_eq!(c, Fk::from_u64(14));
+        c -= &b;
+        assert_eq!(c, a);
+        c *= b;
+        assert_eq!(c, Fk::from_u64(40));
+        assert_eq!(-(&b) + a, Fk::from_u64(6));
+        assert_eq!(a.square(), Fk::from_u64(100));
+        assert_eq!(a.double(), Fk::from_u64(20));
+    }
+
+    #[test]
+    fn test_invert() {
+        assert!(Fk::ZERO.invert().is_none());
+        for x in [1u64, 2, 3, 0xDEAD_BEEF, u64::MAX] {
+            let a = Fk::from_u64(x);
+            assert_eq!(a * a.invert().unwrap(), Fk::ONE);
+            let b = Fr::from_u64(x);
+            assert_eq!(b * b.invert().unwrap(), Fr::ONE);
+        }
+    }
+
+    #[test]
+    fn test_pow() {
+        let a = Fk::from_u64(3);
+        assert_eq!(a.pow(&Uint::<1>::from_u64(5)), Fk::from_u64(243));
+        assert_eq!(a.pow(&Uint::<1>::ZERO), Fk::ONE);
+        assert_eq!(a.pow(&Fk::MODULUS.sub(&Uint::<1>::ONE)), Fk::ONE);
+        assert_eq!(a.pow_vartime(&Uint::<1>::from_u64(5)), Fk::from_u64(243));
+    }
+
+    #[test]
+    fn test_legendre() {
+        assert_eq!(Fk::ZERO.legendre(), 0);
+        assert_eq!(Fk::from_u64(4).legendre(), 1);
+        // -1 is a non-square when p = 3 mod 4
+        assert_eq!(Fk::ONE.neg().legendre(), -1);
+        // 5 is the smallest non-residue modulo the BN254 scalar field
+        assert_eq!(Fr::from_u64(5).legendre(), -1);
+        assert_eq!(Fr::from_u64(6).legendre(), 1);
+    }
+
+    #[test]
+    fn test_sqrt_three_mod_four() {
+        for x in [0u64, 1, 2, 7, 0xFFFF_FFFF] {
+            let square = Fk::from_u64(x).square();
+            let root = square.sqrt().unwrap();
+            assert_eq!(root.square(), square);
+        }
+        assert!(Fk::ONE.neg().sqrt().is_none());
+    }
+
+    #[test]
+    fn test_sqrt_tonelli_shanks() {
+        for x in [0u64, 1, 2, 5, 0x1234_5678, u64::MAX] {
+            let square = Fr::from_u64(x).square();
+            let root = square.sqrt().unwrap();
+            assert_eq!(root.square(), square);
+
+            let small = F64::from_u64(x).square();
+            assert_eq!(small.sqrt().unwrap().square(), small);
+        }
+        assert!(Fr::from_u64(5).sqrt().is_none());
+    }
+
+    #[test]
+    fn test_sqrt_atkin() {
+        type F25519 = Fp<4, P25519>;
+        for x in [0u64, 1, 2, 3, 0xDEAD_BEEF] {
+            let square = F25519::from_u64(x).square();
+            assert_eq!(square.sqrt().unwrap().square(), square);
+        }
+        // 2 is a non-residue modulo primes that are 5 mod 8
+        assert!(F25519::from_u64(2).sqrt().is_none());
+        assert_eq!(
+            F25519::ONE.neg().sqrt().unwrap().square(),
+            F25519::ONE.neg()
+        );
+    }
+
+    #[test]
+    fn test_byte_encoding() {
+        let a = Fk::from_uint(&Uint::from_be_hex(
+            "0102030405060708090A0B0C0D0E0F101112131415161718191A1B1C1D1E1F20",
+        ));
+        let mut be = [0u8; 32];
+        a.write_be_bytes(&mut be);
+        assert_eq!(be[0], 0x01);
+        assert_eq!(be[31], 0x20);
+        assert_eq!(Fk::from_be_bytes(&be), Some(a));
+
+        let mut le = [0u8; 32];
+        a.write_le_bytes(&mut le);
+        assert_eq!(le[0], 0x20);
+        assert_eq!(Fk::from_le_bytes(&le), Some(a));
+
+        // p itself and anything above it are rejected
+        let mut p_bytes = [0u8; 32];
+        Fk::MODULUS.write_be_bytes(&mut p_bytes);
+        assert_eq!(Fk::from_be_bytes(&p_bytes), None);
+        assert_eq!(Fk::from_be_bytes(&[0xFF; 32]), None);
+        assert_eq!(Fk::from_be_bytes(&be[1..]), None);
+    }
+
+    #[test]
+    fn test_debug_shows_canonical_value() {
+        let formatted = format!("{:?}", F64::from_u64(0xABCD));
+        assert_eq!(formatted, "Fp(0x000000000000abcd)");
+    }
+
+    #[test]
+    fn test_special_backend_matches_montgomery() {
+        type Fs = Fp<4, Secp256k1Special>;
+
+        assert_eq!(Fs::ONE.to_uint(), Uint::ONE);
+        let mut a = Fk::from_u64(0x1234_5678_9ABC_DEF0);
+        let mut b = Fs::from_u64(0x1234_5678_9ABC_DEF0);
+        for _ in 0..50 {
+            a = a.square() + Fk::from_u64(3);
+            b = b.square() + Fs::from_u64(3);
+            assert_eq!(a.to_uint(), b.to_uint());
+        }
+        assert_eq!(b * b.invert().unwrap(), Fs::ONE);
+        assert_eq!(b.square().sqrt().unwrap().square(), b.square());
+        assert_eq!(
+            Fs::from_uint(&Uint::MAX).to_uint(),
+            Fk::from_uint(&Uint::MAX).to_uint()
+        );
+    }
+
+    #[test]
+    fn test_goldilocks_field() {
+        type Fg = Fp<1, Goldilocks>;
+        let p = 0xFFFF_FFFF_0000_0001u128;
+
+        let values = [
+            0,
+            1,
+            2,
+            0xFFFF_FFFF,
+            0xFFFF_FFFF_0000_0000,
+            0x1234_5678_9ABC_DEF0,
+        ];
+        for &a in &values {
+            for &b in &values {
+                let product = Fg::from_u64(a) * Fg::from_u64(b);
+                assert_eq!(
+                    product.to_uint().limbs[0] as u128,
+                    (a as u128 * b as u128) % p
+                );
+            }
+        }
+        // The multiplicative group has order 2^32 * (2^32 - 1), so sqrt uses Tonelli–Shanks
+        let x = Fg::from_u64(7).square();
+        assert_eq!(x.sqrt().unwrap().square(), x);
+    }
+}
@@ -11,7 +11,29 @@
 //! - **Compile-time sizing**: `Uint<N>` where N is the number of 64-bit limbs
 //! - **Zero-allocation arithmetic**: All operations use stack-allocated arrays
 //! - **Mixed-size operations**: Add/multiply integers with different limb counts
+//! - **Literals**: `uint!` builds constants from decimal, hex or binary literals at compile time
+//! - **Runtime widths**: `BoxedUint` with a limb count chosen at runtime (`alloc` feature)
+//! - **Constant time**: `Choice` masks, `ct_eq`/`ct_lt` comparisons and conditional select, swap
+//!   and negate, with branch-free arithmetic underneath
+//! - **Secrets**: `SecretUint` zeroizes on drop, redacts `Debug` and offers only constant-time
+//!   arithmetic
 //! - **Modular arithmetic**: Built-in support for modular operations
+//! - **Number theory**: Modular square roots, Legendre and Jacobi symbols
+//! - **Prime fields**: `Fp<LIMBS, P>` with a compile-time modulus and Montgomery multiplication
+//! - **Extension fields**: `Fp2`, `Fp6` and `Fp12` towers over any prime field
+//! - **Elliptic curves**: Short Weierstrass group arithmetic for secp256k1, P-256 and P-384, and
+//!   X25519 and Edwards25519, and the BLS12-381 pairing
+//! - **Signatures**: ECDSA over prehashed messages with RFC 6979 nonces and key recovery
+//! - **Generic numerics**: `num-traits` implementations for `Uint` (`num-traits` feature)
+//! - **Interop**: Conversions to and from `num-bigint`, `rug` and `malachite` integers (features of
+//!   the same name)
+//! - **Serialization**: `0x` hex strings for human-readable formats and fixed-width big-endian
+//!   bytes for binary ones (`serde` feature)
+//! - **Zero-copy views**: `zerocopy` traits for casting byte buffers to `Uint` slices in place
+//!   (`zerocopy` feature)
+//! - **Random sampling**: Uniform `Uint` and field elements from a caller-supplied RNG
+//! - **Primality**: Miller–Rabin and Baillie–PSW probable prime tests
+//! - **RSA**: Key generation and raw RFC 8017 primitives with CRT and blinding
 //! - **Extensive testing**: Comprehensive test suite including edge cases
 //! 
 //! ## Examples
@@ -41,9 +63,42 @@
 //! memory usage and minimal allocation overhead are critical. See the benchmark
 //! results in the repository for detailed performance comparisons.
 
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
+#[cfg(feature = "alloc")]
+pub mod boxed;
+#[cfg(any(feature = "malachite", feature = "num-bigint", feature = "rug"))]
+mod convert;
+pub mod ct;
+pub mod curves;
+pub mod ecdsa;
+pub mod extension;
+pub mod field;
+mod limb;
+mod modular;
+pub mod monty;
+#[cfg(feature = "num-traits")]
+mod num;
+pub mod prime;
+pub mod rand;
+pub mod reduce;
+pub mod rsa;
+pub mod secret;
+#[cfg(feature = "serde")]
+pub mod serde;
 pub mod uint;
+pub mod weierstrass;
 
-pub use uint::Uint;
+#[cfg(feature = "alloc")]
+pub use boxed::{BoxedMontyParams, BoxedUint};
+#[cfg(any(feature = "malachite", feature = "num-bigint", feature = "rug"))]
+pub use convert::TryFromBigIntError;
+pub use ct::{Choice, CtTable};
+pub use field::{FieldParams, Fp, Reduction};
+pub use monty::MontyParams;
+pub use secret::SecretUint;
+pub use uint::{ParseUintError, Uint};
 
 /// Type alias for 64-bit unsigned integer (1 limb)
 pub type U64 = Uint<1>;
@@ -51,11 +106,32 @@ pub type U64 = Uint<1>;
 /// Type alias for 128-bit unsigned integer (2 limbs)
 pub type U128 = Uint<2>;
 
+/// Type alias for 192-bit unsigned integer (3 limbs)
+pub type U192 = Uint<3>;
+
 /// Type alias for 256-bit unsigned integer (4 limbs)
 pub type U256 = Uint<4>;
 
+/// Type alias for 384-bit unsigned integer (6 limbs)
+pub type U384 = Uint<6>;
+
 /// Type alias for 512-bit unsigned integer (8 limbs)
 pub type U512 = Uint<8>;
 
+/// Type alias for 768-bit unsigned integer (12 limbs)
+pub type U768 = Uint<12>;
+
 /// Type alias for 1024-bit unsigned integer (16 limbs)
-pub type U1024 = Uint<16>;
\ No newline at end of file
+pub type U1024 = Uint<16>;
+
+/// Type alias for 2048-bit unsigned integer (32 limbs)
+pub type U2048 = Uint<32>;
+
+/// Type alias for 3072-bit unsigned integer (48 limbs)
+pub type U3072 = Uint<48>;
+
+/// Type alias for 4096-bit unsigned integer (64 limbs)
+pub type U4096 = Uint<64>;
+
+/// Type alias for 8192-bit unsigned integer (128 limbs)
+pub type U8192 = Uint<128>;
\ No newline at end of file
@@ -0,0 +1,297 @@
+//! Single-limb arithmetic helpers shared by the multi-limb routines.
+//!
+//! Carries and borrows are passed around as `u64` values that are either 0 or 1, so the helpers
+//! compose without any data-dependent branches.
+
+/// Computes `a + b + carry`, returning the low limb and the carry out.
+#[inline(always)]
+pub(crate) const fn adc(a: u64, b: u64, carry: u64) -> (u64, u64) {
+    let t = a as u128 + b as u128 + carry as u128;
+    (t as u64, (t >> 64) as u64)
+}
+
+/// Computes `a - b - borrow`, returning the low limb and the borrow out.
+#[inline(always)]
+pub(crate) const fn sbb(a: u64, b: u64, borrow: u64) -> (u64, u64) {
+    let t = (a as u128).wrapping_sub(b as u128 + borrow as u128);
+    (t as u64, (t >> 127) as u64)
+}
+
+/// Computes `a + b * c + carry`, returning the low limb and the high limb.
+///
+/// The result always fits in 128 bits: `(2^64 - 1) + (2^64 - 1)^2 + (2^64 - 1) = 2^128 - 1`.
+#[inline(always)]
+pub(crate) const fn mac(a: u64, b: u64, c: u64, carry: u64) -> (u64, u64) {
+    let t = a as u128 + (b as u128) * (c as u128) + carry as u128;
+    (t as u64, (t >> 64) as u64)
+}
+
+/// Returns `u64::MAX` when `bit` is 1 and `0` when it is 0.
+///
+/// The bit passes through an optimization barrier first, so the compiler cannot tell that it only
+/// takes two values and turn the masking built on it back into a branch.
+#[inline(always)]
+pub(crate) const fn mask(bit: u64) -> u64 {
+    0u64.wrapping_sub(core::hint::black_box(bit))
+}
+
+/// Returns 1 when `x` is non-zero and 0 when it is zero.
+#[inline(always)]
+pub(crate) const fn is_nonzero(x: u64) -> u64 {
+    (x | x.wrapping_neg()) >> 63
+}
+
+/// Returns `a` when `choice` is 0 and `b` when `choice` is 1.
+#[inline(always)]
+pub(crate) const fn select(a: u64, b: u64, choice: u64) -> u64 {
+    a ^ (mask(choice) & (a ^ b))
+}
+
+// Slice kernels. `Uint` calls them on its fixed-size arrays and `BoxedUint` on its heap limbs, so
+// both widths share one implementation of each algorithm. Limbs are little-endian throughout.
+
+/// Computes `a += b` in place, where `b` may be shorter than `a`, returning the carry out.
+#[inline(always)]
+pub(crate) const fn add_assign(a: &mut [u64], b: &[u64]) -> u64 {
+    let mut carry = 0;
+    let mut i = 0;
+    while i < a.len() {
+        let b_limb = if i < b.len() { b[i] } else { 0 };
+        (a[i], carry) = adc(a[i], b_limb, carry);
+        i += 1;
+    }
+    carry
+}
+
+/// Computes `a -= b` in place, where `b` may be shorter than `a`, returning the borrow out.
+#[inline(always)]
+pub(crate) const fn sub_assign(a: &mut [u64], b: &[u64]) -> u64 {
+    let mut borrow = 0;
+    let mut i = 0;
+    while i < a.len() {
+        let b_limb = if i < b.len() { b[i] } else { 0 };
+        (a[i], borrow) = sbb(a[i], b_limb, borrow);
+        i += 1;
+    }
+    borrow
+}
+
+/// Returns 1 when `a < b` and 0 otherwise, where `b` may be shorter than `a`. This is the borrow
+/// out of `a - b`; the difference itself is discarded.
+#[inline(always)]
+pub(crate) const fn lt(a: &[u64], b: &[u64]) -> u64 {
+    let mut borrow = 0;
+    let mut i = 0;
+    while i < a.len() {
+        let b_limb = if i < b.len() { b[i] } else { 0 };
+        (_, borrow) = sbb(a[i], b_limb, borrow);
+        i += 1;
+    }
+    borrow
+}
+
+/// Computes `a += b` in place when `choice` is 1 and leaves `a` unchanged when it is 0, without
+/// branching. `b` must not be longer than `a`. Returns the carry out (0 when `choice` is 0).
+#[inline(always)]
+pub(crate) const fn cond_add_assign(a: &mut [u64], b: &[u64], choice: u64) -> u64 {
+    let m = mask(choice);
+    let mut carry = 0;
+    let mut i = 0;
+    while i < a.len() {
+        let b_limb = if i < b.len() { b[i] & m } else { 0 };
+        (a[i], carry) = adc(a[i], b_limb, carry);
+        i += 1;
+    }
+    carry
+}
+
+/// Writes the product `a * b`, truncated to `out.len()` limbs, into `out`.
+#[inline(always)]
+pub(crate) const fn mul_into(out: &mut [u64], a: &[u64], b: &[u64]) {
+    let mut k = 0;
+    while k < out.len() {
+        out[k] = 0;
+        k += 1;
+    }
+    let mut i = 0;
+    while i < a.len() && i < out.len() {
+        let mut carry = 0;
+        let mut j = 0;
+        while j < b.len() && i + j < out.len() {
+            (out[i + j], carry) = mac(out[i + j], a[i], b[j], carry);
+            j += 1;
+        }
+        // Position i + b.len() is untouched so far in this row, so the carry lands there cleanly.
+        if i + b.len() < out.len() {
+            out[i + b.len()] = carry;
+        }
+        i += 1;
+    }
+}
+
+/// Writes `a << shift` into `out`, which has the length of `a`, discarding bits shifted out.
+#[inline(always)]
+pub(crate) const fn shl_into(out: &mut [u64], a: &[u64], shift: usize) {
+    let limb_shift = shift / 64;
+    let bit_shift = shift % 64;
+    let mut i = out.len();
+    while i > 0 {
+        i -= 1;
+        out[i] = 0;
+        if i >= limb_shift {
+            let src = i - limb_shift;
+            out[i] = a[src] << bit_shift;
+            if bit_shift != 0 && src > 0 {
+                out[i] |= a[src - 1] >> (64 - bit_shift);
+            }
+        }
+    }
+}
+
+/// Writes `a >> shift` into `out`, which has the length of `a`.
+#[inline(always)]
+pub(crate) const fn shr_into(out: &mut [u64], a: &[u64], shift: usize) {
+    let limb_shift = shift / 64;
+    let bit_shift = shift % 64;
+    let mut i = 0;
+    while i < out.len() {
+        out[i] = 0;
+        let src = i + limb_shift;
+        if src < a.len() {
+            out[i] = a[src] >> bit_shift;
+            if bit_shift != 0 && src + 1 < a.len() {
+                out[i] |= a[src + 1] << (64 - bit_shift);
+            }
+        }
+        i += 1;
+    }
+}
+
+/// Shifts `r` left by one bit in place, moving `bit` into the bottom, and returns the bit shifted
+/// out of the top.
+#[inline(always)]
+const fn shl1_assign(r: &mut [u64], bit: u64) -> u64 {
+    let mut carry = bit;
+    let mut k = 0;
+    while k < r.len() {
+        let next = r[k] >> 63;
+        r[k] = (r[k] << 1) | carry;
+        carry = next;
+        k += 1;
+    }
+    carry
+}
+
+/// Binary long division of `a` by the non-zero `d`, all three of the same length: writes the
+/// quotient into `q` and the remainder into `r`.
+///
+/// Every bit of `a` is processed the same way, so the running time depends only on the length.
+#[inline(always)]
+pub(crate) const fn div_rem_into(q: &mut [u64], r: &mut [u64], a: &[u64], d: &[u64]) {
+    let n = a.len();
+    let mut k = 0;
+    while k < n {
+        q[k] = 0;
+        r[k] = 0;
+        k += 1;
+    }
+    let mut i = n * 64;
+    while i > 0 {
+        i -= 1;
+        // r < d, so 2 * r + 1 < 2 * d; the bit shifted out is the overflow.
+        let carry = shl1_assign(r, (a[i / 64] >> (i % 64)) & 1);
+        let borrow = sub_assign(r, d);
+        // Keep the difference if the shifted value overflowed or d fit; otherwise undo it.
+        let take = carry | (borrow ^ 1);
+        cond_add_assign(r, d, take ^ 1);
+        q[i / 64] |= take << (i % 64);
+    }
+}
+
+/// Returns the number of significant bits of `a`, stopping at the highest non-zero limb.
+#[inline(always)]
+const fn bits_vartime(a: &[u64]) -> usize {
+    let mut i = a.len();
+    while i > 0 {
+        i -= 1;
+        if a[i] != 0 {
+            return i * 64 + 64 - a[i].leading_zeros() as usize;
+        }
+    }
+    0
+}
+
+/// Variable-time counterpart of [`div_rem_into`] with the same contract.
+///
+/// The quotient has at most `bits(a) - bits(d) + 1` bits, so only that many steps run, starting
+/// from the top bits of `a` that are known to be below `d`, and the subtraction is skipped
+/// whenever `d` does not fit.
+#[inline(always)]
+pub(crate) const fn div_rem_vartime_into(q: &mut [u64], r: &mut [u64], a: &[u64], d: &[u64]) {
+    let n = a.len();
+    let mut k = 0;
+    while k < n {
+        q[k] = 0;
+        r[k] = a[k];
+        k += 1;
+    }
+    let (a_bits, d_bits) = (bits_vartime(a), bits_vartime(d));
+    if a_bits < d_bits {
+        return;
+    }
+    let steps = a_bits - d_bits + 1;
+    shr_into(r, a, steps);
+    let mut i = steps;
+    while i > 0 {
+        i -= 1;
+        let carry = shl1_assign(r, (a[i / 64] >> (i % 64)) & 1);
+        if carry == 1 || lt(r, d) == 0 {
+            sub_assign(r, d);
+            q[i / 64] |= 1 << (i % 64);
+        }
+    }
+}
+
+/// Montgomery product `a * b * 2^(-64 n) mod p` of `n`-limb operands, written into `t`, using
+/// coarsely integrated operand scanning. `inv` is `-p^-1 mod 2^64`.
+///
+/// Requires `a * b < 2^(64 n) * p`, which holds whenever either operand is reduced.
+#[inline(always)]
+pub(crate) const fn monty_mul_into(t: &mut [u64], a: &[u64], b: &[u64], p: &[u64], inv: u64) {
+    let n = p.len();
+    let mut k = 0;
+    while k < n {
+        t[k] = 0;
+        k += 1;
+    }
+    let mut t_hi = 0u64;
+
+    let mut i = 0;
+    while i < n {
+        // t += a * b[i]
+        let mut carry = 0;
+        let mut j = 0;
+        while j < n {
+            (t[j], carry) = mac(t[j], a[j], b[i], carry);
+            j += 1;
+        }
+        let (hi, hi_carry) = adc(t_hi, carry, 0);
+
+        // t = (t + m * p) / 2^64 with m chosen so the low limb vanishes
+        let m = t[0].wrapping_mul(inv);
+        let (_, mut carry) = mac(t[0], m, p[0], 0);
+        let mut j = 1;
+        while j < n {
+            (t[j - 1], carry) = mac(t[j], m, p[j], carry);
+            j += 1;
+        }
+        (t[n - 1], carry) = adc(hi, carry, 0);
+        t_hi = hi_carry + carry;
+        i += 1;
+    }
+
+    // (t_hi, t) < 2p: subtract p, and add it back if that went below zero.
+    let borrow = sub_assign(t, p);
+    let (_, borrow) = sbb(t_hi, 0, borrow);
+    cond_add_assign(t, p, borrow);
+}
@@ -0,0 +1,364 @@
+//! Number-theoretic functions on `Uint`: modular square roots and the Legendre and Jacobi symbols.
+//!
+//! The square-root algorithms are written once against [`SqrtField`] so that [`Fp`] (with a
+//! compile-time modulus) and [`Uint::sqrt_mod`] (with a runtime modulus) share them.
+//!
+//! [`Fp`]: crate::Fp
+
+use crate::{monty::MontyParams, uint::Uint};
+
+/// The arithmetic the square-root algorithms need from a prime field.
+pub(crate) trait SqrtField: Copy + PartialEq {
+    /// Returns the element `x mod p` in the same field as `self`.
+    fn constant(&self, x: u64) -> Self;
+    fn mul(&self, other: &Self) -> Self;
+    fn sub(&self, other: &Self) -> Self;
+    fn square(&self) -> Self;
+    fn pow_vartime<const E: usize>(&self, exp: &Uint<E>) -> Self;
+    fn is_zero(&self) -> bool;
+
+    fn one(&self) -> Self {
+        self.constant(1)
+    }
+}
+
+/// Euler's criterion: `x^((p - 1) / 2)` is 1 for squares, `p - 1` for non-squares and 0 for zero.
+///
+/// The exponent is public, so the running time does not depend on `x`.
+pub(crate) fn legendre<F: SqrtField, const LIMBS: usize>(x: &F, modulus: &Uint<LIMBS>) -> i8 {
+    let symbol = x.pow_vartime(&modulus.shr(1));
+    if symbol.is_zero() {
+        0
+    } else if symbol == x.one() {
+        1
+    } else {
+        -1
+    }
+}
+
+/// Returns a square root of `x` modulo the odd prime `modulus`, or `None` if there is none.
+///
+/// Picks the cheapest method for the shape of the modulus: a single exponentiation when
+/// `p = 3 mod 4`, Atkin's method when `p = 5 mod 8`, and Tonelli–Shanks otherwise. The first two
+/// run in time independent of `x`; Tonelli–Shanks does not.
+pub(crate) fn sqrt<F: SqrtField, const LIMBS: usize>(x: &F, modulus: &Uint<LIMBS>) -> Option<F> {
+    let root = match modulus.limbs[0] & 7 {
+        // (p + 1) / 4 = (p >> 2) + 1 since the low two bits of p are set
+        3 | 7 => x.pow_vartime(&modulus.shr(2).add(&Uint::<1>::ONE)),
+        5 => atkin(x, modulus),
+        _ => tonelli_shanks(x, modulus)?,
+    };
+
+    if root.square() == *x {
+        Some(root)
+    } else {
+        None
+    }
+}
+
+/// Atkin's square root for `p = 5 mod 8`: with `t = (2x)^((p - 5) / 8)` and `i = 2x * t^2`, which
+/// is a square root of -1 when `x` is a square, the root is `x * t * (i - 1)`.
+fn atkin<F: SqrtField, const LIMBS: usize>(x: &F, modulus: &Uint<LIMBS>) -> F {
+    let two_x = x.mul(&x.constant(2));
+    let t = two_x.pow_vartime(&modulus.shr(3));
+    let i = two_x.mul(&t.square());
+    x.mul(&t).mul(&i.sub(&x.one()))
+}
+
+fn tonelli_shanks<F: SqrtField, const LIMBS: usize>(x: &F, modulus: &Uint<LIMBS>) -> Option<F> {
+    if x.is_zero() {
+        return Some(*x);
+    }
+    if legendre(x, modulus) != 1 {
+        return None;
+    }
+
+    // p - 1 = 2^s * t with t odd
+    let p_minus_one = modulus.sub(&Uint::<1>::ONE);
+    let s = p_minus_one.trailing_zeros();
+    let t = p_minus_one.shr(s);
+
+    let mut k = 2;
+    while legendre(&x.constant(k), modulus) != -1 {
+        k += 1;
+    }
+    let one = x.one();
+
+    let mut m = s;
+    let mut c = x.constant(k).pow_vartime(&t);
+    let mut u = x.pow_vartime(&t);
+    let mut root = x.pow_vartime(&t.shr(1).add(&Uint::<1>::ONE));
+
+    while u != one {
+        // Least i with u^(2^i) = 1; i < m because u has order dividing 2^(m - 1)
+        let mut i = 0;
+        let mut u_pow = u;
+        while u_pow != one {
+            u_pow = u_pow.square();
+            i += 1;
+        }
+
+        let mut b = c;
+        for _ in 0..m - i - 1 {
+            b = b.square();
+        }
+        root = root.mul(&b);
+        c = b.square();
+        u = u.mul(&c);
+        m = i;
+    }
+
+    Some(root)
+}
+
+/// A residue modulo a runtime modulus, in Montgomery form.
+#[derive(Clone, Copy)]
+struct Residue<'a, const LIMBS: usize> {
+    value: Uint<LIMBS>,
+    params: &'a MontyParams<LIMBS>,
+}
+
+impl<const LIMBS: usize> PartialEq for Residue<'_, LIMBS> {
+    fn eq(&self, other: &Self) -> bool {
+        self.value == other.value
+    }
+}
+
+impl<const LIMBS: usize> SqrtField for Residue<'_, LIMBS> {
+    fn constant(&self, x: u64) -> Self {
+        Self {
+            value: self.params.to_monty(&Uint::from_u64(x)),
+            params: self.params,
+        }
+    }
+
+    fn mul(&self, other: &Self) -> Self {
+        Self {
+            value: self.params.mul(&self.value, &other.value),
+            params: self.params,
+        }
+    }
+
+    fn sub(&self, other: &Self) -> Self {
+        Self {
+            value: self.params.sub(&self.value, &other.value),
+            params: self.params,
+        }
+    }
+
+    fn square(&self) -> Self {
+        Self {
+            value: self.params.square(&self.value),
+            params: self.params,
+        }
+    }
+
+    fn pow_vartime<const E: usize>(&self, exp: &Uint<E>) -> Self {
+        Self {
+            value: self.params.pow_vartime(&self.value, exp),
+            params: self.params,
+        }
+    }
+
+    fn is_zero(&self) -> bool {
+        self.value.is_zero()
+    }
+}
+
+impl<const LIMBS: usize> Uint<LIMBS> {
+    /// Returns a square root of `self` modulo the odd prime `p`, or `None` if `self` is not a
+    /// quadratic residue.
+    ///
+    /// Uses the `(p + 1) / 4` exponent when `p = 3 mod 4`, Atkin's method when `p = 5 mod 8` and
+    /// Tonelli–Shanks otherwise. The result is not checked for primality of `p`; for a composite
+    /// modulus the answer is meaningless but any returned value still squares to `self`.
+    pub fn sqrt_mod(&self, p: &Self) -> Option<Self> {
+        let params = MontyParams::new(p);
+        let x = Residue {
+            value: params.to_monty(self),
+            params: &params,
+        };
+        sqrt(&x, p).map(|root| params.from_monty(&root.value))
+    }
+
+    /// Returns the Legendre symbol `(self / p)` for an odd prime `p`: 1 for a non-zero square,
+    /// -1 for a non-square and 0 when `p` divides `self`.
+    ///
+    /// Computed with Euler's criterion, so the running time does not depend on `self`.
+    pub fn legendre(&self, p: &Self) -> i8 {
+        let params = MontyParams::new(p);
+        legendre(
+            &Residue {
+                value: params.to_monty(self),
+                params: &params,
+            },
+            p,
+        )
+    }
+
+    /// Returns the Jacobi symbol `(self / n)` for an odd modulus `n`.
+    ///
+    /// Uses the binary algorithm based on quadratic reciprocity, which needs no exponentiation but
+    /// is variable-time. Panics if `n` is even.
+    pub fn jacobi(&self, n: &Self) -> i8 {
+        assert!(n.is_odd(), "Jacobi symbol requires an odd modulus");
+        if *n == Self::ONE {
+            return 1;
+        }
+
+        let params = MontyParams::new(n);
+        let mut a = params.from_monty(&params.to_monty(self));
+        let mut n = *n;
+        let mut result = 1;
+
+        while !a.is_zero() {
+            let twos = a.trailing_zeros();
+            a = a.shr(twos);
+            // (2 / n) = -1 exactly when n = 3 or 5 mod 8
+            if twos % 2 == 1 && matches!(n.limbs[0] & 7, 3 | 5) {
+                result = -result;
+            }
+            if a < n {
+                core::mem::swap(&mut a, &mut n);
+                if a.limbs[0] & 3 == 3 && n.limbs[0] & 3 == 3 {
+                    result = -result;
+                }
+            }
+            a = a.sub(&n);
+        }
+
+        if n == Self::ONE {
+            result
+        } else {
+            0
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Computes the Jacobi symbol of small values from the definition via Euler's criterion on
+    /// each prime factor.
+    fn jacobi_reference(a: u64, n: u64) -> i8 {
+        let mut result = 1;
+        let mut rest = n;
+        let mut q = 3;
+        while rest > 1 {
+            while rest.is_multiple_of(q) {
+                let euler = (0..(q - 1) / 2).fold(1u64, |acc, _| acc * (a % q) % q);
+                result *= match euler {
+                    0 => 0,
+                    1 => 1,
+                    _ => -1,
+                };
+                rest /= q;
+            }
+            q += 2;
+        }
+        result
+    }
+
+    #[test]
+    fn test_jacobi_small_values() {
+        for n in (1..200u64).step_by(2) {
+            for a in 0..60u64 {
+                let expected = jacobi_reference(a, n);
+                assert_eq!(
+                    Uint::<2>::from_u64(a).jacobi(&Uint::from_u64(n)),
+                    expected,
+                    "{a}/{n}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_jacobi_large_operand() {
+        // A value much larger than the modulus is reduced first: 2^128 - 1 = 3 mod 19, and 3 is
+        // not a square modulo 19
+        assert_eq!(Uint::<2>::MAX.jacobi(&Uint::from_u64(19)), -1);
+        assert_eq!(Uint::<2>::from_u64(34).jacobi(&Uint::from_u64(17)), 0);
+    }
+
+    #[test]
+    #[should_panic(expected = "Jacobi symbol requires an odd modulus")]
+    fn test_jacobi_even_modulus() {
+        let _ = Uint::<1>::from_u64(3).jacobi(&Uint::from_u64(10));
+    }
+
+    #[test]
+    fn test_legendre_matches_jacobi_for_primes() {
+        let p = Uint::<4>::from_be_hex(
+            "FFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFEFFFFFC2F",
+        );
+        for a in 0..50u64 {
+            let a = Uint::from_u64(a);
+            assert_eq!(a.legendre(&p), a.jacobi(&p));
+        }
+    }
+
+    fn check_sqrt_mod<const LIMBS: usize>(p: &Uint<LIMBS>) {
+        let params = MontyParams::new(p);
+        let mut residues = 0;
+        for a in 0..200u64 {
+            let a = Uint::<LIMBS>::from_u64(a);
+            let expected_square = a.legendre(p) >= 0;
+            match a.sqrt_mod(p) {
+                Some(root) => {
+                    let square = params.from_monty(&params.square(&params.to_monty(&root)));
+                    assert_eq!(square, a);
+                    residues += 1;
+                    assert!(expected_square);
+                }
+                None => assert!(!expected_square),
+            }
+        }
+        // Roughly half of the small values are squares
+        assert!(residues > 50 && residues < 150);
+    }
+
+    #[test]
+    fn test_sqrt_mod_three_mod_four() {
+        // secp256k1 base field prime
+        check_sqrt_mod(&Uint::<4>::from_be_hex(
+            "FFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFEFFFFFC2F",
+        ));
+    }
+
+    #[test]
+    fn test_sqrt_mod_atkin() {
+        // 2^255 - 19 = 5 mod 8
+        let p = Uint::<4>::from_be_hex(
+            "7FFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFED",
+        );
+        assert_eq!(p.limbs[0] & 7, 5);
+        check_sqrt_mod(&p);
+
+        // -1 is a square modulo primes that are 1 mod 4
+        let params = MontyParams::new(&p);
+        let minus_one = p.sub(&Uint::<1>::ONE);
+        let root = params.to_monty(&minus_one.sqrt_mod(&p).unwrap());
+        assert_eq!(params.from_monty(&params.square(&root)), minus_one);
+    }
+
+    #[test]
+    fn test_sqrt_mod_tonelli_shanks() {
+        // BN254 scalar field, 2-adicity 28, and the Goldilocks prime, 2-adicity 32
+        check_sqrt_mod(&Uint::<4>::from_be_hex(
+            "30644E72E131A029B85045B68181585D2833E84879B9709143E1F593F0000001",
+        ));
+        check_sqrt_mod(&Uint::<1>::from_u64(0xFFFF_FFFF_0000_0001));
+    }
+
+    #[test]
+    fn test_sqrt_mod_reduces_input() {
+        let p = Uint::<1>::from_u64(0xFFFF_FFFF_FFFF_FFC5);
+        // p + 4 = 4 mod p
+        let root = Uint::<1>::from_u64(0xFFFF_FFFF_FFFF_FFC9)
+            .sqrt_mod(&p)
+            .unwrap();
+        assert!(root == Uint::from_u64(2) || root == Uint::from_u64(0xFFFF_FFFF_FFFF_FFC3));
+    }
+}
@@ -0,0 +1,230 @@
+//! Montgomery arithmetic modulo an odd modulus.
+//!
+//! Values are kept in Montgomery form `x * R mod p` with `R = 2^(LIMBS * 64)`, which turns modular
+//! multiplication into a multiply followed by a cheap word-by-word reduction. `MontyParams` holds
+//! the per-modulus constants and can be built in a `const` context, so prime fields get them for
+//! free at compile time.
+
+use crate::{ct::fixed_window, limb::monty_mul_into, uint::Uint};
+
+/// Precomputed constants for Montgomery arithmetic modulo an odd `modulus`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MontyParams<const LIMBS: usize> {
+    /// The modulus `p`.
+    modulus: Uint<LIMBS>,
+    /// `R mod p`, the Montgomery form of one.
+    one: Uint<LIMBS>,
+    /// `R^2 mod p`, used to convert into Montgomery form.
+    r2: Uint<LIMBS>,
+    /// `-p^-1 mod 2^64`.
+    inv: u64,
+}
+
+impl<const LIMBS: usize> MontyParams<LIMBS> {
+    /// Computes the Montgomery constants for `modulus`.
+    ///
+    /// Panics if the modulus is even or smaller than 3.
+    pub const fn new(modulus: &Uint<LIMBS>) -> Self {
+        assert!(modulus.is_odd(), "Montgomery modulus must be odd");
+        assert!(
+            modulus.bits() > 1,
+            "Montgomery modulus must be greater than 1"
+        );
+
+        // Newton iteration doubles the number of correct low bits each step: 1 -> 2 -> ... -> 64.
+        let mut inv: u64 = 1;
+        let mut i = 0;
+        while i < 6 {
+            inv = inv.wrapping_mul(2u64.wrapping_sub(modulus.limbs[0].wrapping_mul(inv)));
+            i += 1;
+        }
+
+        // R mod p and R^2 mod p by repeated modular doubling of one.
+        let mut one = Uint::ONE;
+        let mut i = 0;
+        while i < Uint::<LIMBS>::BITS {
+            one = one.add_mod(&one, modulus);
+            i += 1;
+        }
+        let mut r2 = one;
+        let mut i = 0;
+        while i < Uint::<LIMBS>::BITS {
+            r2 = r2.add_mod(&r2, modulus);
+            i += 1;
+        }
+
+        Self {
+            modulus: *modulus,
+            one,
+            r2,
+            inv: inv.wrapping_neg(),
+        }
+    }
+
+    /// Returns the modulus.
+    pub const fn modulus(&self) -> &Uint<LIMBS> {
+        &self.modulus
+    }
+
+    /// Returns `R mod p`, the Montgomery form of one.
+    pub const fn one(&self) -> &Uint<LIMBS> {
+        &self.one
+    }
+
+    /// Converts `x` into Montgomery form. Any `x` is accepted and reduced modulo `p`.
+    pub const fn to_monty(&self, x: &Uint<LIMBS>) -> Uint<LIMBS> {
+        self.mul(x, &self.r2)
+    }
+
+    /// Converts `x` out of Montgomery form, returning the canonical residue.
+    pub const fn from_monty(&self, x: &Uint<LIMBS>) -> Uint<LIMBS> {
+        self.mul(x, &Uint::ONE)
+    }
+
+    /// Montgomery product `a * b * R^-1 mod p` using coarsely integrated operand scanning.
+    ///
+    /// Requires `a * b < R * p`, which holds whenever either operand is reduced.
+    pub const fn mul(&self, a: &Uint<LIMBS>, b: &Uint<LIMBS>) -> Uint<LIMBS> {
+        let mut t = [0u64; LIMBS];
+        monty_mul_into(&mut t, &a.limbs, &b.limbs, &self.modulus.limbs, self.inv);
+        Uint { limbs: t }
+    }
+
+    /// Montgomery square `a^2 * R^-1 mod p`.
+    pub const fn square(&self, a: &Uint<LIMBS>) -> Uint<LIMBS> {
+        self.mul(a, a)
+    }
+
+    /// Computes `a + b mod p` for reduced `a` and `b`.
+    pub fn add(&self, a: &Uint<LIMBS>, b: &Uint<LIMBS>) -> Uint<LIMBS> {
+        a.add_mod(b, &self.modulus)
+    }
+
+    /// Computes `a - b mod p` for reduced `a` and `b`.
+    pub fn sub(&self, a: &Uint<LIMBS>, b: &Uint<LIMBS>) -> Uint<LIMBS> {
+        a.sub_mod(b, &self.modulus)
+    }
+
+    /// Computes `-a mod p` for reduced `a`.
+    pub fn neg(&self, a: &Uint<LIMBS>) -> Uint<LIMBS> {
+        a.neg_mod(&self.modulus)
+    }
+
+    /// Raises `base` (in Montgomery form) to `exp`, returning the result in Montgomery form.
+    ///
+    /// Uses a fixed 4-bit window, so neither the running time nor the memory accesses depend on
+    /// the exponent value, only on `E`.
+    pub fn pow<const E: usize>(&self, base: &Uint<LIMBS>, exp: &Uint<E>) -> Uint<LIMBS> {
+        fixed_window(
+            self.one,
+            base,
+            exp,
+            |a, b| self.mul(a, b),
+            |a| self.square(a),
+        )
+    }
+
+    /// Like [`MontyParams::pow`] but skips leading zero bits of the exponent.
+    ///
+    /// Only use this when the exponent is public.
+    pub fn pow_vartime<const E: usize>(&self, base: &Uint<LIMBS>, exp: &Uint<E>) -> Uint<LIMBS> {
+        let mut acc = self.one;
+        for i in (0..exp.bits()).rev() {
+            acc = self.square(&acc);
+            if exp.bit(i) {
+                acc = self.mul(&acc, base);
+            }
+        }
+        acc
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // 2^64 - 59, the largest 64-bit prime
+    const P64: u64 = 0xFFFF_FFFF_FFFF_FFC5;
+
+    fn mulmod(a: u64, b: u64, p: u64) -> u64 {
+        ((a as u128 * b as u128) % p as u128) as u64
+    }
+
+    #[test]
+    fn test_constants_single_limb() {
+        let params = MontyParams::<1>::new(&Uint::from_u64(P64));
+        let r = ((1u128 << 64) % P64 as u128) as u64;
+        assert_eq!(params.one().limbs[0], r);
+        assert_eq!(params.r2.limbs[0], mulmod(r, r, P64));
+        assert_eq!(params.inv.wrapping_mul(P64), u64::MAX);
+    }
+
+    #[test]
+    fn test_mul_matches_u128() {
+        let params = MontyParams::<1>::new(&Uint::from_u64(P64));
+        let values = [
+            0,
+            1,
+            2,
+            0x1234_5678_9ABC_DEF0,
+            P64 - 1,
+            P64 - 2,
+            0x8000_0000_0000_0000,
+        ];
+        for &a in &values {
+            for &b in &values {
+                let am = params.to_monty(&Uint::from_u64(a));
+                let bm = params.to_monty(&Uint::from_u64(b));
+                let product = params.from_monty(&params.mul(&am, &bm));
+                assert_eq!(product.limbs[0], mulmod(a, b, P64));
+            }
+        }
+    }
+
+    #[test]
+    fn test_add_sub_neg() {
+        let params = MontyParams::<1>::new(&Uint::from_u64(P64));
+        let a = Uint::from_u64(P64 - 1);
+        let b = Uint::from_u64(5);
+        assert_eq!(params.add(&a, &b).limbs[0], 4);
+        assert_eq!(params.sub(&b, &a).limbs[0], 6);
+        assert_eq!(params.neg(&b).limbs[0], P64 - 5);
+        assert_eq!(params.neg(&Uint::ZERO), Uint::ZERO);
+    }
+
+    #[test]
+    fn test_multi_limb_modulus_near_r() {
+        // secp256k1 base field prime, within 2^33 of R
+        let p = Uint::<4>::from_be_hex(
+            "FFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFEFFFFFC2F",
+        );
+        let params = MontyParams::new(&p);
+        // R mod p = 2^32 + 977
+        assert_eq!(params.one(), &Uint::from_u64(0x1_0000_03D1));
+
+        // (p - 1)^2 = 1 mod p
+        let minus_one = params.to_monty(&p.sub(&Uint::<1>::from_u64(1)));
+        assert_eq!(params.from_monty(&params.square(&minus_one)), Uint::ONE);
+
+        // Values at or above p are reduced on the way in
+        assert_eq!(
+            params.from_monty(&params.to_monty(&Uint::MAX)),
+            Uint::from_u64(0x1_0000_03D0)
+        );
+    }
+
+    #[test]
+    fn test_pow_fermat() {
+        let params = MontyParams::<1>::new(&Uint::from_u64(P64));
+        let base = params.to_monty(&Uint::from_u64(3));
+        let exp = Uint::<1>::from_u64(P64 - 1);
+        assert_eq!(params.from_monty(&params.pow(&base, &exp)), Uint::ONE);
+        assert_eq!(
+            params.from_monty(&params.pow_vartime(&base, &exp)),
+            Uint::ONE
+        );
+
+        let exp = Uint::<2>::from_u64(10);
+        assert_eq!(params.from_monty(&params.pow(&base, &exp)).limbs[0], 59049);
+    }
+}
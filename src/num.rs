@@ -0,0 +1,314 @@
+//! [`num-traits`](https://docs.rs/num-traits) implementations for [`Uint`], behind the
+//! `num-traits` feature.
+//!
+//! The traits build on the operators `Uint` always implements. Like those, [`PrimInt::pow`] wraps
+//! on overflow; the `Checked*` and [`Saturating`] traits detect it.
+
+use num_traits::{
+    Bounded, CheckedAdd, CheckedDiv, CheckedMul, CheckedSub, FromPrimitive, Num, NumCast, One,
+    PrimInt, Saturating, ToPrimitive, Unsigned, WrappingAdd, WrappingMul, WrappingSub, Zero,
+};
+
+use crate::uint::{ParseUintError, Uint};
+
+impl<const LIMBS: usize> Zero for Uint<LIMBS> {
+    fn zero() -> Self {
+        Self::ZERO
+    }
+
+    fn is_zero(&self) -> bool {
+        Uint::is_zero(self)
+    }
+}
+
+impl<const LIMBS: usize> One for Uint<LIMBS> {
+    fn one() -> Self {
+        Self::ONE
+    }
+}
+
+impl<const LIMBS: usize> Num for Uint<LIMBS> {
+    type FromStrRadixErr = ParseUintError;
+
+    fn from_str_radix(s: &str, radix: u32) -> Result<Self, ParseUintError> {
+        Uint::from_str_radix(s, radix)
+    }
+}
+
+impl<const LIMBS: usize> Unsigned for Uint<LIMBS> {}
+
+impl<const LIMBS: usize> Bounded for Uint<LIMBS> {
+    fn min_value() -> Self {
+        Self::ZERO
+    }
+
+    fn max_value() -> Self {
+        Self::MAX
+    }
+}
+
+impl<const LIMBS: usize> CheckedAdd for Uint<LIMBS> {
+    fn checked_add(&self, v: &Self) -> Option<Self> {
+        let (sum, overflow) = self.overflowing_add(v);
+        (!overflow).then_some(sum)
+    }
+}
+
+impl<const LIMBS: usize> CheckedSub for Uint<LIMBS> {
+    fn checked_sub(&self, v: &Self) -> Option<Self> {
+        let (difference, underflow) = self.overflowing_sub(v);
+        (!underflow).then_some(difference)
+    }
+}
+
+impl<const LIMBS: usize> CheckedMul for Uint<LIMBS> {
+    fn checked_mul(&self, v: &Self) -> Option<Self> {
+        let (lo, hi) = self.widening_mul(v);
+        Uint::is_zero(&hi).then_some(lo)
+    }
+}
+
+impl<const LIMBS: usize> CheckedDiv for Uint<LIMBS> {
+    fn checked_div(&self, v: &Self) -> Option<Self> {
+        (!Uint::is_zero(v)).then(|| self.div_rem(v).0)
+    }
+}
+
+impl<const LIMBS: usize> WrappingAdd for Uint<LIMBS> {
+    fn wrapping_add(&self, v: &Self) -> Self {
+        Uint::add(self, v)
+    }
+}
+
+impl<const LIMBS: usize> WrappingSub for Uint<LIMBS> {
+    fn wrapping_sub(&self, v: &Self) -> Self {
+        Uint::sub(self, v)
+    }
+}
+
+impl<const LIMBS: usize> WrappingMul for Uint<LIMBS> {
+    fn wrapping_mul(&self, v: &Self) -> Self {
+        Uint::mul(self, v)
+    }
+}
+
+impl<const LIMBS: usize> Saturating for Uint<LIMBS> {
+    fn saturating_add(self, v: Self) -> Self {
+        self.checked_add(&v).unwrap_or(Self::MAX)
+    }
+
+    fn saturating_sub(self, v: Self) -> Self {
+        self.checked_sub(&v).unwrap_or(Self::ZERO)
+    }
+}
+
+impl<const LIMBS: usize> ToPrimitive for Uint<LIMBS> {
+    fn to_i64(&self) -> Option<i64> {
+        self.to_u64().and_then(|x| x.try_into().ok())
+    }
+
+    fn to_u64(&self) -> Option<u64> {
+        (self.bits() <= 64).then(|| self.limbs.first().copied().unwrap_or(0))
+    }
+
+    fn to_i128(&self) -> Option<i128> {
+        self.to_u128().and_then(|x| x.try_into().ok())
+    }
+
+    fn to_u128(&self) -> Option<u128> {
+        let limb = |i: usize| self.limbs.get(i).copied().unwrap_or(0) as u128;
+        (self.bits() <= 128).then(|| limb(0) | (limb(1) << 64))
+    }
+}
+
+impl<const LIMBS: usize> FromPrimitive for Uint<LIMBS> {
+    fn from_i64(n: i64) -> Option<Self> {
+        u128::try_from(n).ok().and_then(Self::from_u128)
+    }
+
+    fn from_u64(n: u64) -> Option<Self> {
+        Self::from_u128(n as u128)
+    }
+
+    fn from_i128(n: i128) -> Option<Self> {
+        u128::try_from(n).ok().and_then(Self::from_u128)
+    }
+
+    fn from_u128(n: u128) -> Option<Self> {
+        Uint::from_le_bytes(&n.to_le_bytes())
+    }
+}
+
+impl<const LIMBS: usize> NumCast for Uint<LIMBS> {
+    fn from<T: ToPrimitive>(n: T) -> Option<Self> {
+        n.to_u128().and_then(Self::from_u128)
+    }
+}
+
+impl<const LIMBS: usize> PrimInt for Uint<LIMBS> {
+    fn count_ones(self) -> u32 {
+        self.limbs.iter().map(|limb| limb.count_ones()).sum()
+    }
+
+    fn count_zeros(self) -> u32 {
+        self.limbs.iter().map(|limb| limb.count_zeros()).sum()
+    }
+
+    fn leading_zeros(self) -> u32 {
+        (Self::BITS - self.bits()) as u32
+    }
+
+    fn trailing_zeros(self) -> u32 {
+        Uint::trailing_zeros(&self) as u32
+    }
+
+    fn rotate_left(self, n: u32) -> Self {
+        let n = n as usize % Self::BITS;
+        Uint::shl(&self, n) | Uint::shr(&self, Self::BITS - n)
+    }
+
+    fn rotate_right(self, n: u32) -> Self {
+        let n = n as usize % Self::BITS;
+        Uint::shr(&self, n) | Uint::shl(&self, Self::BITS - n)
+    }
+
+    fn signed_shl(self, n: u32) -> Self {
+        Uint::shl(&self, n as usize)
+    }
+
+    fn signed_shr(self, n: u32) -> Self {
+        // Arithmetic shift: copies of the top bit fill the vacated positions.
+        let fill = if self.bit(Self::BITS - 1) {
+            !Uint::shr(&Self::MAX, n as usize)
+        } else {
+            Self::ZERO
+        };
+        Uint::shr(&self, n as usize) | fill
+    }
+
+    fn unsigned_shl(self, n: u32) -> Self {
+        Uint::shl(&self, n as usize)
+    }
+
+    fn unsigned_shr(self, n: u32) -> Self {
+        Uint::shr(&self, n as usize)
+    }
+
+    fn swap_bytes(self) -> Self {
+        let mut limbs = self.limbs.map(u64::swap_bytes);
+        limbs.reverse();
+        Self { limbs }
+    }
+
+    fn from_be(x: Self) -> Self {
+        x.to_be()
+    }
+
+    fn from_le(x: Self) -> Self {
+        x.to_le()
+    }
+
+    fn to_be(self) -> Self {
+        if cfg!(target_endian = "big") {
+            self
+        } else {
+            self.swap_bytes()
+        }
+    }
+
+    fn to_le(self) -> Self {
+        if cfg!(target_endian = "little") {
+            self
+        } else {
+            self.swap_bytes()
+        }
+    }
+
+    fn pow(self, exp: u32) -> Self {
+        let mut acc = Self::ONE;
+        for i in (0..u32::BITS).rev() {
+            acc = Uint::mul(&acc, &acc);
+            if (exp >> i) & 1 == 1 {
+                acc = Uint::mul(&acc, &self);
+            }
+        }
+        acc
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    type U = Uint<2>;
+
+    fn generic_sum<T: PrimInt + Unsigned>(values: &[T]) -> Option<T> {
+        values
+            .iter()
+            .try_fold(T::zero(), |acc, &value| acc.checked_add(&value))
+    }
+
+    #[test]
+    fn test_checked_and_saturating() {
+        assert_eq!(U::MAX.checked_add(&U::ONE), None);
+        assert_eq!(U::ZERO.checked_sub(&U::ONE), None);
+        assert_eq!(U::MAX.checked_mul(&U::from_u64(2)), None);
+        assert_eq!(U::ONE.checked_div(&U::ZERO), None);
+        assert_eq!(
+            U::from_u64(7).checked_div(&U::from_u64(2)),
+            Some(U::from_u64(3))
+        );
+        assert_eq!(U::MAX.saturating_add(U::ONE), U::MAX);
+        assert_eq!(U::ONE.saturating_sub(U::MAX), U::ZERO);
+        assert_eq!(U::MAX.wrapping_add(&U::ONE), U::ZERO);
+        assert_eq!(generic_sum(&[U::MAX, U::ONE]), None);
+        assert_eq!(generic_sum(&[U::ONE, U::ONE]), Some(U::from_u64(2)));
+    }
+
+    #[test]
+    fn test_primitive_conversions() {
+        assert_eq!(U::from_u128(u128::MAX), Some(U::MAX));
+        assert_eq!(Uint::<1>::from_u128(1 << 64), None);
+        assert_eq!(U::from_i64(-1), None);
+        assert_eq!(U::from_i64(5), Some(U::from_u64(5)));
+        assert_eq!(U::MAX.to_u128(), Some(u128::MAX));
+        assert_eq!(U::MAX.to_u64(), None);
+        assert_eq!(U::from_u64(u64::MAX).to_i64(), None);
+        assert_eq!(U::from_u64(42).to_i64(), Some(42));
+        assert_eq!(<U as NumCast>::from(300u16), Some(U::from_u64(300)));
+        assert_eq!(<U as Num>::from_str_radix("ff", 16), Ok(U::from_u64(255)));
+    }
+
+    #[test]
+    fn test_prim_int() {
+        let x = U::from_be_hex("8000000000000000000000000000f00f");
+        assert_eq!(x.count_ones(), 9);
+        assert_eq!(x.count_zeros(), 119);
+        assert_eq!(x.leading_zeros(), 0);
+        assert_eq!(PrimInt::trailing_zeros(x), 0);
+        assert_eq!(
+            x.rotate_left(1),
+            U::from_be_hex("0000000000000000000000000001e01f")
+        );
+        assert_eq!(
+            x.rotate_right(1),
+            U::from_be_hex("c0000000000000000000000000007807")
+        );
+        assert_eq!(x.rotate_left(128), x);
+        assert_eq!(
+            x.signed_shr(4),
+            U::from_be_hex("f8000000000000000000000000000f00")
+        );
+        assert_eq!(
+            x.unsigned_shr(4),
+            U::from_be_hex("08000000000000000000000000000f00")
+        );
+        assert_eq!(
+            x.swap_bytes(),
+            U::from_be_hex("0ff00000000000000000000000000080")
+        );
+        assert_eq!(U::from_be(x.to_be()), x);
+        assert_eq!(U::from_u64(3).pow(80), U::from_u128(3u128.pow(80)).unwrap());
+        assert_eq!(U::from_u64(2).pow(128), U::ZERO);
+    }
+}
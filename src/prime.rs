@@ -0,0 +1,729 @@
+//! Primality testing and prime generation.
+//!
+//! [`Uint::is_probable_prime`] runs the Baillie–PSW test, which has no known counterexample.
+//! [`miller_rabin_bases`] and [`miller_rabin_random`] expose Miller–Rabin with fixed or random
+//! bases, and [`is_probable_prime_with_rounds`] combines both as FIPS 186-5 recommends for prime
+//! generation. Every test starts with trial division by the odd primes below 2048 and answers
+//! with a [`Primality`], which is [`Primality::Prime`] only when the result is certain.
+//!
+//! [`random_prime`], [`random_safe_prime`] and [`random_rsa_primes`] generate primes from a
+//! caller-supplied [`Rng`].
+
+use crate::{monty::MontyParams, rand::Rng, uint::Uint};
+
+/// Number of odd primes below [`SIEVE_LIMIT`].
+const SMALL_PRIME_COUNT: usize = 308;
+
+/// Trial division uses every odd prime below this bound.
+const SIEVE_LIMIT: u32 = 2048;
+
+/// The odd primes below [`SIEVE_LIMIT`], computed during constant evaluation.
+pub(crate) const SMALL_PRIMES: [u32; SMALL_PRIME_COUNT] = {
+    let mut composite = [false; SIEVE_LIMIT as usize];
+    let mut primes = [0u32; SMALL_PRIME_COUNT];
+    let mut count = 0;
+    let mut i = 3;
+    while i < SIEVE_LIMIT as usize {
+        if !composite[i] {
+            primes[count] = i as u32;
+            count += 1;
+            let mut j = i * i;
+            while j < SIEVE_LIMIT as usize {
+                composite[j] = true;
+                j += 2 * i;
+            }
+        }
+        i += 2;
+    }
+    assert!(count == SMALL_PRIME_COUNT);
+    primes
+};
+
+/// Returns `n mod d` for a small divisor.
+pub(crate) fn rem_small<const LIMBS: usize>(n: &Uint<LIMBS>, d: u32) -> u32 {
+    let d = d as u128;
+    n.limbs
+        .iter()
+        .rev()
+        .fold(0u128, |rem, &limb| ((rem << 64) | limb as u128) % d) as u32
+}
+
+/// The outcome of a primality test.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Primality {
+    /// The number is certainly composite (or smaller than 2).
+    Composite,
+    /// The number passed a probabilistic test.
+    ProbablyPrime,
+    /// The number is certainly prime.
+    Prime,
+}
+
+impl Primality {
+    /// Returns `true` unless the number was found to be composite.
+    pub fn is_probably_prime(self) -> bool {
+        self != Primality::Composite
+    }
+
+    fn from_bool(probably_prime: bool) -> Self {
+        if probably_prime {
+            Primality::ProbablyPrime
+        } else {
+            Primality::Composite
+        }
+    }
+}
+
+/// The first thirteen primes. Miller–Rabin to these bases proves primality below
+/// 3317044064679887385961981, a little over `2^81`.
+pub const DETERMINISTIC_BASES: [u64; 13] = [2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37, 41];
+
+/// `PSI[k - 1]` is the smallest strong pseudoprime to all of the first `k` prime bases
+/// (OEIS A014233).
+const PSI: [u128; 13] = [
+    2047,
+    1373653,
+    25326001,
+    3215031751,
+    2152302898747,
+    3474749660383,
+    341550071728321,
+    341550071728321,
+    3825123056546413051,
+    318665857834031151167461,
+    318665857834031151167461,
+    318665857834031151167461,
+    3317044064679887385961981,
+];
+
+/// Decides primality by trial division where that is conclusive: `Composite` if a small prime
+/// divides `n`, `Prime` if `n` is small enough to have no undiscovered factor, and `None`
+/// otherwise.
+fn trial_division<const LIMBS: usize>(n: &Uint<LIMBS>) -> Option<Primality> {
+    if n.bits() <= 1 {
+        return Some(Primality::Composite);
+    }
+    if !n.is_odd() {
+        return Some(if *n == Uint::from_u64(2) {
+            Primality::Prime
+        } else {
+            Primality::Composite
+        });
+    }
+    let small = (n.bits() <= 64).then_some(n.limbs[0]);
+    for &p in SMALL_PRIMES.iter() {
+        if small == Some(p as u64) {
+            return Some(Primality::Prime);
+        }
+        if rem_small(n, p) == 0 {
+            return Some(Primality::Composite);
+        }
+    }
+    let limit = SIEVE_LIMIT as u64;
+    small
+        .filter(|&n| n < limit * limit)
+        .map(|_| Primality::Prime)
+}
+
+/// Runs one Miller–Rabin round on the odd `n > 3` with the given base, returning `false` if the
+/// base witnesses that `n` is composite.
+fn miller_rabin<const LIMBS: usize>(monty: &MontyParams<LIMBS>, base: &Uint<LIMBS>) -> bool {
+    let n = monty.modulus();
+    let n_minus_one = n.sub(&Uint::<1>::ONE);
+    let s = n_minus_one.trailing_zeros();
+    let one = *monty.one();
+    let minus_one = monty.neg(&one);
+
+    let mut x = monty.pow(&monty.to_monty(base), &n_minus_one.shr(s));
+    if x == one || x == minus_one || x.is_zero() {
+        return true;
+    }
+    for _ in 1..s {
+        x = monty.square(&x);
+        if x == minus_one {
+            return true;
+        }
+        if x == one {
+            return false;
+        }
+    }
+    false
+}
+
+/// Returns whether `n` is a perfect square, using Newton's method on the integer square root.
+fn is_square<const LIMBS: usize>(n: &Uint<LIMBS>) -> bool {
+    // Start above the root; the iteration then decreases monotonically to the floor of it.
+    let mut x = Uint::<LIMBS>::ONE.shl(n.bits().div_ceil(2));
+    loop {
+        let y = x.add(&n.div_rem(&x).0).shr(1);
+        if y >= x {
+            return x.mul(&x) == *n;
+        }
+        x = y;
+    }
+}
+
+/// Runs the strong Lucas probable prime test with Selfridge's parameters on an odd `n` that has
+/// passed trial division, returning `false` if `n` is found composite.
+///
+/// `D` is the first of 5, -7, 9, -11, ... with Jacobi symbol `(D / n) = -1`, `P = 1` and
+/// `Q = (1 - D) / 4`. Writing `n + 1 = d 2^s` with `d` odd, `n` passes if `U_d = 0` or
+/// `V_{d 2^r} = 0` for some `r < s`.
+fn strong_lucas<const LIMBS: usize>(n: &Uint<LIMBS>) -> bool {
+    let mut d_abs = 5u64;
+    let mut negative = false;
+    let mut attempts = 0;
+    loop {
+        let d = Uint::from_u64(d_abs);
+        let d_mod_n = if negative { n.sub(&d) } else { d };
+        match d_mod_n.jacobi(n) {
+            -1 => break,
+            0 => return false,
+            _ => {}
+        }
+        // No suitable D exists for squares; look for one only after a few misses.
+        attempts += 1;
+        if attempts == 8 && is_square(n) {
+            return false;
+        }
+        d_abs += 2;
+        negative = !negative;
+    }
+
+    let monty = MontyParams::new(n);
+    let signed = |value: u64, negative: bool| {
+        let value = Uint::from_u64(value);
+        monty.to_monty(&if negative { n.sub(&value) } else { value })
+    };
+    // Q = (1 - D) / 4, which is negative exactly when D is positive.
+    let d = signed(d_abs, negative);
+    let q = if negative {
+        signed((d_abs + 1) / 4, false)
+    } else {
+        signed((d_abs - 1) / 4, true)
+    };
+    // (x + n) / 2 when x is odd; halving commutes with the Montgomery form.
+    let half_n = n.shr(1).add(&Uint::<1>::ONE);
+    let half = |x: Uint<LIMBS>| {
+        if x.is_odd() {
+            x.shr(1).add(&half_n)
+        } else {
+            x.shr(1)
+        }
+    };
+
+    // n + 1 does not overflow: trial division rejects 2^(64 LIMBS) - 1, a multiple of 3.
+    let n_plus_one = n.add(&Uint::<1>::ONE);
+    let s = n_plus_one.trailing_zeros();
+    let k = n_plus_one.shr(s);
+
+    // Ladder over the bits of k with (U_j, V_j, Q^j), starting from j = 1 where U = 1, V = P = 1.
+    let one = *monty.one();
+    let (mut u, mut v, mut qk) = (one, one, q);
+    for i in (0..k.bits() - 1).rev() {
+        u = monty.mul(&u, &v);
+        v = monty.sub(&monty.square(&v), &monty.add(&qk, &qk));
+        qk = monty.square(&qk);
+        if k.bit(i) {
+            (u, v) = (
+                half(monty.add(&u, &v)),
+                half(monty.add(&monty.mul(&d, &u), &v)),
+            );
+            qk = monty.mul(&qk, &q);
+        }
+    }
+
+    if u.is_zero() || v.is_zero() {
+        return true;
+    }
+    for _ in 1..s {
+        v = monty.sub(&monty.square(&v), &monty.add(&qk, &qk));
+        if v.is_zero() {
+            return true;
+        }
+        qk = monty.square(&qk);
+    }
+    false
+}
+
+/// Tests `n` with trial division and Miller–Rabin to the given bases.
+///
+/// Bases are reduced modulo `n`. When `bases` starts with the first `k` primes (see
+/// [`DETERMINISTIC_BASES`]) and `n` lies below the smallest strong pseudoprime to all of them, a
+/// pass proves primality and [`Primality::Prime`] is returned.
+pub fn miller_rabin_bases<const LIMBS: usize>(n: &Uint<LIMBS>, bases: &[u64]) -> Primality {
+    if let Some(decided) = trial_division(n) {
+        return decided;
+    }
+    let monty = MontyParams::new(n);
+    if !bases
+        .iter()
+        .all(|&base| miller_rabin(&monty, &Uint::from_u64(base)))
+    {
+        return Primality::Composite;
+    }
+    let prefix = bases
+        .iter()
+        .zip(DETERMINISTIC_BASES.iter())
+        .take_while(|(a, b)| a == b)
+        .count();
+    let proven = prefix > 0 && n.bits() <= 128 && {
+        let n = n.limbs[0] as u128 | (n.limbs.get(1).copied().unwrap_or(0) as u128) << 64;
+        n < PSI[prefix - 1]
+    };
+    if proven {
+        Primality::Prime
+    } else {
+        Primality::ProbablyPrime
+    }
+}
+
+/// Tests `n` with trial division and `rounds` Miller–Rabin rounds on uniformly random bases in
+/// `[2, n - 2]`.
+///
+/// A composite passes with probability at most `4^-rounds`, and far less for random candidates.
+pub fn miller_rabin_random<const LIMBS: usize, R: Rng + ?Sized>(
+    n: &Uint<LIMBS>,
+    rounds: usize,
+    rng: &mut R,
+) -> Primality {
+    if let Some(decided) = trial_division(n) {
+        return decided;
+    }
+    let monty = MontyParams::new(n);
+    let range = n.sub(&Uint::<1>::from_u64(3));
+    Primality::from_bool((0..rounds).all(|_| {
+        let base = Uint::random_below(rng, &range).add(&Uint::<1>::from_u64(2));
+        miller_rabin(&monty, &base)
+    }))
+}
+
+/// Tests `n` with the Baillie–PSW test followed by `rounds` Miller–Rabin rounds on random bases,
+/// as FIPS 186-5 allows for prime generation.
+pub fn is_probable_prime_with_rounds<const LIMBS: usize, R: Rng + ?Sized>(
+    n: &Uint<LIMBS>,
+    rounds: usize,
+    rng: &mut R,
+) -> Primality {
+    match n.is_probable_prime() {
+        Primality::ProbablyPrime => miller_rabin_random(n, rounds, rng),
+        decided => decided,
+    }
+}
+
+/// Miller–Rabin rounds on random bases for a random candidate of `bits` bits, after FIPS 186-5
+/// table B.1, for an error probability below `2^-100`.
+pub fn miller_rabin_rounds(bits: usize) -> usize {
+    match bits {
+        0..=255 => 40,
+        256..=511 => 10,
+        512..=1023 => 7,
+        1024..=1535 => 5,
+        _ => 4,
+    }
+}
+
+/// How far above its random starting point a search looks before drawing a new one.
+const SEARCH_WINDOW: u64 = 1 << 16;
+
+/// `floor(sqrt(2) * 2^63) + 1`: an RSA prime whose top 64 bits are at least this is above
+/// `sqrt(2) * 2^(bits - 1)`.
+const SQRT_2_TOP: u64 = 0xb504_f333_f9de_6485;
+
+/// Scans the odd integers in a window above the odd `start` for one that passes `accept`, sieving
+/// out those with a factor below [`SIEVE_LIMIT`] first. With `safe`, candidates `x` for which
+/// `2 x + 1` has such a factor are sieved out as well.
+///
+/// Returns `None` once the window is exhausted or the candidates outgrow `bits` bits.
+fn search<const LIMBS: usize>(
+    start: &Uint<LIMBS>,
+    bits: usize,
+    safe: bool,
+    mut accept: impl FnMut(&Uint<LIMBS>) -> bool,
+) -> Option<Uint<LIMBS>> {
+    // Below 2^11 candidates can be small primes themselves, which sieving would discard.
+    let sieving = bits > 11;
+    let residues = SMALL_PRIMES.map(|p| rem_small(start, p) as u64);
+    for delta in (0..SEARCH_WINDOW).step_by(2) {
+        let survives = !sieving
+            || SMALL_PRIMES.iter().zip(residues.iter()).all(|(&p, &r)| {
+                let p = p as u64;
+                let x = (r + delta) % p;
+                x != 0 && !(safe && x == p / 2)
+            });
+        if !survives {
+            continue;
+        }
+        let candidate = start.add(&Uint::<1>::from_u64(delta));
+        if candidate.bits() > bits {
+            return None;
+        }
+        if accept(&candidate) {
+            return Some(candidate);
+        }
+    }
+    None
+}
+
+/// Draws a random odd integer of exactly `bits` bits.
+fn random_odd<const LIMBS: usize, R: Rng + ?Sized>(rng: &mut R, bits: usize) -> Uint<LIMBS> {
+    let mut x = Uint::<LIMBS>::random_bits(rng, bits);
+    x.limbs[(bits - 1) / 64] |= 1 << ((bits - 1) % 64);
+    x.limbs[0] |= 1;
+    x
+}
+
+/// Returns a random prime of exactly `bits` bits.
+///
+/// Starts from a random odd number and searches upward with a sieve, testing survivors with
+/// [`is_probable_prime_with_rounds`] and [`miller_rabin_rounds`]. Primes following long gaps are
+/// slightly more likely than others. Panics unless `2 <= bits <= LIMBS * 64`.
+pub fn random_prime<const LIMBS: usize, R: Rng + ?Sized>(bits: usize, rng: &mut R) -> Uint<LIMBS> {
+    assert!(
+        (2..=Uint::<LIMBS>::BITS).contains(&bits),
+        "invalid prime size"
+    );
+    let rounds = miller_rabin_rounds(bits);
+    loop {
+        let start = random_odd(rng, bits);
+        let found = search(&start, bits, false, |candidate| {
+            is_probable_prime_with_rounds(candidate, rounds, rng).is_probably_prime()
+        });
+        if let Some(p) = found {
+            return p;
+        }
+    }
+}
+
+/// Returns a random safe prime `p` of exactly `bits` bits, so that `(p - 1) / 2` is prime too.
+///
+/// Searches like [`random_prime`] over `q = (p - 1) / 2`, sieving `q` and `2 q + 1` together.
+/// Only odd `q` are tried, so the result is at least 7. Panics unless `3 <= bits <= LIMBS * 64`.
+pub fn random_safe_prime<const LIMBS: usize, R: Rng + ?Sized>(
+    bits: usize,
+    rng: &mut R,
+) -> Uint<LIMBS> {
+    assert!(
+        (3..=Uint::<LIMBS>::BITS).contains(&bits),
+        "invalid prime size"
+    );
+    let rounds = miller_rabin_rounds(bits);
+    loop {
+        let start = random_odd(rng, bits - 1);
+        let found = search(&start, bits - 1, true, |q| {
+            // Baillie–PSW on both rejects almost every candidate before any random rounds.
+            let p = q.shl(1).add(&Uint::<1>::ONE);
+            q.is_probable_prime().is_probably_prime()
+                && p.is_probable_prime().is_probably_prime()
+                && miller_rabin_random(q, rounds, rng).is_probably_prime()
+                && miller_rabin_random(&p, rounds, rng).is_probably_prime()
+        });
+        if let Some(q) = found {
+            return q.shl(1).add(&Uint::<1>::ONE);
+        }
+    }
+}
+
+/// Returns a random prime of exactly `bits` bits suitable as an RSA factor, following FIPS
+/// 186-5, appendix A.1.3: `p >= sqrt(2) * 2^(bits - 1)`, so that the product of two such primes
+/// has exactly `2 * bits` bits, and `gcd(p - 1, e) = 1`.
+///
+/// Panics unless `64 <= bits <= LIMBS * 64` and `e` is odd and at least 3.
+pub fn random_rsa_prime<const LIMBS: usize, R: Rng + ?Sized>(
+    bits: usize,
+    e: u64,
+    rng: &mut R,
+) -> Uint<LIMBS> {
+    assert!(
+        (64..=Uint::<LIMBS>::BITS).contains(&bits),
+        "invalid prime size"
+    );
+    assert!(
+        e % 2 == 1 && e >= 3,
+        "the public exponent must be odd and at least 3"
+    );
+    let rounds = miller_rabin_rounds(bits);
+    let e = Uint::<LIMBS>::from_u64(e);
+    loop {
+        let start: Uint<LIMBS> = random_odd(rng, bits);
+        if start.shr(bits - 64).limbs[0] < SQRT_2_TOP {
+            continue;
+        }
+        let found = search(&start, bits, false, |candidate| {
+            candidate.sub(&Uint::<1>::ONE).gcd(&e) == Uint::ONE
+                && is_probable_prime_with_rounds(candidate, rounds, rng).is_probably_prime()
+        });
+        if let Some(p) = found {
+            return p;
+        }
+    }
+}
+
+/// Returns two RSA primes from [`random_rsa_prime`] that also satisfy the FIPS 186-5 distance
+/// requirement `|p - q| > 2^(bits - 100)`.
+///
+/// Panics under the same conditions as [`random_rsa_prime`].
+pub fn random_rsa_primes<const LIMBS: usize, R: Rng + ?Sized>(
+    bits: usize,
+    e: u64,
+    rng: &mut R,
+) -> (Uint<LIMBS>, Uint<LIMBS>) {
+    let p = random_rsa_prime(bits, e, rng);
+    loop {
+        let q = random_rsa_prime(bits, e, rng);
+        if far_apart(&p, &q, bits) {
+            return (p, q);
+        }
+    }
+}
+
+/// Returns whether `|p - q| > 2^(bits - 100)`, taking the bound as 1 for primes under 100 bits.
+fn far_apart<const LIMBS: usize>(p: &Uint<LIMBS>, q: &Uint<LIMBS>, bits: usize) -> bool {
+    let distance = if p > q { p.sub(q) } else { q.sub(p) };
+    distance > Uint::ONE.shl(bits.saturating_sub(100))
+}
+
+impl<const LIMBS: usize> Uint<LIMBS> {
+    /// Tests primality with the Baillie–PSW test: trial division by the primes below 2048,
+    /// Miller–Rabin to base 2 and a strong Lucas test.
+    ///
+    /// No composite passing Baillie–PSW is known, and none exists below `2^64`, so numbers of up
+    /// to 64 bits get a definite answer. Deterministic and variable-time.
+    pub fn is_probable_prime(&self) -> Primality {
+        if let Some(decided) = trial_division(self) {
+            return decided;
+        }
+        let monty = MontyParams::new(self);
+        if !miller_rabin(&monty, &Uint::from_u64(2)) || !strong_lucas(self) {
+            Primality::Composite
+        } else if self.bits() <= 64 {
+            Primality::Prime
+        } else {
+            Primality::ProbablyPrime
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rand::TestRng;
+
+    #[test]
+    fn test_small_primes_table() {
+        assert_eq!(SMALL_PRIMES[..6], [3, 5, 7, 11, 13, 17]);
+        assert_eq!(SMALL_PRIMES[SMALL_PRIME_COUNT - 1], 2039);
+        assert_eq!(
+            rem_small(&Uint::<2>::from_be_hex("10000000000000000"), 7),
+            2
+        );
+    }
+
+    fn is_prime_naive(n: u64) -> bool {
+        n > 1
+            && (2..n)
+                .take_while(|d| d * d <= n)
+                .all(|d| !n.is_multiple_of(d))
+    }
+
+    #[test]
+    fn test_small_values() {
+        let mut rng = TestRng(3);
+        for n in 0..3000u64 {
+            let x = Uint::<1>::from_u64(n);
+            let expected = if is_prime_naive(n) {
+                Primality::Prime
+            } else {
+                Primality::Composite
+            };
+            assert_eq!(x.is_probable_prime(), expected, "{n}");
+            assert_eq!(miller_rabin_random(&x, 10, &mut rng), expected, "{n}");
+            assert_eq!(miller_rabin_bases(&x, &[2]), expected, "{n}");
+        }
+    }
+
+    #[test]
+    fn test_agrees_with_naive_beyond_trial_division() {
+        // Above 2048^2 trial division is no longer conclusive, so every test has to do the work.
+        let start = 1u64 << 22;
+        for n in (start + 1..start + 4000).step_by(2) {
+            let x = Uint::<1>::from_u64(n);
+            let prime = is_prime_naive(n);
+            assert_eq!(x.is_probable_prime() == Primality::Prime, prime, "{n}");
+            assert_eq!(
+                miller_rabin_bases(&x, &DETERMINISTIC_BASES) == Primality::Prime,
+                prime
+            );
+        }
+    }
+
+    #[test]
+    fn test_strong_lucas() {
+        // The strong Lucas pseudoprimes below 20000 (OEIS A217255) and some primes.
+        let pseudoprimes = [5459, 5777, 10877, 16109, 18971];
+        for n in (1001..20000u64).step_by(2) {
+            let passes = strong_lucas(&Uint::<1>::from_u64(n));
+            assert_eq!(
+                passes,
+                is_prime_naive(n) || pseudoprimes.contains(&n),
+                "{n}"
+            );
+        }
+        // None of them is a strong pseudoprime to base 2, so Baillie–PSW rejects them.
+        for n in pseudoprimes {
+            assert_eq!(
+                Uint::<1>::from_u64(n).is_probable_prime(),
+                Primality::Composite
+            );
+        }
+        assert!(!is_square(&Uint::<2>::from_u64(5777)));
+        assert!(is_square(&Uint::<2>::from_be_hex(
+            "fffffffffffffffe0000000000000001"
+        )));
+    }
+
+    #[test]
+    fn test_strong_pseudoprimes() {
+        // psi_9 = 149491 * 747451 * 34233211 passes Miller–Rabin to the first nine prime bases,
+        // and psi_12 = 399165290221 * 798330580441 to the first twelve.
+        let psi9 = Uint::<2>::from_u64(3825123056546413051);
+        assert_eq!(
+            miller_rabin_bases(&psi9, &DETERMINISTIC_BASES[..9]),
+            Primality::ProbablyPrime
+        );
+        assert_eq!(
+            miller_rabin_bases(&psi9, &DETERMINISTIC_BASES),
+            Primality::Composite
+        );
+        assert_eq!(psi9.is_probable_prime(), Primality::Composite);
+
+        let psi12 = Uint::<2>::from_be_hex("437ae92817f9fc85b7e5");
+        assert_eq!(
+            miller_rabin_bases(&psi12, &DETERMINISTIC_BASES[..12]),
+            Primality::ProbablyPrime
+        );
+        assert_eq!(
+            miller_rabin_bases(&psi12, &DETERMINISTIC_BASES),
+            Primality::Composite
+        );
+        assert_eq!(psi12.is_probable_prime(), Primality::Composite);
+
+        // The bases must start at 2 to prove anything.
+        let p = Uint::<1>::from_u64(4294967291);
+        assert_eq!(
+            miller_rabin_bases(&p, &DETERMINISTIC_BASES),
+            Primality::Prime
+        );
+        assert_eq!(miller_rabin_bases(&p, &[3, 5, 7]), Primality::ProbablyPrime);
+        assert_eq!(miller_rabin_bases(&p, &[]), Primality::ProbablyPrime);
+    }
+
+    #[test]
+    fn test_large_values() {
+        let mut rng = TestRng(5);
+        // 2^127 - 1 is prime; 2^128 + 1 is divisible by 59649589127497217.
+        let m127 = Uint::<2>::from_be_hex("7FFFFFFFFFFFFFFFFFFFFFFFFFFFFFFF");
+        assert_eq!(m127.is_probable_prime(), Primality::ProbablyPrime);
+        assert_eq!(
+            miller_rabin_random(&m127, 20, &mut rng),
+            Primality::ProbablyPrime
+        );
+        assert_eq!(
+            miller_rabin_bases(&m127, &DETERMINISTIC_BASES),
+            Primality::ProbablyPrime
+        );
+        assert!(is_probable_prime_with_rounds(&m127, 5, &mut rng).is_probably_prime());
+        let f7 = Uint::<3>::from_be_hex("100000000000000000000000000000001");
+        assert_eq!(f7.is_probable_prime(), Primality::Composite);
+        assert_eq!(miller_rabin_random(&f7, 20, &mut rng), Primality::Composite);
+
+        // 2^521 - 1 is prime; 2^523 - 1 is not.
+        let mut m521 = Uint::<9>::MAX;
+        m521.limbs[8] = 0x1ff;
+        assert_eq!(m521.is_probable_prime(), Primality::ProbablyPrime);
+        let mut m523 = m521;
+        m523.limbs[8] = 0x7ff;
+        assert_eq!(m523.is_probable_prime(), Primality::Composite);
+
+        // The Carmichael number (6k + 1)(12k + 1)(18k + 1) for k = 426, whose factors all lie
+        // above the trial division bound, fools the Fermat test but not Miller–Rabin.
+        let carmichael = Uint::<2>::from_u64(2557 * 5113 * 7669);
+        assert_eq!(
+            miller_rabin_random(&carmichael, 20, &mut rng),
+            Primality::Composite
+        );
+        assert_eq!(carmichael.is_probable_prime(), Primality::Composite);
+    }
+
+    #[test]
+    fn test_square_of_prime() {
+        // Squares have no Selfridge parameter; the Lucas test has to detect them separately.
+        let p = 4294967291u64;
+        let square = Uint::<2>::from_u64(p).mul(&Uint::<1>::from_u64(p));
+        assert!(!strong_lucas(&square));
+        assert_eq!(square.is_probable_prime(), Primality::Composite);
+    }
+
+    #[test]
+    fn test_random_prime() {
+        let mut rng = TestRng(11);
+        for bits in [2, 3, 8, 11, 12, 20, 64, 65, 127] {
+            for _ in 0..5 {
+                let p: Uint<2> = random_prime(bits, &mut rng);
+                assert_eq!(p.bits(), bits);
+                assert!(p.is_probable_prime().is_probably_prime(), "{bits}: {p:x}");
+            }
+        }
+        let p: Uint<4> = random_prime(256, &mut rng);
+        assert_eq!(p.bits(), 256);
+        assert!(p.is_probable_prime().is_probably_prime());
+
+        // The generator only depends on the random stream.
+        let a: Uint<4> = random_prime(200, &mut TestRng(1));
+        let b: Uint<4> = random_prime(200, &mut TestRng(1));
+        assert_eq!(a, b);
+    }
+
+    #[test]
+    fn test_random_safe_prime() {
+        let mut rng = TestRng(12);
+        let p: Uint<1> = random_safe_prime(3, &mut rng);
+        assert_eq!(p, Uint::from_u64(7));
+        for bits in [4, 8, 12, 13, 20, 64, 128] {
+            let p: Uint<2> = random_safe_prime(bits, &mut rng);
+            let q = p.shr(1);
+            assert_eq!(p.bits(), bits);
+            assert!(p.is_probable_prime().is_probably_prime(), "{bits}: {p:x}");
+            assert!(q.is_probable_prime().is_probably_prime(), "{bits}: {q:x}");
+        }
+    }
+
+    #[test]
+    fn test_random_rsa_primes() {
+        let mut rng = TestRng(13);
+        let e = 65537;
+        let (p, q): (Uint<4>, Uint<4>) = random_rsa_primes(256, e, &mut rng);
+        for x in [p, q] {
+            assert_eq!(x.bits(), 256);
+            assert!(x.shr(192).limbs[0] >= SQRT_2_TOP);
+            assert_ne!(rem_small(&x, e as u32), 1);
+            assert!(x.is_probable_prime().is_probably_prime());
+        }
+        // The product of two such primes has exactly twice the bits.
+        assert_eq!(p.resize::<8>().mul(&q).bits(), 512);
+        assert!(far_apart(&p, &q, 256));
+
+        // The distance must exceed 2^(bits - 100) strictly.
+        let bound = Uint::<4>::ONE.shl(156);
+        assert!(!far_apart(&p, &p.add(&bound), 256));
+        assert!(!far_apart(&p.add(&bound), &p, 256));
+        assert!(far_apart(&p, &p.add(&bound).add(&Uint::<1>::ONE), 256));
+        assert!(!far_apart(&p, &p, 80));
+        assert!(!far_apart(&p, &p.add(&Uint::<1>::ONE), 80));
+        assert!(far_apart(&p, &p.add(&Uint::<1>::from_u64(2)), 80));
+
+        // e = 3 rules out primes congruent to 1 modulo 3.
+        let p: Uint<2> = random_rsa_prime(128, 3, &mut rng);
+        assert_eq!(rem_small(&p, 3), 2);
+    }
+}
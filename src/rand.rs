@@ -0,0 +1,201 @@
+//! Random number generation.
+//!
+//! The library does not ship a generator. Random integers ([`Uint::random`],
+//! [`Uint::random_below`], [`Fp::random`]) and operations that need randomness, such as prime and
+//! RSA key generation, take any [`Rng`] implementation from the caller. For key material it must
+//! be a cryptographically secure generator.
+//!
+//! With the `rand_core` feature, [`RandCore`] wraps any `rand_core::RngCore`, such as the
+//! generators of the `rand` crate.
+
+use crate::{
+    field::{FieldParams, Fp},
+    uint::Uint,
+};
+
+/// A source of random bits.
+pub trait Rng {
+    /// Returns the next random `u64`.
+    fn next_u64(&mut self) -> u64;
+
+    /// Fills `dest` with random bytes.
+    ///
+    /// The default implementation consumes one [`Rng::next_u64`] call per eight bytes.
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        for chunk in dest.chunks_mut(8) {
+            let bytes = self.next_u64().to_le_bytes();
+            chunk.copy_from_slice(&bytes[..chunk.len()]);
+        }
+    }
+}
+
+impl<R: Rng + ?Sized> Rng for &mut R {
+    fn next_u64(&mut self) -> u64 {
+        (**self).next_u64()
+    }
+
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        (**self).fill_bytes(dest)
+    }
+}
+
+/// Adapts a `rand_core` generator, for example `RandCore(rand::rngs::OsRng)`, to [`Rng`].
+#[cfg(feature = "rand_core")]
+#[derive(Debug, Clone, Default)]
+pub struct RandCore<R>(pub R);
+
+#[cfg(feature = "rand_core")]
+impl<R: rand_core::RngCore> Rng for RandCore<R> {
+    fn next_u64(&mut self) -> u64 {
+        self.0.next_u64()
+    }
+
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        self.0.fill_bytes(dest)
+    }
+}
+
+impl<const LIMBS: usize> Uint<LIMBS> {
+    /// Returns a uniformly random integer in `[0, 2^(64 * LIMBS))`.
+    pub fn random<R: Rng + ?Sized>(rng: &mut R) -> Self {
+        Self::random_bits(rng, Self::BITS)
+    }
+
+    /// Returns a uniformly random integer in `[0, 2^bits)`. Panics if `bits > LIMBS * 64`.
+    pub fn random_bits<R: Rng + ?Sized>(rng: &mut R, bits: usize) -> Self {
+        assert!(bits <= Self::BITS, "bit length exceeds the integer width");
+        let mut x = Self::ZERO;
+        for limb in x.limbs.iter_mut().take(bits.div_ceil(64)) {
+            *limb = rng.next_u64();
+        }
+        if !bits.is_multiple_of(64) {
+            x.limbs[bits / 64] &= (1 << (bits % 64)) - 1;
+        }
+        x
+    }
+
+    /// Returns a uniformly random integer in `[0, bound)`. Panics if `bound` is zero.
+    ///
+    /// Draws integers of the bit length of `bound` until one falls below it, so the result is
+    /// exactly uniform; each draw succeeds with probability above one half. Only the number of
+    /// draws depends on the random values.
+    pub fn random_below<R: Rng + ?Sized>(rng: &mut R, bound: &Self) -> Self {
+        assert!(!bound.is_zero(), "bound must be non-zero");
+        loop {
+            let x = Self::random_bits(rng, bound.bits());
+            if x < *bound {
+                return x;
+            }
+        }
+    }
+
+    /// Returns a uniformly random residue modulo `modulus`, in `[0, modulus)`. Panics if
+    /// `modulus` is zero.
+    ///
+    /// The same distribution as [`Uint::random_below`]. Reducing [`Uint::random`] instead would
+    /// favour small residues unless `modulus` divides `2^(64 * LIMBS)`.
+    pub fn random_mod<R: Rng + ?Sized>(rng: &mut R, modulus: &Self) -> Self {
+        Self::random_below(rng, modulus)
+    }
+}
+
+impl<const LIMBS: usize, P: FieldParams<LIMBS>> Fp<LIMBS, P> {
+    /// Returns a uniformly random field element.
+    pub fn random<R: Rng + ?Sized>(rng: &mut R) -> Self {
+        Self::from_uint(&Uint::random_mod(rng, &P::MODULUS))
+    }
+}
+
+/// A small deterministic generator (SplitMix64) for tests.
+#[cfg(test)]
+pub(crate) struct TestRng(pub u64);
+
+#[cfg(test)]
+impl Rng for TestRng {
+    fn next_u64(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fill_bytes_uses_whole_words() {
+        let mut rng = TestRng(7);
+        let mut bytes = [0u8; 12];
+        rng.fill_bytes(&mut bytes);
+        let mut check = TestRng(7);
+        assert_eq!(bytes[..8], check.next_u64().to_le_bytes());
+        assert_eq!(bytes[8..], check.next_u64().to_le_bytes()[..4]);
+    }
+
+    #[test]
+    fn test_random_bits_and_below() {
+        let mut rng = TestRng(1);
+        for bits in [0, 1, 63, 64, 65, 130] {
+            let x = Uint::<3>::random_bits(&mut rng, bits);
+            assert!(x.bits() <= bits);
+        }
+        let bound = Uint::<2>::from_be_hex("10000000000000001");
+        for _ in 0..100 {
+            assert!(Uint::random_below(&mut rng, &bound) < bound);
+        }
+        assert_eq!(Uint::random_below(&mut rng, &Uint::<2>::ONE), Uint::ZERO);
+        assert_ne!(Uint::<4>::random(&mut rng), Uint::<4>::random(&mut rng));
+    }
+
+    #[test]
+    fn test_random_mod_is_uniform() {
+        // With the modulus 3 * 2^62 a reduced 64-bit draw would hit [0, 2^62) twice as often as
+        // the rest; rejection sampling keeps each third of the range equally likely.
+        let mut rng = TestRng(2);
+        let modulus = Uint::<1>::from_u64(3 << 62);
+        let mut counts = [0u32; 3];
+        for _ in 0..3000 {
+            counts[(Uint::random_mod(&mut rng, &modulus).limbs[0] >> 62) as usize] += 1;
+        }
+        assert!(
+            counts.iter().all(|&c| (900..1100).contains(&c)),
+            "{counts:?}"
+        );
+    }
+
+    #[test]
+    fn test_random_field_element() {
+        use crate::curves::secp256k1::FieldElement;
+        let mut rng = TestRng(4);
+        let x = FieldElement::random(&mut rng);
+        assert_ne!(x, FieldElement::random(&mut rng));
+        assert!(x.to_uint() < FieldElement::MODULUS);
+    }
+
+    #[cfg(feature = "rand_core")]
+    #[test]
+    fn test_rand_core_adapter() {
+        struct Counter(u64);
+        impl rand_core::RngCore for Counter {
+            fn next_u32(&mut self) -> u32 {
+                self.next_u64() as u32
+            }
+            fn next_u64(&mut self) -> u64 {
+                self.0 += 1;
+                self.0
+            }
+            fn fill_bytes(&mut self, dest: &mut [u8]) {
+                rand_core::impls::fill_bytes_via_next(self, dest)
+            }
+            fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), rand_core::Error> {
+                self.fill_bytes(dest);
+                Ok(())
+            }
+        }
+        let x = Uint::<2>::random(&mut RandCore(Counter(0)));
+        assert_eq!(x.limbs, [1, 2]);
+    }
+}
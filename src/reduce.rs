@@ -0,0 +1,279 @@
+//! Special-form reductions of double-width products.
+//!
+//! Each routine takes a `2 * LIMBS`-limb product split into `(lo, hi)` halves, as returned by
+//! [`Uint::widening_mul`], and returns the fully reduced residue. They plug into [`Fp`] through
+//! [`Reduction::Special`], in which case field elements are kept in canonical rather than
+//! Montgomery form.
+//!
+//! [`Fp`]: crate::Fp
+//! [`Reduction::Special`]: crate::field::Reduction::Special
+
+use crate::{
+    limb::{adc, mac, sbb},
+    uint::Uint,
+};
+
+/// Reduces modulo the pseudo-Mersenne prime `p = 2^K - C`.
+///
+/// Since `2^(64 * LIMBS) = 2^(64 * LIMBS - K) * 2^K` is congruent to `C' = C * 2^(64 * LIMBS - K)`,
+/// the high half is folded into the low half by multiplying with `C'`, which needs only a single
+/// limb multiplication per limb. Requires `LIMBS >= 2`, `64 * (LIMBS - 1) < K <= 64 * LIMBS` and
+/// `C'` to fit in one limb.
+pub fn reduce_pseudo_mersenne<const LIMBS: usize, const K: usize, const C: u64>(
+    lo: &Uint<LIMBS>,
+    hi: &Uint<LIMBS>,
+) -> Uint<LIMBS> {
+    let shift = LIMBS * 64 - K;
+    assert!(
+        LIMBS >= 2 && shift < 64,
+        "pseudo-Mersenne exponent out of range"
+    );
+    assert!(
+        C.leading_zeros() as usize >= shift,
+        "pseudo-Mersenne constant too large"
+    );
+    let c_wide = C << shift;
+
+    // t = lo + hi * C', leaving a top limb no larger than C'
+    let mut t = [0u64; LIMBS];
+    let mut top = 0;
+    for (i, t_i) in t.iter_mut().enumerate() {
+        (*t_i, top) = mac(lo.limbs[i], hi.limbs[i], c_wide, top);
+    }
+
+    // Fold the top limb back in twice: the first fold can carry at most once more, and when it
+    // does the low part is small enough that the second fold cannot
+    let product = top as u128 * c_wide as u128;
+    let mut carry;
+    (t[0], carry) = adc(t[0], product as u64, 0);
+    (t[1], carry) = adc(t[1], (product >> 64) as u64, carry);
+    for t_i in t.iter_mut().skip(2) {
+        (*t_i, carry) = adc(*t_i, 0, carry);
+    }
+    let mut carry_in = carry * c_wide;
+    for t_i in t.iter_mut() {
+        (*t_i, carry_in) = adc(*t_i, carry_in, 0);
+    }
+
+    // When K is not a multiple of 64, fold the bits above 2^K with C itself
+    if shift != 0 {
+        let above = t[LIMBS - 1] >> (64 - shift);
+        t[LIMBS - 1] &= u64::MAX >> shift;
+        let mut carry = above * C;
+        for t_i in t.iter_mut() {
+            (*t_i, carry) = adc(*t_i, carry, 0);
+        }
+    }
+
+    // The value is now below 2p. Build 2^K - C; for K = 64 * LIMBS the shift yields zero and the
+    // subtraction wraps to the intended modulus.
+    let (modulus, _) = Uint::<LIMBS>::ONE
+        .shl(K)
+        .overflowing_sub(&Uint::from_u64(C));
+    let t = Uint { limbs: t };
+    let (diff, borrow) = t.overflowing_sub(&modulus);
+    Uint::select(&diff, &t, borrow as u64)
+}
+
+/// Reduces modulo `2^255 - 19`, the Curve25519 base field prime.
+pub fn reduce_p25519(lo: &Uint<4>, hi: &Uint<4>) -> Uint<4> {
+    reduce_pseudo_mersenne::<4, 255, 19>(lo, hi)
+}
+
+/// Reduces modulo `2^256 - 2^32 - 977`, the secp256k1 base field prime.
+pub fn reduce_secp256k1(lo: &Uint<4>, hi: &Uint<4>) -> Uint<4> {
+    reduce_pseudo_mersenne::<4, 256, 0x1_0000_03D1>(lo, hi)
+}
+
+/// The NIST P-256 prime `2^256 - 2^224 + 2^192 + 2^96 - 1`.
+const P256: Uint<4> =
+    Uint::from_be_hex("FFFFFFFF00000001000000000000000000000000FFFFFFFFFFFFFFFFFFFFFFFF");
+
+/// Reduces modulo the NIST P-256 Solinas prime using the 32-bit word identities of FIPS 186-4,
+/// appendix D.2.3.
+pub fn reduce_p256(lo: &Uint<4>, hi: &Uint<4>) -> Uint<4> {
+    let mut c = [0i64; 16];
+    for i in 0..4 {
+        c[2 * i] = (lo.limbs[i] & 0xFFFF_FFFF) as i64;
+        c[2 * i + 1] = (lo.limbs[i] >> 32) as i64;
+        c[8 + 2 * i] = (hi.limbs[i] & 0xFFFF_FFFF) as i64;
+        c[8 + 2 * i + 1] = (hi.limbs[i] >> 32) as i64;
+    }
+
+    // The nine terms of the standard, written least significant word first
+    let s1 = [c[0], c[1], c[2], c[3], c[4], c[5], c[6], c[7]];
+    let s2 = [0, 0, 0, c[11], c[12], c[13], c[14], c[15]];
+    let s3 = [0, 0, 0, c[12], c[13], c[14], c[15], 0];
+    let s4 = [c[8], c[9], c[10], 0, 0, 0, c[14], c[15]];
+    let s5 = [c[9], c[10], c[11], c[13], c[14], c[15], c[13], c[8]];
+    let s6 = [c[11], c[12], c[13], 0, 0, 0, c[8], c[10]];
+    let s7 = [c[12], c[13], c[14], c[15], 0, 0, c[9], c[11]];
+    let s8 = [c[13], c[14], c[15], c[8], c[9], c[10], 0, c[12]];
+    let s9 = [c[14], c[15], 0, c[9], c[10], c[11], 0, c[13]];
+
+    let mut w = [0i64; 8];
+    for j in 0..8 {
+        w[j] = s1[j] + 2 * s2[j] + 2 * s3[j] + s4[j] + s5[j] - s6[j] - s7[j] - s8[j] - s9[j];
+    }
+
+    // Normalize to 32-bit words plus a small signed multiple of 2^256, then fold that multiple
+    // back using 2^256 = 2^224 - 2^192 - 2^96 + 1. The sum lies in (-4 * 2^256, 5 * 2^256), so
+    // two folds bring the carry to zero.
+    let mut top = normalize_words(&mut w);
+    for _ in 0..2 {
+        w[0] += top;
+        w[3] -= top;
+        w[6] -= top;
+        w[7] += top;
+        top = normalize_words(&mut w);
+    }
+
+    let mut t = Uint::<4>::ZERO;
+    for i in 0..4 {
+        t.limbs[i] = (w[2 * i] as u64) | ((w[2 * i + 1] as u64) << 32);
+    }
+    let (diff, borrow) = t.overflowing_sub(&P256);
+    Uint::select(&diff, &t, borrow as u64)
+}
+
+/// Propagates signed carries so every word lies in `[0, 2^32)`, returning the carry out of the
+/// top word.
+fn normalize_words(w: &mut [i64; 8]) -> i64 {
+    let mut carry = 0;
+    for word in w.iter_mut() {
+        *word += carry;
+        carry = *word >> 32;
+        *word &= 0xFFFF_FFFF;
+    }
+    carry
+}
+
+/// The Goldilocks prime `2^64 - 2^32 + 1`.
+const GOLDILOCKS: u64 = 0xFFFF_FFFF_0000_0001;
+
+/// `2^64 mod p` for the Goldilocks prime.
+const GOLDILOCKS_EPSILON: u64 = 0xFFFF_FFFF;
+
+/// Reduces modulo the Goldilocks prime `2^64 - 2^32 + 1`.
+///
+/// Writing the product as `lo + 2^64 * hi_lo + 2^96 * hi_hi`, the identities `2^64 = 2^32 - 1`
+/// and `2^96 = -1` reduce it to `lo - hi_hi + hi_lo * (2^32 - 1)`.
+pub fn reduce_goldilocks(lo: &Uint<1>, hi: &Uint<1>) -> Uint<1> {
+    let (lo, hi) = (lo.limbs[0], hi.limbs[0]);
+    let hi_hi = hi >> 32;
+    let hi_lo = hi & GOLDILOCKS_EPSILON;
+
+    // lo - hi_hi; a borrow means 2^64 too much was added, which is EPSILON modulo p
+    let (t0, borrow) = sbb(lo, hi_hi, 0);
+    let t0 = t0.wrapping_sub(GOLDILOCKS_EPSILON & borrow.wrapping_neg());
+
+    // + hi_lo * EPSILON; a carry here again stands for EPSILON
+    let (t1, carry) = adc(t0, hi_lo * GOLDILOCKS_EPSILON, 0);
+    let t1 = t1.wrapping_add(GOLDILOCKS_EPSILON & carry.wrapping_neg());
+
+    let (diff, borrow) = sbb(t1, GOLDILOCKS, 0);
+    Uint::select(&Uint::from_u64(diff), &Uint::from_u64(t1), borrow)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::monty::MontyParams;
+
+    /// Deterministic xorshift generator for test inputs.
+    struct XorShift(u64);
+
+    impl XorShift {
+        fn next_u64(&mut self) -> u64 {
+            self.0 ^= self.0 << 13;
+            self.0 ^= self.0 >> 7;
+            self.0 ^= self.0 << 17;
+            self.0
+        }
+
+        fn uint<const LIMBS: usize>(&mut self) -> Uint<LIMBS> {
+            let mut x = Uint::ZERO;
+            for limb in x.limbs.iter_mut() {
+                *limb = self.next_u64();
+            }
+            x
+        }
+    }
+
+    /// Reduces `lo + hi * R` with Montgomery arithmetic as a reference. The Montgomery form of
+    /// `hi` is exactly `hi * R mod p`.
+    fn reference<const LIMBS: usize>(
+        modulus: &Uint<LIMBS>,
+        lo: &Uint<LIMBS>,
+        hi: &Uint<LIMBS>,
+    ) -> Uint<LIMBS> {
+        let monty = MontyParams::new(modulus);
+        let lo = monty.from_monty(&monty.to_monty(lo));
+        monty.to_monty(hi).add_mod(&lo, modulus)
+    }
+
+    fn check_against_reference<const LIMBS: usize>(
+        modulus: &Uint<LIMBS>,
+        reduce: fn(&Uint<LIMBS>, &Uint<LIMBS>) -> Uint<LIMBS>,
+    ) {
+        let mut rng = XorShift(0x9E37_79B9_7F4A_7C15);
+        let (p_minus_one, _) = modulus.overflowing_sub(&Uint::ONE);
+        let mut edge_cases = vec![
+            (Uint::ZERO, Uint::ZERO),
+            (*modulus, Uint::ZERO),
+            (p_minus_one, Uint::ZERO),
+            (Uint::MAX, Uint::MAX),
+            (Uint::ZERO, Uint::MAX),
+            p_minus_one.widening_mul(&p_minus_one),
+        ];
+        for _ in 0..500 {
+            edge_cases.push((rng.uint(), rng.uint()));
+            let a = rng.uint::<LIMBS>();
+            edge_cases.push(a.widening_mul(&rng.uint()));
+        }
+        for (lo, hi) in edge_cases {
+            assert_eq!(
+                reduce(&lo, &hi),
+                reference(modulus, &lo, &hi),
+                "lo={lo:x} hi={hi:x}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_p25519() {
+        let p =
+            Uint::from_be_hex("7FFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFED");
+        check_against_reference(&p, reduce_p25519);
+    }
+
+    #[test]
+    fn test_secp256k1() {
+        let p =
+            Uint::from_be_hex("FFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFEFFFFFC2F");
+        check_against_reference(&p, reduce_secp256k1);
+    }
+
+    #[test]
+    fn test_p256() {
+        check_against_reference(&P256, reduce_p256);
+    }
+
+    #[test]
+    fn test_goldilocks() {
+        check_against_reference(&Uint::from_u64(GOLDILOCKS), reduce_goldilocks);
+    }
+
+    #[test]
+    fn test_generic_pseudo_mersenne() {
+        // 2^127 - 1 and 2^192 - 237
+        check_against_reference(
+            &Uint::from_be_hex("7FFFFFFFFFFFFFFFFFFFFFFFFFFFFFFF"),
+            reduce_pseudo_mersenne::<2, 127, 1>,
+        );
+        check_against_reference(
+            &Uint::from_be_hex("FFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFF13"),
+            reduce_pseudo_mersenne::<3, 192, 237>,
+        );
+    }
+}
@@ -0,0 +1,369 @@
+//! Raw RSA primitives from RFC 8017, with CRT private operations.
+//!
+//! This module implements key generation and the bare RSAEP/RSADP/RSASP1/RSAVP1 functions on
+//! integer representatives. They are building blocks: padding (OAEP, PSS, PKCS#1 v1.5) is the
+//! caller's job, and raw RSA on unpadded messages is not secure.
+//!
+//! A private key with `LIMBS`-limb modulus stores its primes and CRT exponents in `HALF`-limb
+//! integers, where `LIMBS = 2 * HALF`; the aliases [`RsaPrivateKey2048`], [`RsaPrivateKey3072`]
+//! and [`RsaPrivateKey4096`] fix the usual sizes. Private operations blind their input with a
+//! fresh random factor, recombine the CRT halves with Garner's formula and check the result
+//! against the public key before returning it.
+
+use core::fmt;
+
+use crate::{monty::MontyParams, prime, rand::Rng, uint::Uint};
+
+/// The public exponent used by key generation, `65537`.
+pub const PUBLIC_EXPONENT: u64 = 65537;
+
+/// An RSA public key `(n, e)`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RsaPublicKey<const LIMBS: usize> {
+    n: Uint<LIMBS>,
+    e: Uint<LIMBS>,
+    monty: MontyParams<LIMBS>,
+}
+
+/// A 2048-bit RSA public key.
+pub type RsaPublicKey2048 = RsaPublicKey<32>;
+
+/// A 3072-bit RSA public key.
+pub type RsaPublicKey3072 = RsaPublicKey<48>;
+
+/// A 4096-bit RSA public key.
+pub type RsaPublicKey4096 = RsaPublicKey<64>;
+
+impl<const LIMBS: usize> RsaPublicKey<LIMBS> {
+    /// Creates a public key.
+    ///
+    /// Returns `None` unless `n` is odd and `e` is an odd integer with `3 <= e < n`.
+    pub fn new(n: Uint<LIMBS>, e: Uint<LIMBS>) -> Option<Self> {
+        let valid = n.is_odd() && e.is_odd() && e.bits() >= 2 && e < n;
+        valid.then(|| Self {
+            n,
+            e,
+            monty: MontyParams::new(&n),
+        })
+    }
+
+    /// Returns the modulus `n`.
+    pub fn n(&self) -> &Uint<LIMBS> {
+        &self.n
+    }
+
+    /// Returns the public exponent `e`.
+    pub fn e(&self) -> &Uint<LIMBS> {
+        &self.e
+    }
+
+    /// RSAEP: returns `m^e mod n`, or `None` if the message representative is not below `n`.
+    pub fn encrypt_raw(&self, m: &Uint<LIMBS>) -> Option<Uint<LIMBS>> {
+        (*m < self.n).then(|| self.public_op(m))
+    }
+
+    /// RSAVP1: returns the message representative `s^e mod n` of a signature, or `None` if the
+    /// signature representative is not below `n`.
+    pub fn verify_raw(&self, s: &Uint<LIMBS>) -> Option<Uint<LIMBS>> {
+        self.encrypt_raw(s)
+    }
+
+    fn public_op(&self, x: &Uint<LIMBS>) -> Uint<LIMBS> {
+        let monty = &self.monty;
+        monty.from_monty(&monty.pow_vartime(&monty.to_monty(x), &self.e))
+    }
+}
+
+/// An RSA private key holding the primes and CRT parameters of RFC 8017, section 3.2.
+///
+/// `Debug` output only shows the public key.
+#[derive(Clone)]
+pub struct RsaPrivateKey<const LIMBS: usize, const HALF: usize> {
+    public: RsaPublicKey<LIMBS>,
+    d: Uint<LIMBS>,
+    p: Uint<HALF>,
+    q: Uint<HALF>,
+    /// `d mod (p - 1)`.
+    dp: Uint<HALF>,
+    /// `d mod (q - 1)`.
+    dq: Uint<HALF>,
+    /// `q^-1 mod p`.
+    qinv: Uint<HALF>,
+    monty_p: MontyParams<HALF>,
+    monty_q: MontyParams<HALF>,
+}
+
+/// A 2048-bit RSA private key.
+pub type RsaPrivateKey2048 = RsaPrivateKey<32, 16>;
+
+/// A 3072-bit RSA private key.
+pub type RsaPrivateKey3072 = RsaPrivateKey<48, 24>;
+
+/// A 4096-bit RSA private key.
+pub type RsaPrivateKey4096 = RsaPrivateKey<64, 32>;
+
+impl<const LIMBS: usize, const HALF: usize> RsaPrivateKey<LIMBS, HALF> {
+    const SIZES_MATCH: () = assert!(
+        LIMBS == 2 * HALF,
+        "the modulus must be twice the prime size"
+    );
+
+    /// Generates a key with `e = 65537` and a modulus of exactly `LIMBS * 64` bits.
+    ///
+    /// The primes come from [`prime::random_rsa_primes`], which follows FIPS 186-5. `rng` must be
+    /// cryptographically secure. Like [`RsaPrivateKey::from_primes`], this is not constant time.
+    pub fn generate<R: Rng + ?Sized>(rng: &mut R) -> Self {
+        let e = Uint::from_u64(PUBLIC_EXPONENT);
+        loop {
+            let (p, q) = prime::random_rsa_primes(Uint::<HALF>::BITS, PUBLIC_EXPONENT, rng);
+            if let Some(key) = Self::from_primes(&p, &q, &e) {
+                return key;
+            }
+        }
+    }
+
+    /// Builds a key from its primes and public exponent, computing `d = e^-1 mod lcm(p - 1,
+    /// q - 1)` and the CRT parameters.
+    ///
+    /// Returns `None` if `p` or `q` is even or smaller than 3, `p = q`, `e` is not invertible
+    /// modulo `lcm(p - 1, q - 1)` or does not give a valid public key. The primes are not tested
+    /// for primality; see [`RsaPrivateKey::validate`].
+    ///
+    /// This is not constant time: the reductions are, but the gcd behind `lcm(p - 1, q - 1)` and
+    /// the inversions of `e` and `q` use the variable-time extended Euclidean algorithm on secret
+    /// values. Build keys where their timing cannot be observed.
+    pub fn from_primes(p: &Uint<HALF>, q: &Uint<HALF>, e: &Uint<LIMBS>) -> Option<Self> {
+        let () = Self::SIZES_MATCH;
+        if !p.is_odd() || !q.is_odd() || p.bits() < 2 || q.bits() < 2 || p == q {
+            return None;
+        }
+        let n = p.resize::<LIMBS>().mul(q);
+        let public = RsaPublicKey::new(n, *e)?;
+
+        let p1 = p.sub(&Uint::<1>::ONE).resize::<LIMBS>();
+        let q1 = q.sub(&Uint::<1>::ONE).resize::<LIMBS>();
+        let lambda = p1.mul(&q1).ct_div_rem(&p1.gcd(&q1)).0;
+        let d = e.inv_mod(&lambda)?;
+
+        Some(Self {
+            public,
+            d,
+            p: *p,
+            q: *q,
+            dp: d.ct_rem(&p1).resize(),
+            dq: d.ct_rem(&q1).resize(),
+            qinv: q.inv_mod(p)?,
+            monty_p: MontyParams::new(p),
+            monty_q: MontyParams::new(q),
+        })
+    }
+
+    /// Returns the public key.
+    pub fn public_key(&self) -> &RsaPublicKey<LIMBS> {
+        &self.public
+    }
+
+    /// Returns the private exponent `d`.
+    pub fn d(&self) -> &Uint<LIMBS> {
+        &self.d
+    }
+
+    /// Returns the prime factors `(p, q)`.
+    pub fn primes(&self) -> (&Uint<HALF>, &Uint<HALF>) {
+        (&self.p, &self.q)
+    }
+
+    /// RSADP: returns `c^d mod n`, or `None` if the ciphertext representative is not below `n`
+    /// or the result fails the check against the public key.
+    ///
+    /// `rng` supplies the blinding factor.
+    pub fn decrypt_raw<R: Rng + ?Sized>(
+        &self,
+        c: &Uint<LIMBS>,
+        rng: &mut R,
+    ) -> Option<Uint<LIMBS>> {
+        if *c >= self.public.n {
+            return None;
+        }
+        self.private_op(c, rng)
+    }
+
+    /// RSASP1: returns the signature representative `m^d mod n`, or `None` if the message
+    /// representative is not below `n` or the result fails the check against the public key.
+    ///
+    /// `rng` supplies the blinding factor.
+    pub fn sign_raw<R: Rng + ?Sized>(&self, m: &Uint<LIMBS>, rng: &mut R) -> Option<Uint<LIMBS>> {
+        self.decrypt_raw(m, rng)
+    }
+
+    /// Checks that the key is consistent: `n = p q` with `p` and `q` distinct probable primes,
+    /// `e` odd, `d`, `dp` and `dq` inverses of `e` modulo `p - 1` and `q - 1`, `qinv` the inverse
+    /// of `q` modulo `p`, and a private operation that the public key undoes.
+    ///
+    /// Primality is checked with [`Uint::is_probable_prime`].
+    pub fn validate(&self) -> bool {
+        let (p, q) = (&self.p, &self.q);
+        let e = &self.public.e;
+        let one = Uint::<LIMBS>::ONE;
+        let p1 = p.sub(&Uint::<1>::ONE).resize::<LIMBS>();
+        let q1 = q.sub(&Uint::<1>::ONE).resize::<LIMBS>();
+
+        let structure = p != q
+            && p.resize::<LIMBS>().mul(q) == self.public.n
+            && self.d < self.public.n
+            && self.d.rem(&p1) == self.dp.resize()
+            && self.d.rem(&q1) == self.dq.resize()
+            && self.dp.resize::<LIMBS>().mul(&e.rem(&p1)).rem(&p1) == one
+            && self.dq.resize::<LIMBS>().mul(&e.rem(&q1)).rem(&q1) == one
+            && self.qinv.resize::<LIMBS>().mul(q).rem(&p.resize()) == one;
+        if !structure || !p.is_probable_prime().is_probably_prime() {
+            return false;
+        }
+        if !q.is_probable_prime().is_probably_prime() {
+            return false;
+        }
+        let m = Uint::from_u64(2);
+        self.public.public_op(&self.crt(&m)) == m
+    }
+
+    /// Computes `x^d mod n` with blinding and a final check against the public key.
+    fn private_op<R: Rng + ?Sized>(&self, x: &Uint<LIMBS>, rng: &mut R) -> Option<Uint<LIMBS>> {
+        let monty = &self.public.monty;
+        // Inversion is variable time, so it runs on r s for a second random s rather than on r:
+        // r s is uniform and independent of r, and r^-1 = s (r s)^-1. r s is invertible unless r
+        // or s shares a factor with n, which reveals p or q and practically never happens.
+        let (r, r_inv) = loop {
+            let r = Uint::random_below(rng, &self.public.n);
+            let s = Uint::random_below(rng, &self.public.n);
+            let rs = monty.mul(&monty.to_monty(&r), &s);
+            if let Some(rs_inv) = rs.inv_mod(&self.public.n) {
+                break (r, monty.mul(&monty.to_monty(&rs_inv), &s));
+            }
+        };
+        let blind = monty.to_monty(&self.public.public_op(&r));
+        let blinded = monty.mul(&blind, x);
+        let y = monty.mul(&monty.to_monty(&self.crt(&blinded)), &r_inv);
+
+        (self.public.public_op(&y) == *x).then_some(y)
+    }
+
+    /// Computes `x^d mod n` for `x < n` from `x^dp mod p` and `x^dq mod q` (Garner).
+    fn crt(&self, x: &Uint<LIMBS>) -> Uint<LIMBS> {
+        let (mp, mq) = (&self.monty_p, &self.monty_q);
+        let xp = x.ct_rem(&self.p.resize()).resize::<HALF>();
+        let xq = x.ct_rem(&self.q.resize()).resize::<HALF>();
+        let m1 = mp.from_monty(&mp.pow(&mp.to_monty(&xp), &self.dp));
+        let m2 = mq.from_monty(&mq.pow(&mq.to_monty(&xq), &self.dq));
+
+        // h = qinv (m1 - m2) mod p, then m = m2 + q h < n.
+        let diff = m1.sub_mod(&mp.from_monty(&mp.to_monty(&m2)), &self.p);
+        let h = mp.mul(&mp.to_monty(&diff), &self.qinv);
+        self.q.resize::<LIMBS>().mul(&h).add(&m2)
+    }
+}
+
+impl<const LIMBS: usize, const HALF: usize> fmt::Debug for RsaPrivateKey<LIMBS, HALF> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("RsaPrivateKey")
+            .field("public", &self.public)
+            .finish_non_exhaustive()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rand::TestRng;
+
+    const E: Uint<32> = Uint::from_u64(PUBLIC_EXPONENT);
+
+    #[test]
+    fn test_known_answer_2048() {
+        let p = Uint::<16>::from_be_hex(
+            "d086ad4d162a1f9a8300365e8b146ee1d388fd50a0d1212763e30fc5e8139460808dca091309e9daa730bfe13c726f7bc14a64bc5c006d75af79f392448a07ee6f516e0224b2c636d047c85fdc5af872cb454165bca56ac3bb11bc87f7a28141f9132fde0df60b74a4f1bcde0cf63dd87f7f87fb893bca23f26651ad03cf6109",
+        );
+        let q = Uint::<16>::from_be_hex(
+            "f20d8829b69a3e5eaaf26f61becad298cecdbfbcea338283c6c4c3edf666ecae1c23c86baf264ec8fd47badb419d0079ade406454899e5ae3b9fdf939cdf5e976ece151d06a7be2ccadd8d77d5249c12d5c60f25cb8cf7aa09166898af728cfd36e24d24f7149034040d904d95b7584c410193e6fc873e81a39afae8e7a287af",
+        );
+        let d = Uint::<32>::from_be_hex(
+            "ea6521c7147bdce8c485eb0fbe1d13ccd1f277d5dc4bc03964e6c51b2269b890378c0f4bb55b412bc05f1df81cf3f78a1e1b71ee8dde80ca91e44169a881aeb15cf265b7fad6344ce03a7b00b519c0d5893199ed579af8c311da624758f28e549381ce8c22b88a89c784bfdf449d39dc1cf9bac066b0ce26fc932373899b9135a40582e557025a6b86345d02d9a8e78409967e24ab0dc6774c507d3f5ab5f8f03088aae533815f784615c91efee0454b8e2e3659b6984d6b9da57c73b9d1d4cf4ea61368a4001e759845cbcbdb05b1e556584356e76cf9c08c0bd2469a8c2c8869f0a3c8fda518a3869230b09f8f757bfb382aa491b0e091c6a868ee553bee9",
+        );
+        let m = Uint::<32>::from_be_hex(
+            "6e61696c207273612074657374206d6573736167656e61696c207273612074657374206d6573736167656e61696c207273612074657374206d657373616765",
+        );
+        let c = Uint::<32>::from_be_hex(
+            "b111b8a989f35ddd529d1aaf857cd5284fbd438b7e702f47f1d7034687cbabbec6489ae8191cb301ce4ebd6122d43f0946e967f4c1891f2b731dfa987145a848599090ac134705e34631c9daeee8dec9ea00fed007b30c5c8a034b8f470d2daec553e81c850e07130f0d2f0eebbb2a355eecdcad7db8ea8af81318b6b5ae0071dddd71c26ad593a1a40ec52675e2529f6b4bb8ecd83a2ea59c8ef8b2100c50f12e75c78dad981ad699f024e9b973d2d5807a0922634b927ff3805f4e92fc08b6d8f03f689a9c089c2369f4e56234f333bd3044b72c1348cb395203e673f4a6d68757295117c8c384e4ddaa47f8e0373e8e812245251d2d33dd09f94df684636d",
+        );
+        let s = Uint::<32>::from_be_hex(
+            "a96e3520a216f2cb3c4658d473df44eaf6a2c119f070b34c92c7fdc58b732208133b4bdf4e0a616c804b31aac5144ecb678e3db2c0d868588a3eab11c1ffd0c2781cd0b20ef387718610415b60a3968c9c64451cadd51573308a05ed7314f003c467b771de276e12b49e63335598a27c4a0d74c742377d698555e5e61c9d82b92ca4d306578cfc78b4962b306a8736373ecc0658577ea196e10ed7483490f2942c36f9b7b3309ea6e28134bd05b3b2139fcab1b27878c9e3f3d89122a0e66c91465015c1754122a81f4664790efec1b081879b6b27a050e750ee9d8ce492ad79ecf58ff0908ba897790ce1dc6f6d0d1e6b97d3b83c053e3372f433b359a874fa",
+        );
+
+        let key = RsaPrivateKey2048::from_primes(&p, &q, &E).unwrap();
+        let public = key.public_key();
+        assert_eq!(key.d(), &d);
+        assert!(key.validate());
+
+        let mut rng = TestRng(2048);
+        assert_eq!(public.encrypt_raw(&m), Some(c));
+        assert_eq!(key.decrypt_raw(&c, &mut rng), Some(m));
+        assert_eq!(key.sign_raw(&m, &mut rng), Some(s));
+        assert_eq!(public.verify_raw(&s), Some(m));
+
+        // Representatives must be below n
+        assert_eq!(public.encrypt_raw(public.n()), None);
+        assert_eq!(key.decrypt_raw(&Uint::MAX, &mut rng), None);
+        assert_eq!(key.decrypt_raw(&Uint::ZERO, &mut rng), Some(Uint::ZERO));
+    }
+
+    #[test]
+    fn test_generate() {
+        // A 1024-bit modulus keeps the prime search quick in unoptimized builds.
+        let mut rng = TestRng(42);
+        let key = RsaPrivateKey::<16, 8>::generate(&mut rng);
+        let (p, q) = key.primes();
+        assert_eq!(key.public_key().n().bits(), 1024);
+        assert_eq!((p.bits(), q.bits()), (512, 512));
+        assert_eq!(key.public_key().e(), &Uint::from_u64(PUBLIC_EXPONENT));
+        assert!(key.validate());
+
+        let m = Uint::<16>::from_be_hex("0123456789abcdef0123456789abcdef");
+        let c = key.public_key().encrypt_raw(&m).unwrap();
+        assert_eq!(key.decrypt_raw(&c, &mut rng), Some(m));
+        assert!(!format!("{:?}", key).contains(&format!("{:x}", key.d())));
+    }
+
+    #[test]
+    fn test_invalid_keys() {
+        let p = Uint::<8>::from_be_hex(
+            "f78003b64dc064fea710e9ad255b3564738f0940d42b6e1692c4fe4794d68159",
+        );
+        let q = Uint::<8>::from_be_hex(
+            "ff92c81307e2f6e2abb2e6ee026d330b9a8b534ffe8640e1317c23278b429a65",
+        );
+        let e = Uint::<16>::from_u64(PUBLIC_EXPONENT);
+        assert!(RsaPrivateKey::<16, 8>::from_primes(&p, &p, &e).is_none());
+        assert!(RsaPrivateKey::<16, 8>::from_primes(&p, &q.add(&Uint::<1>::ONE), &e).is_none());
+        // A composite factor is caught by validation if from_primes accepts it at all.
+        let composite = p.mul(&q);
+        let key = RsaPrivateKey::<16, 8>::from_primes(&composite, &q, &e);
+        assert!(key.is_none_or(|key| !key.validate()));
+
+        let mut key = RsaPrivateKey::<16, 8>::from_primes(&p, &q, &e).unwrap();
+        assert!(key.validate());
+        key.dp = key.dp.add(&Uint::<1>::ONE);
+        assert!(!key.validate());
+
+        assert!(RsaPublicKey::new(Uint::<4>::from_u64(15), Uint::from_u64(4)).is_none());
+        assert!(RsaPublicKey::new(Uint::<4>::from_u64(15), Uint::from_u64(17)).is_none());
+        assert!(RsaPublicKey::new(Uint::<4>::from_u64(16), Uint::from_u64(3)).is_none());
+    }
+
+    #[test]
+    fn test_fault_is_detected() {
+        let mut rng = TestRng(9);
+        let mut key = RsaPrivateKey::<16, 8>::generate(&mut rng);
+        let m = Uint::from_u64(12345);
+        key.dq = key.dq.add(&Uint::<1>::from_u64(2));
+        assert_eq!(key.sign_raw(&m, &mut rng), None);
+    }
+}
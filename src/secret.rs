@@ -0,0 +1,197 @@
+//! Secret integers that are wiped from memory when dropped.
+//!
+//! [`SecretUint`] wraps a [`Uint`] holding key material. It has no `Display`, its `Debug` output
+//! is redacted, and its limbs are overwritten with zeros on drop. Arithmetic goes through the
+//! constant-time operations only; the value itself is reached through
+//! [`SecretUint::expose_secret`], which makes every use of it easy to find.
+//!
+//! Zeroization covers the wrapper's own storage. Copies made by exposing the value, and
+//! temporaries the compiler spills while computing, are not tracked.
+
+use core::{
+    fmt,
+    sync::atomic::{compiler_fence, Ordering},
+};
+
+use crate::{ct::Choice, rand::Rng, uint::Uint};
+
+/// A [`Uint`] holding secret data, zeroized on drop.
+#[derive(Clone)]
+pub struct SecretUint<const LIMBS: usize> {
+    inner: Uint<LIMBS>,
+}
+
+impl<const LIMBS: usize> SecretUint<LIMBS> {
+    /// Wraps `value`.
+    pub const fn new(value: Uint<LIMBS>) -> Self {
+        Self { inner: value }
+    }
+
+    /// Returns a uniformly random secret in `[0, modulus)`. Panics if `modulus` is zero.
+    pub fn random_mod<R: Rng + ?Sized>(rng: &mut R, modulus: &Uint<LIMBS>) -> Self {
+        Self::new(Uint::random_mod(rng, modulus))
+    }
+
+    /// Returns the wrapped value.
+    pub const fn expose_secret(&self) -> &Uint<LIMBS> {
+        &self.inner
+    }
+
+    /// Overwrites the value with zero in a way the compiler cannot optimize out.
+    pub fn zeroize(&mut self) {
+        for limb in self.inner.limbs.iter_mut() {
+            // SAFETY: `limb` comes from a mutable reference, so it is valid and aligned.
+            unsafe { core::ptr::write_volatile(limb, 0) };
+        }
+        compiler_fence(Ordering::SeqCst);
+    }
+
+    /// Returns `self + other`, wrapping on overflow.
+    pub fn add(&self, other: &Self) -> Self {
+        Self::new(self.inner.add(&other.inner))
+    }
+
+    /// Returns `self - other`, wrapping on underflow.
+    pub fn sub(&self, other: &Self) -> Self {
+        Self::new(self.inner.sub(&other.inner))
+    }
+
+    /// Returns `self * other`, truncated to `LIMBS` limbs.
+    pub fn mul(&self, other: &Self) -> Self {
+        Self::new(self.inner.mul(&other.inner))
+    }
+
+    /// Returns `self mod modulus`, in constant time. Panics if `modulus` is zero.
+    pub fn rem(&self, modulus: &Uint<LIMBS>) -> Self {
+        Self::new(self.inner.ct_rem(modulus))
+    }
+
+    /// Computes `self + other mod modulus` for operands already reduced modulo `modulus`.
+    pub const fn add_mod(&self, other: &Self, modulus: &Uint<LIMBS>) -> Self {
+        Self::new(self.inner.add_mod(&other.inner, modulus))
+    }
+
+    /// Computes `self - other mod modulus` for operands already reduced modulo `modulus`.
+    pub const fn sub_mod(&self, other: &Self, modulus: &Uint<LIMBS>) -> Self {
+        Self::new(self.inner.sub_mod(&other.inner, modulus))
+    }
+
+    /// Computes `-self mod modulus` for `self` already reduced modulo `modulus`.
+    pub const fn neg_mod(&self, modulus: &Uint<LIMBS>) -> Self {
+        Self::new(self.inner.neg_mod(modulus))
+    }
+
+    /// Returns whether the value is zero, in constant time.
+    pub const fn ct_is_zero(&self) -> Choice {
+        self.inner.ct_is_zero()
+    }
+
+    /// Returns whether `self == other`, in constant time.
+    pub const fn ct_eq(&self, other: &Self) -> Choice {
+        self.inner.ct_eq(&other.inner)
+    }
+
+    /// Returns whether `self != other`, in constant time.
+    pub const fn ct_ne(&self, other: &Self) -> Choice {
+        self.inner.ct_ne(&other.inner)
+    }
+
+    /// Returns whether `self < other`, in constant time.
+    pub const fn ct_lt(&self, other: &Self) -> Choice {
+        self.inner.ct_lt(&other.inner)
+    }
+
+    /// Returns whether `self > other`, in constant time.
+    pub const fn ct_gt(&self, other: &Self) -> Choice {
+        self.inner.ct_gt(&other.inner)
+    }
+
+    /// Returns `a` when `choice` is false and `b` when it is true, in constant time.
+    pub const fn conditional_select(a: &Self, b: &Self, choice: Choice) -> Self {
+        Self::new(Uint::conditional_select(&a.inner, &b.inner, choice))
+    }
+
+    /// Swaps `a` and `b` when `choice` is true, in constant time.
+    pub const fn conditional_swap(a: &mut Self, b: &mut Self, choice: Choice) {
+        Uint::conditional_swap(&mut a.inner, &mut b.inner, choice)
+    }
+}
+
+impl<const LIMBS: usize> From<Uint<LIMBS>> for SecretUint<LIMBS> {
+    fn from(value: Uint<LIMBS>) -> Self {
+        Self::new(value)
+    }
+}
+
+impl<const LIMBS: usize> Drop for SecretUint<LIMBS> {
+    fn drop(&mut self) {
+        self.zeroize();
+    }
+}
+
+impl<const LIMBS: usize> fmt::Debug for SecretUint<LIMBS> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "SecretUint<{LIMBS}>(REDACTED)")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rand::TestRng;
+
+    #[test]
+    fn test_debug_is_redacted() {
+        let secret = SecretUint::new(Uint::<2>::from_u64(0xdead_beef));
+        let debug = format!("{secret:?}");
+        assert_eq!(debug, "SecretUint<2>(REDACTED)");
+        assert!(!debug.contains("deadbeef") && !debug.contains("3735928559"));
+    }
+
+    #[test]
+    fn test_zeroize() {
+        let mut secret = SecretUint::new(Uint::<4>::MAX);
+        secret.zeroize();
+        assert_eq!(*secret.expose_secret(), Uint::ZERO);
+    }
+
+    #[test]
+    fn test_arithmetic_matches_uint() {
+        let mut rng = TestRng(43);
+        let modulus = Uint::<3>::from_be_hex("fffffffffffffffffffffffffffffffeffffffffffffffff");
+        let (a, b) = (
+            SecretUint::random_mod(&mut rng, &modulus),
+            SecretUint::random_mod(&mut rng, &modulus),
+        );
+        let (x, y) = (*a.expose_secret(), *b.expose_secret());
+
+        assert_eq!(*a.add(&b).expose_secret(), x.add(&y));
+        assert_eq!(*a.sub(&b).expose_secret(), x.sub(&y));
+        assert_eq!(*a.mul(&b).expose_secret(), x.mul(&y));
+        assert_eq!(
+            *a.mul(&b).rem(&modulus).expose_secret(),
+            x.mul(&y).rem(&modulus)
+        );
+        assert_eq!(
+            *a.add_mod(&b, &modulus).expose_secret(),
+            x.add_mod(&y, &modulus)
+        );
+        assert_eq!(
+            *a.sub_mod(&b, &modulus).expose_secret(),
+            x.sub_mod(&y, &modulus)
+        );
+        assert_eq!(*a.neg_mod(&modulus).expose_secret(), x.neg_mod(&modulus));
+
+        assert!(bool::from(a.ct_eq(&a.clone())));
+        assert_eq!(bool::from(a.ct_ne(&b)), x != y);
+        assert_eq!(bool::from(a.ct_lt(&b)), x < y);
+        assert_eq!(bool::from(a.ct_gt(&b)), x > y);
+        assert!(bool::from(a.sub(&a).ct_is_zero()));
+
+        let chosen = SecretUint::conditional_select(&a, &b, Choice::TRUE);
+        assert_eq!(*chosen.expose_secret(), y);
+        let (mut c, mut d) = (a.clone(), b.clone());
+        SecretUint::conditional_swap(&mut c, &mut d, Choice::TRUE);
+        assert_eq!((*c.expose_secret(), *d.expose_secret()), (y, x));
+    }
+}
@@ -0,0 +1,244 @@
+//! [`serde`](https://docs.rs/serde) support for [`Uint`], behind the `serde` feature.
+//!
+//! Human-readable formats such as JSON get a `"0x…"` hex string of the full width, and binary
+//! formats get the `LIMBS * 8` big-endian bytes. Deserializing accepts `0x` hex strings, decimal
+//! strings, integers and byte strings, and fails if the value does not fit in `LIMBS` limbs.
+//!
+//! Fields that should be written as decimal strings instead can use [`decimal`]:
+//!
+//! ```
+//! # use serde::{Deserialize, Serialize};
+//! #[derive(Serialize, Deserialize)]
+//! struct Config {
+//!     #[serde(with = "nail::serde::decimal")]
+//!     supply: nail::U256,
+//! }
+//! ```
+
+use core::fmt;
+
+use serde::{
+    de::{self, SeqAccess, Visitor},
+    Deserialize, Deserializer, Serialize, Serializer,
+};
+
+use crate::uint::Uint;
+
+impl<const LIMBS: usize> Serialize for Uint<LIMBS> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        if serializer.is_human_readable() {
+            serializer.collect_str(&format_args!("{self:#x}"))
+        } else {
+            let mut bytes = vec![0u8; LIMBS * 8];
+            self.write_be_bytes(&mut bytes);
+            serializer.serialize_bytes(&bytes)
+        }
+    }
+}
+
+impl<'de, const LIMBS: usize> Deserialize<'de> for Uint<LIMBS> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        if deserializer.is_human_readable() {
+            deserializer.deserialize_any(UintVisitor)
+        } else {
+            deserializer.deserialize_bytes(UintVisitor)
+        }
+    }
+}
+
+/// Decimal strings for `#[serde(with = "nail::serde::decimal")]` fields.
+///
+/// Deserializing accepts the same inputs as the default [`Deserialize`] implementation.
+pub mod decimal {
+    use serde::{Deserializer, Serializer};
+
+    use super::{Decimal, UintVisitor};
+    use crate::uint::Uint;
+
+    /// Serializes `value` as a decimal string.
+    pub fn serialize<S: Serializer, const LIMBS: usize>(
+        value: &Uint<LIMBS>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(&Decimal(value))
+    }
+
+    /// Deserializes a `Uint` from a hex or decimal string, an integer or big-endian bytes.
+    pub fn deserialize<'de, D: Deserializer<'de>, const LIMBS: usize>(
+        deserializer: D,
+    ) -> Result<Uint<LIMBS>, D::Error> {
+        deserializer.deserialize_any(UintVisitor)
+    }
+}
+
+/// Formats a `Uint` in decimal.
+struct Decimal<'a, const LIMBS: usize>(&'a Uint<LIMBS>);
+
+impl<const LIMBS: usize> fmt::Display for Decimal<'_, LIMBS> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // Peel off 19 digits at a time, the most that fit in a limb, least significant first.
+        const CHUNK: u64 = 10_000_000_000_000_000_000;
+        let mut rest = *self.0;
+        let mut chunks = Vec::new();
+        loop {
+            let mut rem = 0u128;
+            for limb in rest.limbs.iter_mut().rev() {
+                let acc = (rem << 64) | *limb as u128;
+                *limb = (acc / CHUNK as u128) as u64;
+                rem = acc % CHUNK as u128;
+            }
+            chunks.push(rem as u64);
+            if rest.is_zero() {
+                break;
+            }
+        }
+        let mut chunks = chunks.iter().rev();
+        write!(f, "{}", chunks.next().unwrap_or(&0))?;
+        chunks.try_for_each(|chunk| write!(f, "{chunk:019}"))
+    }
+}
+
+struct UintVisitor<const LIMBS: usize>;
+
+impl<'de, const LIMBS: usize> Visitor<'de> for UintVisitor<LIMBS> {
+    type Value = Uint<LIMBS>;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "an unsigned integer of at most {} bits", LIMBS * 64)
+    }
+
+    fn visit_u64<E: de::Error>(self, v: u64) -> Result<Self::Value, E> {
+        self.visit_u128(v as u128)
+    }
+
+    fn visit_u128<E: de::Error>(self, v: u128) -> Result<Self::Value, E> {
+        Uint::from_le_bytes(&v.to_le_bytes()).ok_or_else(|| E::custom("integer too large for Uint"))
+    }
+
+    fn visit_i64<E: de::Error>(self, v: i64) -> Result<Self::Value, E> {
+        self.visit_i128(v as i128)
+    }
+
+    fn visit_i128<E: de::Error>(self, v: i128) -> Result<Self::Value, E> {
+        match u128::try_from(v) {
+            Ok(v) => self.visit_u128(v),
+            Err(_) => Err(E::invalid_value(
+                de::Unexpected::Other("negative integer"),
+                &self,
+            )),
+        }
+    }
+
+    fn visit_str<E: de::Error>(self, v: &str) -> Result<Self::Value, E> {
+        let parsed = match v.strip_prefix("0x").or_else(|| v.strip_prefix("0X")) {
+            Some(hex) => Uint::from_str_radix(hex, 16),
+            None => Uint::from_str_radix(v, 10),
+        };
+        parsed.map_err(E::custom)
+    }
+
+    fn visit_bytes<E: de::Error>(self, v: &[u8]) -> Result<Self::Value, E> {
+        Uint::from_be_bytes(v).ok_or_else(|| E::custom("integer too large for Uint"))
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+        let mut bytes = Vec::with_capacity(seq.size_hint().unwrap_or(LIMBS * 8));
+        while let Some(byte) = seq.next_element::<u8>()? {
+            bytes.push(byte);
+        }
+        self.visit_bytes(&bytes)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde::{Deserialize, Serialize};
+
+    use super::*;
+
+    type U = Uint<2>;
+
+    const X: &str = "0x123456789abcdef0fedcba9876543210";
+    const X_DECIMAL: &str = "24197857203266734881846307747534221840";
+
+    fn from_cbor<T: for<'de> Deserialize<'de>>(bytes: &[u8]) -> Result<T, String> {
+        ciborium::from_reader(bytes).map_err(|e| e.to_string())
+    }
+
+    fn to_cbor<T: Serialize>(value: &T) -> Vec<u8> {
+        let mut out = Vec::new();
+        ciborium::into_writer(value, &mut out).unwrap();
+        out
+    }
+
+    #[test]
+    fn test_json() {
+        let x = U::from_be_hex(&X[2..]);
+        assert_eq!(serde_json::to_string(&x).unwrap(), format!("\"{X}\""));
+        assert_eq!(
+            serde_json::to_string(&U::ONE).unwrap(),
+            "\"0x00000000000000000000000000000001\""
+        );
+
+        let parse = |json: &str| serde_json::from_str::<U>(json);
+        assert_eq!(parse(&format!("\"{X}\"")).unwrap(), x);
+        assert_eq!(parse("\"0XFF\"").unwrap(), U::from_u64(255));
+        assert_eq!(parse(&format!("\"{X_DECIMAL}\"")).unwrap(), x);
+        assert_eq!(parse("42").unwrap(), U::from_u64(42));
+        assert_eq!(
+            parse("18446744073709551615").unwrap(),
+            U::from_u64(u64::MAX)
+        );
+
+        assert!(parse("-1").is_err());
+        assert!(parse("1.5").is_err());
+        assert!(parse("\"0x\"").is_err());
+        assert!(parse("\"12a\"").is_err());
+        assert!(parse("\"0x100000000000000000000000000000000\"").is_err());
+        assert!(serde_json::from_str::<Uint<1>>("\"18446744073709551616\"").is_err());
+    }
+
+    #[test]
+    fn test_binary_is_fixed_width_big_endian() {
+        let x = U::from_be_hex(&X[2..]);
+        let encoded = to_cbor(&x);
+        // CBOR byte string header (major type 2, length 16), then the big-endian bytes.
+        assert_eq!(encoded[0], 0x50);
+        let mut be = [0u8; 16];
+        x.write_be_bytes(&mut be);
+        assert_eq!(&encoded[1..], &be[..]);
+        assert_eq!(from_cbor::<U>(&encoded), Ok(x));
+        assert_eq!(to_cbor(&U::ZERO).len(), 17);
+
+        assert_eq!(from_cbor::<Uint<3>>(&encoded), Ok(x.resize()));
+        assert!(from_cbor::<Uint<1>>(&encoded).is_err());
+    }
+
+    #[test]
+    fn test_decimal() {
+        #[derive(Debug, PartialEq, Serialize, Deserialize)]
+        struct Config {
+            #[serde(with = "crate::serde::decimal")]
+            supply: U,
+        }
+
+        let config = Config {
+            supply: U::from_be_hex(&X[2..]),
+        };
+        let json = serde_json::to_string(&config).unwrap();
+        assert_eq!(json, format!("{{\"supply\":\"{X_DECIMAL}\"}}"));
+        assert_eq!(serde_json::from_str::<Config>(&json).unwrap(), config);
+        assert_eq!(from_cbor::<Config>(&to_cbor(&config)), Ok(config));
+
+        for (value, expected) in [
+            (U::ZERO, "0"),
+            (
+                U::from_u64(10_000_000_000_000_000_000),
+                "10000000000000000000",
+            ),
+            (U::MAX, "340282366920938463463374607431768211455"),
+        ] {
+            assert_eq!(Decimal(&value).to_string(), expected);
+        }
+    }
+}
@@ -3,12 +3,46 @@
 //! This module provides a generic `Uint<LIMBS>` type for arbitrary precision
 //! unsigned integer arithmetic with compile-time determined size.
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+use core::{cmp::Ordering, fmt};
+
+use crate::limb::{
+    adc, add_assign, cond_add_assign, div_rem_into, div_rem_vartime_into, is_nonzero, lt, mac,
+    mul_into, sbb, select, shl_into, shr_into, sub_assign,
+};
+
+/// An unsigned integer of `LIMBS` 64-bit limbs, least significant first.
+///
+/// The layout is exactly that of `[u64; LIMBS]`. With the `zerocopy` feature, `Uint` implements
+/// the `zerocopy` traits, so slices of limbs in native byte order (for example a memory-mapped
+/// file of little-endian values on a little-endian machine) can be viewed as `Uint`s in place.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(
+    feature = "zerocopy",
+    derive(
+        zerocopy::FromBytes,
+        zerocopy::IntoBytes,
+        zerocopy::Immutable,
+        zerocopy::KnownLayout
+    )
+)]
+#[repr(transparent)]
 pub struct Uint<const LIMBS: usize> {
     pub limbs: [u64; LIMBS],
 }
 
 impl<const LIMBS: usize> Uint<LIMBS> {
+    /// The value zero.
+    pub const ZERO: Self = Self { limbs: [0; LIMBS] };
+
+    /// The value one.
+    pub const ONE: Self = Self::from_u64(1);
+
+    /// The largest representable value, `2^(LIMBS * 64) - 1`.
+    pub const MAX: Self = Self { limbs: [u64::MAX; LIMBS] };
+
+    /// Total number of bits in the integer.
+    pub const BITS: usize = LIMBS * 64;
+
     /// Creates a new `Uint` with all limbs set to zero.
     pub fn zero() -> Self {
         Self { limbs: [0; LIMBS] }
@@ -17,7 +51,7 @@ impl<const LIMBS: usize> Uint<LIMBS> {
     /// Creates a new `Uint` from a single `u64` value.
     /// 
     /// The value is placed in the least significant limb, with all other limbs set to zero.
-    pub fn from_u64(x: u64) -> Self {
+    pub const fn from_u64(x: u64) -> Self {
         let mut limbs = [0; LIMBS];
         limbs[0] = x;
         Self { limbs }
@@ -33,19 +67,8 @@ impl<const LIMBS: usize> Uint<LIMBS> {
         // Ensure left operand has at least as many limbs as right operand
         assert!(LIMBS >= OTHER_LIMBS, "Left operand must have >= limbs than right operand");
         
-        let mut out = [0u64; LIMBS];
-        let mut carry = false;
-
-        for i in 0..LIMBS {
-            let other_limb = if i < OTHER_LIMBS { other.limbs[i] } else { 0 };
-            
-            let (s1, c1) = self.limbs[i].overflowing_add(other_limb);
-            let (s2, c2) = s1.overflowing_add(carry as u64);
-
-            out[i] = s2;
-            carry = c1 || c2;
-        }
-
+        let mut out = self.limbs;
+        add_assign(&mut out, &other.limbs);
         Self { limbs: out }
     }
 
@@ -60,36 +83,7 @@ impl<const LIMBS: usize> Uint<LIMBS> {
         // Ensure left operand has at least as many limbs as right operand
         assert!(LIMBS >= OTHER_LIMBS, "Left operand must have >= limbs than right operand");
         let mut out = [0u64; LIMBS];
-
-        for i in 0..LIMBS {
-            for j in 0..OTHER_LIMBS {
-                if i + j >= LIMBS { break; }
-                
-                // Calculate full 128-bit product manually
-                let a = self.limbs[i] as u128;
-                let b = other.limbs[j] as u128;
-                let product = a * b;
-                let low = product as u64;
-                let high = (product >> 64) as u64;
-                
-                // Add low part to current position
-                let (sum, overflow) = out[i + j].overflowing_add(low);
-                out[i + j] = sum;
-                
-                // Propagate overflow to next position
-                if overflow && i + j + 1 < LIMBS {
-                    let (sum2, _) = out[i + j + 1].overflowing_add(1);
-                    out[i + j + 1] = sum2;
-                }
-                
-                // Add high part to next position if it fits
-                if i + j + 1 < LIMBS {
-                    let (sum3, _) = out[i + j + 1].overflowing_add(high);
-                    out[i + j + 1] = sum3;
-                }
-            }
-        }
-
+        mul_into(&mut out, &self.limbs, &other.limbs);
         Self { limbs: out }
     }
 
@@ -98,14 +92,10 @@ impl<const LIMBS: usize> Uint<LIMBS> {
         assert!(bitsize <= LIMBS * 64);
 
         let mut out = [0u64; LIMBS];
-        let mut carry = false;
-
-        for i in 0..LIMBS {
-            let (s1, c1) = self.limbs[i].overflowing_add(other.limbs[i]);
-            let (s2, c2) = s1.overflowing_add(carry as u64);
+        let mut carry = 0;
 
-            out[i] = s2;
-            carry = c1 || c2;
+        for (i, out_limb) in out.iter_mut().enumerate() {
+            (*out_limb, carry) = adc(self.limbs[i], other.limbs[i], carry);
         }
 
         // Apply bit mask to the last limb
@@ -117,26 +107,24 @@ impl<const LIMBS: usize> Uint<LIMBS> {
         }
         
         // Zero out higher limbs
-        let last_limb = (bitsize + 63) / 64;
-        for i in last_limb..LIMBS {
-            out[i] = 0;
-        }
+        let last_limb = bitsize.div_ceil(64);
+        out[last_limb..].fill(0);
 
         Self { limbs: out }
     }
      pub fn mulmod_bits(&self, other: &Self, bitsize: usize) -> Self {
         assert!(bitsize <= LIMBS * 64);
 
-        let nlimbs = (bitsize + 63) / 64;
+        let nlimbs = bitsize.div_ceil(64);
         let mut out = [0u64; LIMBS];
 
-        for i in 0..nlimbs {
+        for (i, out_limb) in out.iter_mut().enumerate().take(nlimbs) {
             let mut carry: u128 = 0;
             for j in 0..=i {
                 let a = self.limbs[j] as u128;
                 let b = other.limbs[i - j] as u128;
-                let sum = out[i] as u128 + a * b + carry;
-                out[i] = sum as u64;
+                let sum = *out_limb as u128 + a * b + carry;
+                *out_limb = sum as u64;
                 carry = sum >> 64;
             }
             // Even if carry remains, we don't need to propagate to out[i+1] because of mod 2^(nlimbs*64)
@@ -149,12 +137,628 @@ impl<const LIMBS: usize> Uint<LIMBS> {
             out[nlimbs - 1] &= mask;
         }
         // Zero out higher limbs
-        for i in nlimbs..LIMBS {
-            out[i] = 0;
+        out[nlimbs..].fill(0);
+
+        Self { limbs: out }
+    }
+
+    /// Parses a big-endian hexadecimal string (without a `0x` prefix).
+    ///
+    /// Intended for constants: panics on an invalid digit or if the value does not fit in
+    /// `LIMBS` limbs, which turns into a compile error when evaluated in a `const` item.
+    pub const fn from_be_hex(hex: &str) -> Self {
+        let bytes = hex.as_bytes();
+        let mut limbs = [0u64; LIMBS];
+        let mut i = 0;
+        while i < bytes.len() {
+            let c = bytes[bytes.len() - 1 - i];
+            let nibble = match c {
+                b'0'..=b'9' => c - b'0',
+                b'a'..=b'f' => c - b'a' + 10,
+                b'A'..=b'F' => c - b'A' + 10,
+                _ => panic!("invalid hex digit"),
+            } as u64;
+            if i / 16 < LIMBS {
+                limbs[i / 16] |= nibble << ((i % 16) * 4);
+            } else {
+                assert!(nibble == 0, "hex value does not fit in Uint");
+            }
+            i += 1;
         }
+        Self { limbs }
+    }
 
+    /// Parses an integer literal: decimal, or hexadecimal or binary with a `0x` or `0b` prefix,
+    /// with `_` separators allowed anywhere after the prefix. This is what [`uint!`](crate::uint!)
+    /// expands to.
+    ///
+    /// Like [`Uint::from_be_hex`], it panics on an invalid digit or if the value does not fit in
+    /// `LIMBS` limbs, so a bad literal in a `const` item is a compile error.
+    pub const fn from_literal(literal: &str) -> Self {
+        let bytes = literal.as_bytes();
+        let (radix, mut i) = match bytes {
+            [b'0', b'x' | b'X', ..] => (16, 2),
+            [b'0', b'b' | b'B', ..] => (2, 2),
+            _ => (10, 0),
+        };
+        let mut limbs = [0u64; LIMBS];
+        let mut digits = 0;
+        while i < bytes.len() {
+            let c = bytes[i];
+            i += 1;
+            if c == b'_' {
+                continue;
+            }
+            let digit = match c {
+                b'0'..=b'9' => c - b'0',
+                b'a'..=b'f' => c - b'a' + 10,
+                b'A'..=b'F' => c - b'A' + 10,
+                _ => radix,
+            } as u64;
+            assert!(digit < radix as u64, "invalid digit in integer literal");
+            // limbs = limbs * radix + digit
+            let mut carry = digit;
+            let mut j = 0;
+            while j < LIMBS {
+                let (lo, hi) = mac(0, limbs[j], radix as u64, carry);
+                limbs[j] = lo;
+                carry = hi;
+                j += 1;
+            }
+            assert!(carry == 0, "integer literal does not fit in Uint");
+            digits += 1;
+        }
+        assert!(digits > 0, "integer literal has no digits");
+        Self { limbs }
+    }
+
+    /// Creates a `Uint` from big-endian bytes.
+    ///
+    /// Returns `None` if the value does not fit in `LIMBS` limbs.
+    pub fn from_be_bytes(bytes: &[u8]) -> Option<Self> {
+        let mut limbs = [0u64; LIMBS];
+        for (i, &byte) in bytes.iter().rev().enumerate() {
+            if i / 8 < LIMBS {
+                limbs[i / 8] |= (byte as u64) << ((i % 8) * 8);
+            } else if byte != 0 {
+                return None;
+            }
+        }
+        Some(Self { limbs })
+    }
+
+    /// Creates a `Uint` from little-endian bytes.
+    ///
+    /// Returns `None` if the value does not fit in `LIMBS` limbs.
+    pub fn from_le_bytes(bytes: &[u8]) -> Option<Self> {
+        let mut limbs = [0u64; LIMBS];
+        for (i, &byte) in bytes.iter().enumerate() {
+            if i / 8 < LIMBS {
+                limbs[i / 8] |= (byte as u64) << ((i % 8) * 8);
+            } else if byte != 0 {
+                return None;
+            }
+        }
+        Some(Self { limbs })
+    }
+
+    /// Parses a number in base `radix` (2 to 36), like `u64::from_str_radix`: an optional `+`
+    /// followed by at least one digit, with letters in either case.
+    ///
+    /// Panics if `radix` is out of range.
+    pub fn from_str_radix(s: &str, radix: u32) -> Result<Self, ParseUintError> {
+        assert!((2..=36).contains(&radix), "radix must be between 2 and 36");
+        let digits = s.strip_prefix('+').unwrap_or(s);
+        if digits.is_empty() {
+            return Err(ParseUintError::Empty);
+        }
+        let radix_uint = Self::from_u64(radix as u64);
+        let mut acc = Self::ZERO;
+        for c in digits.chars() {
+            let digit = c.to_digit(radix).ok_or(ParseUintError::InvalidDigit)?;
+            let (shifted, high) = acc.widening_mul(&radix_uint);
+            let (sum, carry) = shifted.overflowing_add(&Self::from_u64(digit as u64));
+            if !high.is_zero() || carry {
+                return Err(ParseUintError::Overflow);
+            }
+            acc = sum;
+        }
+        Ok(acc)
+    }
+
+    /// Writes the value as big-endian bytes into `out`, which must be `LIMBS * 8` bytes long.
+    pub fn write_be_bytes(&self, out: &mut [u8]) {
+        assert_eq!(out.len(), LIMBS * 8, "output must be LIMBS * 8 bytes");
+        for (chunk, limb) in out.chunks_exact_mut(8).zip(self.limbs.iter().rev()) {
+            chunk.copy_from_slice(&limb.to_be_bytes());
+        }
+    }
+
+    /// Writes the value as little-endian bytes into `out`, which must be `LIMBS * 8` bytes long.
+    pub fn write_le_bytes(&self, out: &mut [u8]) {
+        assert_eq!(out.len(), LIMBS * 8, "output must be LIMBS * 8 bytes");
+        for (chunk, limb) in out.chunks_exact_mut(8).zip(self.limbs.iter()) {
+            chunk.copy_from_slice(&limb.to_le_bytes());
+        }
+    }
+
+    /// Returns the value's little-endian bytes without copying.
+    #[cfg(all(feature = "zerocopy", target_endian = "little"))]
+    pub fn as_le_bytes(&self) -> &[u8] {
+        zerocopy::IntoBytes::as_bytes(self)
+    }
+
+    /// Returns `true` if the value is zero.
+    pub const fn is_zero(&self) -> bool {
+        let mut acc = 0;
+        let mut i = 0;
+        while i < LIMBS {
+            acc |= self.limbs[i];
+            i += 1;
+        }
+        acc == 0
+    }
+
+    /// Returns `true` if the least significant bit is set.
+    pub const fn is_odd(&self) -> bool {
+        self.limbs[0] & 1 == 1
+    }
+
+    /// Returns the bit at position `index` (0 is the least significant bit).
+    ///
+    /// Indices at or beyond `LIMBS * 64` read as zero.
+    pub const fn bit(&self, index: usize) -> bool {
+        index < Self::BITS && (self.limbs[index / 64] >> (index % 64)) & 1 == 1
+    }
+
+    /// Returns the number of significant bits, i.e. the position of the highest set bit plus one.
+    pub const fn bits(&self) -> usize {
+        // Every limb is visited; each non-zero one overrides the count from the limbs below it.
+        let mut bits = 0;
+        let mut i = 0;
+        while i < LIMBS {
+            let limb_bits = (i * 64 + 64 - self.limbs[i].leading_zeros() as usize) as u64;
+            bits = select(bits, limb_bits, is_nonzero(self.limbs[i]));
+            i += 1;
+        }
+        bits as usize
+    }
+
+    /// Returns the number of trailing zero bits (`LIMBS * 64` for zero).
+    pub const fn trailing_zeros(&self) -> usize {
+        // Every limb is visited; each non-zero one overrides the count from the limbs above it.
+        let mut zeros = Self::BITS as u64;
+        let mut i = LIMBS;
+        while i > 0 {
+            i -= 1;
+            let limb_zeros = (i * 64 + self.limbs[i].trailing_zeros() as usize) as u64;
+            zeros = select(zeros, limb_zeros, is_nonzero(self.limbs[i]));
+        }
+        zeros as usize
+    }
+
+    /// Subtraction where left operand has >= limbs than right operand
+    /// Returns result with same limb count as left operand (self), wrapping on underflow
+    pub fn sub<const OTHER_LIMBS: usize>(&self, other: &Uint<OTHER_LIMBS>) -> Self
+    where
+        [(); LIMBS]:,
+        [(); OTHER_LIMBS]:,
+    {
+        assert!(LIMBS >= OTHER_LIMBS, "Left operand must have >= limbs than right operand");
+
+        let mut out = self.limbs;
+        sub_assign(&mut out, &other.limbs);
         Self { limbs: out }
     }
+
+    /// Computes `self + other`, returning the wrapped sum and whether the addition overflowed.
+    pub const fn overflowing_add(&self, other: &Self) -> (Self, bool) {
+        let mut limbs = self.limbs;
+        let carry = add_assign(&mut limbs, &other.limbs);
+        (Self { limbs }, carry != 0)
+    }
+
+    /// Computes `self - other`, returning the wrapped difference and whether the subtraction
+    /// underflowed.
+    pub const fn overflowing_sub(&self, other: &Self) -> (Self, bool) {
+        let mut limbs = self.limbs;
+        let borrow = sub_assign(&mut limbs, &other.limbs);
+        (Self { limbs }, borrow != 0)
+    }
+
+    /// Computes the full `2 * LIMBS`-limb product, returned as `(low, high)` halves.
+    pub const fn widening_mul(&self, other: &Self) -> (Self, Self) {
+        let mut lo = [0u64; LIMBS];
+        let mut hi = [0u64; LIMBS];
+
+        let mut i = 0;
+        while i < LIMBS {
+            let mut carry = 0;
+            let mut j = 0;
+            while j < LIMBS {
+                let k = i + j;
+                if k < LIMBS {
+                    (lo[k], carry) = mac(lo[k], self.limbs[i], other.limbs[j], carry);
+                } else {
+                    (hi[k - LIMBS], carry) = mac(hi[k - LIMBS], self.limbs[i], other.limbs[j], carry);
+                }
+                j += 1;
+            }
+            // Position i + LIMBS is untouched so far in this row, so the carry lands there cleanly.
+            hi[i] = carry;
+            i += 1;
+        }
+
+        (Self { limbs: lo }, Self { limbs: hi })
+    }
+
+    /// Shifts left by `shift` bits, discarding bits shifted out. Shifts of `LIMBS * 64` or more
+    /// produce zero.
+    pub const fn shl(&self, shift: usize) -> Self {
+        let mut limbs = [0u64; LIMBS];
+        shl_into(&mut limbs, &self.limbs, shift);
+        Self { limbs }
+    }
+
+    /// Shifts right by `shift` bits. Shifts of `LIMBS * 64` or more produce zero.
+    pub const fn shr(&self, shift: usize) -> Self {
+        let mut limbs = [0u64; LIMBS];
+        shr_into(&mut limbs, &self.limbs, shift);
+        Self { limbs }
+    }
+
+    /// Computes `self + other mod modulus` for operands already reduced modulo `modulus`.
+    pub const fn add_mod(&self, other: &Self, modulus: &Self) -> Self {
+        let mut limbs = self.limbs;
+        let carry = add_assign(&mut limbs, &other.limbs);
+        let borrow = sub_assign(&mut limbs, &modulus.limbs);
+        // Undo the subtraction if the sum was below the modulus and did not overflow
+        let (_, restore) = sbb(carry, 0, borrow);
+        cond_add_assign(&mut limbs, &modulus.limbs, restore);
+        Self { limbs }
+    }
+
+    /// Computes `self - other mod modulus` for operands already reduced modulo `modulus`.
+    pub const fn sub_mod(&self, other: &Self, modulus: &Self) -> Self {
+        let mut limbs = self.limbs;
+        let borrow = sub_assign(&mut limbs, &other.limbs);
+        cond_add_assign(&mut limbs, &modulus.limbs, borrow);
+        Self { limbs }
+    }
+
+    /// Computes `-self mod modulus` for `self` already reduced modulo `modulus`.
+    pub const fn neg_mod(&self, modulus: &Self) -> Self {
+        Self::ZERO.sub_mod(self, modulus)
+    }
+
+    /// Returns `a` when `choice` is 0 and `b` when `choice` is 1, without branching.
+    pub(crate) const fn select(a: &Self, b: &Self, choice: u64) -> Self {
+        let mut limbs = [0u64; LIMBS];
+        let mut i = 0;
+        while i < LIMBS {
+            limbs[i] = select(a.limbs[i], b.limbs[i], choice);
+            i += 1;
+        }
+        Self { limbs }
+    }
+}
+
+impl<const LIMBS: usize> Uint<LIMBS> {
+    /// Converts to a different width, zero-extending or truncating the high limbs.
+    pub const fn resize<const OTHER_LIMBS: usize>(&self) -> Uint<OTHER_LIMBS> {
+        let mut limbs = [0u64; OTHER_LIMBS];
+        let mut i = 0;
+        while i < LIMBS && i < OTHER_LIMBS {
+            limbs[i] = self.limbs[i];
+            i += 1;
+        }
+        Uint { limbs }
+    }
+
+    /// Computes `(self / divisor, self % divisor)` by binary long division.
+    ///
+    /// Variable-time: only as many steps run as the quotient has bits, and each skips its
+    /// subtraction when the divisor does not fit. Use [`Uint::ct_div_rem`] when either operand is
+    /// secret. Panics if `divisor` is zero.
+    pub fn div_rem(&self, divisor: &Self) -> (Self, Self) {
+        assert!(!divisor.is_zero(), "division by zero");
+        let mut quotient = Self::ZERO;
+        let mut rem = Self::ZERO;
+        div_rem_vartime_into(&mut quotient.limbs, &mut rem.limbs, &self.limbs, &divisor.limbs);
+        (quotient, rem)
+    }
+
+    /// Computes `self % modulus`, in variable time. Panics if `modulus` is zero.
+    pub fn rem(&self, modulus: &Self) -> Self {
+        self.div_rem(modulus).1
+    }
+
+    /// Computes `(self / divisor, self % divisor)` in constant time.
+    ///
+    /// Runs exactly `LIMBS * 64` steps of binary long division, one per bit of `self`. Each step
+    /// shifts the remainder left by one bit, subtracts the divisor and adds it back under a mask,
+    /// so the cost is about `3 * 64 * LIMBS^2` limb operations whatever the values are, and the
+    /// memory access pattern is fixed. Panics if `divisor` is zero, which is the only
+    /// value-dependent branch.
+    pub fn ct_div_rem(&self, divisor: &Self) -> (Self, Self) {
+        assert!(!divisor.is_zero(), "division by zero");
+        let mut quotient = Self::ZERO;
+        let mut rem = Self::ZERO;
+        div_rem_into(&mut quotient.limbs, &mut rem.limbs, &self.limbs, &divisor.limbs);
+        (quotient, rem)
+    }
+
+    /// Computes `self % modulus` in constant time; see [`Uint::ct_div_rem`] for the cost. Panics
+    /// if `modulus` is zero.
+    pub fn ct_rem(&self, modulus: &Self) -> Self {
+        self.ct_div_rem(modulus).1
+    }
+
+    /// Returns the greatest common divisor, with `gcd(0, 0) = 0`.
+    ///
+    /// Uses the binary algorithm and is variable-time.
+    pub fn gcd(&self, other: &Self) -> Self {
+        let (mut a, mut b) = (*self, *other);
+        if a.is_zero() {
+            return b;
+        }
+        if b.is_zero() {
+            return a;
+        }
+        let shift = a.trailing_zeros().min(b.trailing_zeros());
+        a = a.shr(a.trailing_zeros());
+        loop {
+            b = b.shr(b.trailing_zeros());
+            if a > b {
+                core::mem::swap(&mut a, &mut b);
+            }
+            b = b.sub(&a);
+            if b.is_zero() {
+                return a.shl(shift);
+            }
+        }
+    }
+
+    /// Returns `self^-1 mod modulus`, or `None` if `self` and `modulus` are not coprime.
+    ///
+    /// Any modulus is accepted, including even ones. Uses the extended Euclidean algorithm and
+    /// is variable-time. Panics if `modulus` is zero.
+    pub fn inv_mod(&self, modulus: &Self) -> Option<Self> {
+        // The Bezout coefficients alternate in sign and never exceed the modulus in magnitude,
+        // so only magnitudes are kept, together with the sign of the older one.
+        let (mut r0, mut r1) = (*modulus, self.rem(modulus));
+        let (mut t0, mut t1) = (Self::ZERO, Self::ONE);
+        let mut t0_negative = true;
+        while !r1.is_zero() {
+            // (r0, r1) <- (r1, r0 mod r1) and (t0, t1) <- (t1, t0 + q t1), updated in place so
+            // that wide integers need few temporaries.
+            let q;
+            (q, r0) = r0.div_rem(&r1);
+            t0 = t0.add(&q.mul(&t1));
+            core::mem::swap(&mut r0, &mut r1);
+            core::mem::swap(&mut t0, &mut t1);
+            t0_negative = !t0_negative;
+        }
+        if r0 != Self::ONE {
+            return None;
+        }
+        if t0_negative && !t0.is_zero() {
+            Some(modulus.sub(&t0))
+        } else {
+            Some(t0)
+        }
+    }
+}
+
+/// The error returned by [`Uint::from_str_radix`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ParseUintError {
+    /// The string has no digits.
+    Empty,
+    /// A character is not a digit in the requested radix.
+    InvalidDigit,
+    /// The value does not fit in `LIMBS` limbs.
+    Overflow,
+}
+
+impl fmt::Display for ParseUintError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::Empty => "cannot parse integer from empty string",
+            Self::InvalidDigit => "invalid digit found in string",
+            Self::Overflow => "number too large to fit in target type",
+        })
+    }
+}
+
+impl core::error::Error for ParseUintError {}
+
+impl<const LIMBS: usize> Default for Uint<LIMBS> {
+    fn default() -> Self {
+        Self::ZERO
+    }
+}
+
+impl<const LIMBS: usize> PartialOrd for Uint<LIMBS> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<const LIMBS: usize> Ord for Uint<LIMBS> {
+    /// Compares every limb regardless of where the operands first differ; only the returned
+    /// `Ordering` depends on the values.
+    fn cmp(&self, other: &Self) -> Ordering {
+        let less = lt(&self.limbs, &other.limbs);
+        let greater = lt(&other.limbs, &self.limbs);
+        (greater as i64 - less as i64).cmp(&0)
+    }
+}
+
+impl<const LIMBS: usize> fmt::LowerHex for Uint<LIMBS> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if f.alternate() {
+            f.write_str("0x")?;
+        }
+        for limb in self.limbs.iter().rev() {
+            write!(f, "{:016x}", limb)?;
+        }
+        Ok(())
+    }
+}
+
+impl<const LIMBS: usize> fmt::UpperHex for Uint<LIMBS> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if f.alternate() {
+            f.write_str("0x")?;
+        }
+        for limb in self.limbs.iter().rev() {
+            write!(f, "{:016X}", limb)?;
+        }
+        Ok(())
+    }
+}
+
+// Operators. Like the inherent methods they wrap on overflow, and `/` and `%` panic on a zero
+// divisor.
+
+/// Implements a binary operator and its assigning form for all owned/borrowed combinations.
+macro_rules! impl_binop {
+    ($trait:ident, $method:ident, $assign_trait:ident, $assign_method:ident, $op:path) => {
+        impl<const LIMBS: usize> core::ops::$trait<&Uint<LIMBS>> for &Uint<LIMBS> {
+            type Output = Uint<LIMBS>;
+
+            fn $method(self, rhs: &Uint<LIMBS>) -> Uint<LIMBS> {
+                $op(self, rhs)
+            }
+        }
+
+        impl<const LIMBS: usize> core::ops::$trait<Uint<LIMBS>> for &Uint<LIMBS> {
+            type Output = Uint<LIMBS>;
+
+            fn $method(self, rhs: Uint<LIMBS>) -> Uint<LIMBS> {
+                $op(self, &rhs)
+            }
+        }
+
+        impl<const LIMBS: usize> core::ops::$trait<&Uint<LIMBS>> for Uint<LIMBS> {
+            type Output = Uint<LIMBS>;
+
+            fn $method(self, rhs: &Uint<LIMBS>) -> Uint<LIMBS> {
+                $op(&self, rhs)
+            }
+        }
+
+        impl<const LIMBS: usize> core::ops::$trait<Uint<LIMBS>> for Uint<LIMBS> {
+            type Output = Uint<LIMBS>;
+
+            fn $method(self, rhs: Uint<LIMBS>) -> Uint<LIMBS> {
+                $op(&self, &rhs)
+            }
+        }
+
+        impl<const LIMBS: usize> core::ops::$assign_trait<&Uint<LIMBS>> for Uint<LIMBS> {
+            fn $assign_method(&mut self, rhs: &Uint<LIMBS>) {
+                *self = $op(self, rhs);
+            }
+        }
+
+        impl<const LIMBS: usize> core::ops::$assign_trait<Uint<LIMBS>> for Uint<LIMBS> {
+            fn $assign_method(&mut self, rhs: Uint<LIMBS>) {
+                *self = $op(self, &rhs);
+            }
+        }
+    };
+}
+
+fn quotient<const LIMBS: usize>(a: &Uint<LIMBS>, b: &Uint<LIMBS>) -> Uint<LIMBS> {
+    a.div_rem(b).0
+}
+
+fn and_limbs<const LIMBS: usize>(a: &Uint<LIMBS>, b: &Uint<LIMBS>) -> Uint<LIMBS> {
+    Uint {
+        limbs: core::array::from_fn(|i| a.limbs[i] & b.limbs[i]),
+    }
+}
+
+fn or_limbs<const LIMBS: usize>(a: &Uint<LIMBS>, b: &Uint<LIMBS>) -> Uint<LIMBS> {
+    Uint {
+        limbs: core::array::from_fn(|i| a.limbs[i] | b.limbs[i]),
+    }
+}
+
+fn xor_limbs<const LIMBS: usize>(a: &Uint<LIMBS>, b: &Uint<LIMBS>) -> Uint<LIMBS> {
+    Uint {
+        limbs: core::array::from_fn(|i| a.limbs[i] ^ b.limbs[i]),
+    }
+}
+
+impl_binop!(Add, add, AddAssign, add_assign, Uint::add);
+impl_binop!(Sub, sub, SubAssign, sub_assign, Uint::sub);
+impl_binop!(Mul, mul, MulAssign, mul_assign, Uint::mul);
+impl_binop!(Div, div, DivAssign, div_assign, quotient);
+impl_binop!(Rem, rem, RemAssign, rem_assign, Uint::rem);
+impl_binop!(BitAnd, bitand, BitAndAssign, bitand_assign, and_limbs);
+impl_binop!(BitOr, bitor, BitOrAssign, bitor_assign, or_limbs);
+impl_binop!(BitXor, bitxor, BitXorAssign, bitxor_assign, xor_limbs);
+
+impl<const LIMBS: usize> core::ops::Not for Uint<LIMBS> {
+    type Output = Self;
+
+    fn not(self) -> Self {
+        Self {
+            limbs: self.limbs.map(|limb| !limb),
+        }
+    }
+}
+
+impl<const LIMBS: usize> core::ops::Shl<usize> for Uint<LIMBS> {
+    type Output = Self;
+
+    fn shl(self, shift: usize) -> Self {
+        Uint::shl(&self, shift)
+    }
+}
+
+impl<const LIMBS: usize> core::ops::Shr<usize> for Uint<LIMBS> {
+    type Output = Self;
+
+    fn shr(self, shift: usize) -> Self {
+        Uint::shr(&self, shift)
+    }
+}
+
+/// Builds a [`Uint`] from an integer literal at compile time.
+///
+/// The literal may be decimal, `0x` hexadecimal or `0b` binary, of any length and with `_`
+/// separators. The limb count is inferred from the context or given after a comma. The value is
+/// always evaluated in a `const` block, so a literal that does not fit is a compile error even
+/// outside `const` and `static` items.
+///
+/// ```
+/// use nail::{uint, U256};
+///
+/// const ORDER: U256 =
+///     uint!(0xFFFFFFFF_FFFFFFFF_FFFFFFFF_FFFFFFFE_BAAEDCE6_AF48A03B_BFD25E8C_D0364141);
+/// static TEN_POW_30: U256 = uint!(1_000_000_000_000_000_000_000_000_000_000);
+///
+/// let mask = uint!(0b1111, 2);
+/// assert_eq!(mask, nail::U128::from_u64(15));
+/// assert!(TEN_POW_30 < ORDER);
+/// ```
+///
+/// ```compile_fail
+/// // 2^64 does not fit in one limb.
+/// let x: nail::U64 = nail::uint!(18446744073709551616);
+/// ```
+#[macro_export]
+macro_rules! uint {
+    ($literal:tt) => {
+        const { $crate::Uint::from_literal(stringify!($literal)) }
+    };
+    ($literal:tt, $limbs:expr) => {
+        const { $crate::Uint::<$limbs>::from_literal(stringify!($literal)) }
+    };
 }
 
 #[cfg(test)]
@@ -205,6 +809,15 @@ mod tests {
         assert_eq!(result.limbs[1], 0);
     }
 
+    #[test]
+    fn test_multiplication_carry_chain() {
+        // (2^128 - 1)^2 = 2^256 - 2^129 + 1: every column carries into the next
+        let a = Uint::<4>::from_be_hex("ffffffffffffffffffffffffffffffff");
+        let b = Uint::<2>::MAX;
+        assert_eq!(a.mul(&b).limbs, [1, 0, u64::MAX - 1, u64::MAX]);
+        assert_eq!(a.mul(&a).limbs, [1, 0, u64::MAX - 1, u64::MAX]);
+    }
+
     #[test]
     fn test_zero() {
         let zero = Uint::<4>::zero();
@@ -294,4 +907,324 @@ mod tests {
         assert_eq!(result.limbs[2], 0);
         assert_eq!(result.limbs[3], 0);
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_subtraction_with_borrow() {
+        let a = Uint::<4>::from_be_hex("10000000000000000");
+        let b = Uint::<1>::from_u64(1);
+        let result = a.sub(&b);
+        assert_eq!(result.limbs[0], u64::MAX);
+        assert_eq!(result.limbs[1], 0);
+
+        // Wraps around on underflow
+        assert_eq!(Uint::<4>::ZERO.sub(&b), Uint::<4>::MAX);
+    }
+
+    #[test]
+    fn test_overflowing_add_sub() {
+        let (sum, carry) = Uint::<2>::MAX.overflowing_add(&Uint::ONE);
+        assert_eq!(sum, Uint::ZERO);
+        assert!(carry);
+
+        let (diff, borrow) = Uint::<2>::ZERO.overflowing_sub(&Uint::ONE);
+        assert_eq!(diff, Uint::MAX);
+        assert!(borrow);
+
+        let (diff, borrow) = Uint::<2>::from_u64(5).overflowing_sub(&Uint::from_u64(3));
+        assert_eq!(diff, Uint::from_u64(2));
+        assert!(!borrow);
+    }
+
+    #[test]
+    fn test_widening_mul() {
+        // (2^128 - 1)^2 = 2^256 - 2^129 + 1
+        let (lo, hi) = Uint::<2>::MAX.widening_mul(&Uint::MAX);
+        assert_eq!(lo, Uint::ONE);
+        assert_eq!(hi.limbs, [u64::MAX - 1, u64::MAX]);
+
+        let a = Uint::<3>::from_be_hex("123456789ABCDEF0FEDCBA9876543210");
+        let b = Uint::<3>::from_u64(0x1_0000);
+        let (lo, hi) = a.widening_mul(&b);
+        assert_eq!(lo, Uint::from_be_hex("123456789ABCDEF0FEDCBA98765432100000"));
+        assert_eq!(hi, Uint::ZERO);
+    }
+
+    #[test]
+    fn test_from_be_hex() {
+        let x = Uint::<2>::from_be_hex("0123456789abcdefFEDCBA9876543210");
+        assert_eq!(x.limbs, [0xFEDC_BA98_7654_3210, 0x0123_4567_89AB_CDEF]);
+
+        // Leading zeros beyond the width are accepted
+        const Y: Uint<1> = Uint::from_be_hex("0000000000000000000000FF");
+        assert_eq!(Y, Uint::from_u64(0xFF));
+    }
+
+    #[test]
+    #[should_panic(expected = "hex value does not fit in Uint")]
+    fn test_from_be_hex_overflow() {
+        let _ = Uint::<1>::from_be_hex("10000000000000000");
+    }
+
+    #[test]
+    fn test_operators() {
+        let a = Uint::<2>::from_be_hex("123456789abcdef0fedcba9876543210");
+        let b = Uint::<2>::from_u64(0x1_0000_0001);
+        assert_eq!(a + b, a.add(&b));
+        assert_eq!(core::ops::Sub::sub(&a, b), a.sub(&b));
+        assert_eq!(core::ops::Mul::mul(a, &b), a.mul(&b));
+        assert_eq!(a / b, a.div_rem(&b).0);
+        assert_eq!(a % b, a.div_rem(&b).1);
+        assert_eq!((a & b) | (a ^ b), a | b);
+        assert_eq!(!Uint::<2>::ZERO, Uint::<2>::MAX);
+        assert_eq!(a << 4 >> 4, a.shl(4).shr(4));
+
+        let mut c = a;
+        c += b;
+        c -= &b;
+        c *= Uint::<2>::ONE;
+        assert_eq!(c, a);
+    }
+
+    #[test]
+    fn test_from_literal() {
+        const SECP256K1_ORDER: Uint<4> = crate::uint!(
+            0xFFFFFFFF_FFFFFFFF_FFFFFFFF_FFFFFFFE_BAAEDCE6_AF48A03B_BFD25E8C_D0364141
+        );
+        assert_eq!(
+            SECP256K1_ORDER,
+            Uint::from_be_hex("FFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFEBAAEDCE6AF48A03BBFD25E8CD0364141")
+        );
+        let x = crate::uint!(340282366920938463463374607431768211455, 2);
+        assert_eq!(x, Uint::<2>::MAX);
+        assert_eq!(crate::uint!(0b1010_0101, 1), Uint::from_u64(0xa5));
+        let inferred: Uint<3> = crate::uint!(0);
+        assert_eq!(inferred, Uint::ZERO);
+        assert_eq!(Uint::<1>::from_literal("0x00000000000000000000ff"), Uint::from_u64(255));
+    }
+
+    #[test]
+    #[should_panic(expected = "integer literal does not fit in Uint")]
+    fn test_from_literal_overflow() {
+        Uint::<1>::from_literal("18446744073709551616");
+    }
+
+    #[test]
+    #[should_panic(expected = "invalid digit in integer literal")]
+    fn test_from_literal_invalid_digit() {
+        Uint::<1>::from_literal("0b102");
+    }
+
+    #[test]
+    fn test_byte_round_trip() {
+        let x = Uint::<2>::from_be_hex("0102030405060708090A0B0C0D0E0F10");
+        let mut be = [0u8; 16];
+        x.write_be_bytes(&mut be);
+        assert_eq!(be, [1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16]);
+        assert_eq!(Uint::<2>::from_be_bytes(&be), Some(x));
+
+        let mut le = [0u8; 16];
+        x.write_le_bytes(&mut le);
+        assert_eq!(le[0], 16);
+        assert_eq!(Uint::<2>::from_le_bytes(&le), Some(x));
+
+        // Short inputs are zero-extended, oversized inputs must have zero high bytes
+        assert_eq!(Uint::<2>::from_be_bytes(&[1, 0]), Some(Uint::from_u64(256)));
+        assert_eq!(Uint::<1>::from_be_bytes(&[0, 0, 0, 0, 0, 0, 0, 0, 0, 7]), Some(Uint::from_u64(7)));
+        assert_eq!(Uint::<1>::from_be_bytes(&[1, 0, 0, 0, 0, 0, 0, 0, 0]), None);
+        assert_eq!(Uint::<1>::from_le_bytes(&[0, 0, 0, 0, 0, 0, 0, 0, 1]), None);
+    }
+
+    #[test]
+    #[cfg(all(feature = "zerocopy", target_endian = "little"))]
+    fn test_zerocopy_casts() {
+        use zerocopy::{FromBytes, IntoBytes};
+
+        let x = Uint::<2>::from_be_hex("0102030405060708090A0B0C0D0E0F10");
+        let mut le = [0u8; 16];
+        x.write_le_bytes(&mut le);
+        assert_eq!(x.as_le_bytes(), &le);
+        assert_eq!(x.as_bytes(), &le);
+
+        // A buffer of limbs stands in for an aligned memory-mapped file of 128-bit values.
+        let storage = [x.limbs[0], x.limbs[1], 5, 0];
+        let values = <[Uint<2>]>::ref_from_bytes(storage.as_bytes()).unwrap();
+        assert_eq!(values, &[x, Uint::from_u64(5)]);
+        assert_eq!(Uint::<2>::read_from_bytes(&le), Ok(x));
+        assert!(<[Uint<2>]>::ref_from_bytes(&storage.as_bytes()[..24]).is_err());
+        assert!(<[Uint<2>]>::ref_from_bytes(&storage.as_bytes()[4..20]).is_err());
+    }
+
+    #[test]
+    fn test_bit_queries() {
+        let x = Uint::<2>::from_be_hex("80000000000000000000000000000100");
+        assert_eq!(x.bits(), 128);
+        assert_eq!(x.trailing_zeros(), 8);
+        assert!(x.bit(127));
+        assert!(x.bit(8));
+        assert!(!x.bit(9));
+        assert!(!x.bit(500));
+        assert!(!x.is_odd());
+        assert!(!x.is_zero());
+
+        assert_eq!(Uint::<2>::ZERO.bits(), 0);
+        assert_eq!(Uint::<2>::ZERO.trailing_zeros(), 128);
+        assert!(Uint::<2>::ZERO.is_zero());
+    }
+
+    #[test]
+    fn test_shifts() {
+        let x = Uint::<3>::from_be_hex("10000000000000003");
+        assert_eq!(x.shl(1), Uint::from_be_hex("20000000000000006"));
+        assert_eq!(x.shl(64), Uint::from_be_hex("100000000000000030000000000000000"));
+        assert_eq!(x.shl(120).shr(120), x);
+        assert_eq!(x.shr(1), Uint::from_be_hex("8000000000000001"));
+        assert_eq!(x.shr(64), Uint::ONE);
+        assert_eq!(x.shl(192), Uint::ZERO);
+        assert_eq!(x.shr(192), Uint::ZERO);
+        assert_eq!(x.shl(0), x);
+    }
+
+    #[test]
+    fn test_ordering() {
+        let small = Uint::<2>::from_be_hex("FFFFFFFFFFFFFFFF");
+        let large = Uint::<2>::from_be_hex("10000000000000000");
+        assert!(small < large);
+        assert!(large > small);
+        assert_eq!(small.cmp(&small), Ordering::Equal);
+        assert_eq!(Uint::<2>::MAX.max(large), Uint::MAX);
+    }
+
+    #[test]
+    fn test_resize() {
+        let x = Uint::<2>::from_be_hex("0123456789ABCDEF0011223344556677");
+        assert_eq!(x.resize::<4>(), Uint::from_be_hex("0123456789ABCDEF0011223344556677"));
+        assert_eq!(x.resize::<1>(), Uint::from_u64(0x0011_2233_4455_6677));
+        assert_eq!(x.resize::<4>().resize::<2>(), x);
+    }
+
+    #[test]
+    fn test_div_rem() {
+        let a = Uint::<3>::from_be_hex("123456789ABCDEF0FEDCBA98765432100F1E2D3C4B5A6978");
+        let b = Uint::<3>::from_be_hex("FEDCBA9876543210FF");
+        let (q, r) = a.div_rem(&b);
+        assert!(r < b);
+        assert_eq!(q.mul(&b).add(&r), a);
+        assert_eq!(a.rem(&b), r);
+
+        // Divisors with the top bit set exercise the overflow of the shifted remainder
+        let top = Uint::<2>::from_be_hex("80000000000000000000000000000001");
+        assert_eq!(Uint::<2>::MAX.div_rem(&top), (Uint::ONE, Uint::from_be_hex("7FFFFFFFFFFFFFFFFFFFFFFFFFFFFFFE")));
+        assert_eq!(Uint::<2>::MAX.div_rem(&Uint::MAX), (Uint::ONE, Uint::ZERO));
+        assert_eq!(Uint::<2>::from_u64(5).div_rem(&Uint::from_u64(7)), (Uint::ZERO, Uint::from_u64(5)));
+    }
+
+    #[test]
+    fn test_ct_div_rem_matches_div_rem() {
+        let mut rng = crate::rand::TestRng(44);
+        let mut values = vec![Uint::<3>::ONE, Uint::MAX, Uint::from_u64(3), Uint::MAX.shr(1)];
+        values.push(Uint::from_be_hex("80000000000000000000000000000001"));
+        for bits in [1, 63, 64, 65, 127, 128, 129, 191, 192] {
+            values.push(Uint::random_bits(&mut rng, bits));
+        }
+        for a in values.iter().chain([&Uint::ZERO]) {
+            for d in values.iter().filter(|d| !d.is_zero()) {
+                let (q, r) = a.ct_div_rem(d);
+                assert_eq!((q, r), a.div_rem(d), "{a:x} / {d:x}");
+                assert_eq!(a.ct_rem(d), r);
+                assert!(r < *d);
+            }
+        }
+    }
+
+    #[test]
+    fn test_from_str_radix() {
+        let x = Uint::<2>::from_be_hex("123456789abcdef0fedcba9876543210");
+        assert_eq!(Uint::from_str_radix("123456789ABCDEF0fedcba9876543210", 16), Ok(x));
+        assert_eq!(
+            Uint::<2>::from_str_radix("+340282366920938463463374607431768211455", 10),
+            Ok(Uint::MAX)
+        );
+        assert_eq!(Uint::<1>::from_str_radix("zz", 36), Ok(Uint::from_u64(36 * 36 - 1)));
+        assert_eq!(
+            Uint::<2>::from_str_radix("340282366920938463463374607431768211456", 10),
+            Err(ParseUintError::Overflow)
+        );
+        assert_eq!(Uint::<2>::from_str_radix("12a", 10), Err(ParseUintError::InvalidDigit));
+        assert_eq!(Uint::<2>::from_str_radix("-1", 10), Err(ParseUintError::InvalidDigit));
+        assert_eq!(Uint::<2>::from_str_radix("+", 10), Err(ParseUintError::Empty));
+    }
+
+    #[test]
+    #[should_panic(expected = "division by zero")]
+    fn test_ct_div_by_zero() {
+        let _ = Uint::<2>::ONE.ct_div_rem(&Uint::ZERO);
+    }
+
+    #[test]
+    #[should_panic(expected = "division by zero")]
+    fn test_div_by_zero() {
+        let _ = Uint::<2>::ONE.div_rem(&Uint::ZERO);
+    }
+
+    #[test]
+    fn test_gcd_and_inverse() {
+        let a = Uint::<2>::from_u64(2 * 3 * 5 * 7 * 11 * 13);
+        let b = Uint::<2>::from_u64(7 * 13 * 17 * 64);
+        assert_eq!(a.gcd(&b), Uint::from_u64(7 * 13 * 2));
+        assert_eq!(a.gcd(&Uint::ZERO), a);
+        assert_eq!(Uint::<2>::ZERO.gcd(&Uint::ZERO), Uint::ZERO);
+
+        // Odd and even moduli
+        let m = Uint::<2>::from_be_hex("1000000000000000000000000000000");
+        let x = Uint::<2>::from_u64(65537);
+        let inv = x.inv_mod(&m).unwrap();
+        assert_eq!(x.mul(&inv).rem(&m), Uint::ONE);
+        let p = Uint::<2>::from_be_hex("FFFFFFFFFFFFFFFFFFFFFFFFFFFFFF61");
+        let y = Uint::<2>::from_be_hex("DEADBEEF0123456789ABCDEF");
+        let product = y.resize::<4>().mul(&y.inv_mod(&p).unwrap());
+        assert_eq!(product.rem(&p.resize()), Uint::ONE);
+        assert_eq!(y.inv_mod(&p).unwrap().inv_mod(&p), Some(y));
+
+        assert_eq!(Uint::<2>::from_u64(6).inv_mod(&Uint::from_u64(9)), None);
+        assert_eq!(Uint::<2>::ZERO.inv_mod(&Uint::from_u64(9)), None);
+        assert_eq!(Uint::<2>::from_u64(3).inv_mod(&Uint::from_u64(10)), Some(Uint::from_u64(7)));
+    }
+
+    #[test]
+    fn test_hex_formatting() {
+        let x = Uint::<2>::from_u64(0xABC);
+        assert_eq!(format!("{:x}", x), "00000000000000000000000000000abc");
+        assert_eq!(format!("{:#X}", x), "0x00000000000000000000000000000ABC");
+    }
+
+    /// Runs `f` on a thread with a `kib` KiB stack, a fraction of the usual default.
+    fn on_small_stack<T: Send + 'static>(kib: usize, f: impl FnOnce() -> T + Send + 'static) -> T {
+        std::thread::Builder::new().stack_size(kib * 1024).spawn(f).unwrap().join().unwrap()
+    }
+
+    #[test]
+    fn test_wide_operations_fit_small_stack() {
+        // 8192-bit values are 1 KiB each; the algorithms must not need more than a few dozen
+        // of them, even in unoptimized builds.
+        let mut a = Uint::<128>::MAX.shr(1);
+        a.limbs[0] = 0x1234_5678_9abc_def1;
+        let b = Uint::<128>::from_be_hex("fedcba98765432100123456789abcdef");
+
+        let (q, r) = on_small_stack(64, move || a.div_rem(&b));
+        assert_eq!(q.mul(&b).add(&r), a);
+        let (lo, hi) = on_small_stack(64, move || a.widening_mul(&b));
+        assert_eq!(lo, a.mul(&b));
+        assert!(hi.bits() <= 128);
+        let inv = on_small_stack(64, move || b.inv_mod(&a)).unwrap();
+        let mul_mod = |x: &Uint<128>, y: &Uint<128>| -> Uint<128> {
+            x.resize::<256>().mul(y).rem(&a.resize()).resize()
+        };
+        assert_eq!(mul_mod(&inv, &b), Uint::ONE);
+        // Exponentiation also holds a 16-entry window table.
+        let pow = on_small_stack(128, move || {
+            let monty = crate::MontyParams::new(&a);
+            monty.from_monty(&monty.pow(&monty.to_monty(&b), &Uint::<1>::from_u64(3)))
+        });
+        assert_eq!(pow, mul_mod(&mul_mod(&b, &b), &b));
+    }
+}
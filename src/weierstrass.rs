@@ -0,0 +1,765 @@
+//! Short Weierstrass curves `y^2 = x^3 + a*x + b` over a prime field.
+//!
+//! A curve is declared by implementing [`CurveParams`] on a marker type; [`AffinePoint`] and
+//! [`ProjectivePoint`] then provide the group law. Arithmetic happens in homogeneous projective
+//! coordinates using the complete formulas of Renes, Costello and Batina ("Complete addition
+//! formulas for prime order elliptic curves", 2016), so addition has no special cases for the
+//! identity, doubling or inverse points. Specialised versions are picked at compile time for
+//! `a = 0` and `a = -3`.
+//!
+//! The formulas are only complete on curves of odd order; every curve in [`crate::curves`] has
+//! prime order.
+
+use core::fmt;
+
+use crate::{
+    ct::{fixed_window, Choice, ConditionallySelectable},
+    field::{FieldParams, Fp},
+    uint::Uint,
+};
+
+/// Compile-time description of a prime-order short Weierstrass curve.
+pub trait CurveParams<const LIMBS: usize>: Copy + fmt::Debug + Eq + Send + Sync + 'static {
+    /// The base field the coordinates live in.
+    type Base: FieldParams<LIMBS>;
+
+    /// The scalar field, whose modulus is the group order.
+    type Scalar: FieldParams<LIMBS>;
+
+    /// The coefficient `a`.
+    const A: Fp<LIMBS, Self::Base>;
+
+    /// The coefficient `b`.
+    const B: Fp<LIMBS, Self::Base>;
+
+    /// Affine coordinates `(x, y)` of the standard generator.
+    const GENERATOR: (Fp<LIMBS, Self::Base>, Fp<LIMBS, Self::Base>);
+}
+
+/// A base field element of the curve `C`.
+pub type FieldElement<const LIMBS: usize, C> = Fp<LIMBS, <C as CurveParams<LIMBS>>::Base>;
+
+/// A scalar of the curve `C`, i.e. an integer modulo the group order.
+pub type Scalar<const LIMBS: usize, C> = Fp<LIMBS, <C as CurveParams<LIMBS>>::Scalar>;
+
+/// The shape of the coefficient `a`, which selects the addition formulas.
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum CoeffA {
+    Zero,
+    MinusThree,
+    Generic,
+}
+
+/// A point in affine coordinates, or the point at infinity.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AffinePoint<const LIMBS: usize, C: CurveParams<LIMBS>> {
+    x: FieldElement<LIMBS, C>,
+    y: FieldElement<LIMBS, C>,
+    /// Set for the point at infinity, whose coordinates are both zero.
+    infinity: bool,
+}
+
+impl<const LIMBS: usize, C: CurveParams<LIMBS>> AffinePoint<LIMBS, C> {
+    /// The point at infinity.
+    pub const IDENTITY: Self = Self {
+        x: Fp::ZERO,
+        y: Fp::ZERO,
+        infinity: true,
+    };
+
+    /// The standard generator.
+    pub const GENERATOR: Self = Self {
+        x: C::GENERATOR.0,
+        y: C::GENERATOR.1,
+        infinity: false,
+    };
+
+    /// Creates a point from affine coordinates, or returns `None` if it is not on the curve.
+    pub fn new(x: FieldElement<LIMBS, C>, y: FieldElement<LIMBS, C>) -> Option<Self> {
+        let point = Self {
+            x,
+            y,
+            infinity: false,
+        };
+        if point.is_on_curve() {
+            Some(point)
+        } else {
+            None
+        }
+    }
+
+    /// Recovers the point with the given x-coordinate and y parity, or returns `None` if
+    /// `x^3 + a*x + b` is not a square.
+    pub fn decompress(x: &FieldElement<LIMBS, C>, y_is_odd: bool) -> Option<Self> {
+        let y = x.square().add(&C::A).mul(x).add(&C::B).sqrt()?;
+        let y = if y.to_uint().is_odd() == y_is_odd {
+            y
+        } else {
+            y.neg()
+        };
+        Some(Self {
+            x: *x,
+            y,
+            infinity: false,
+        })
+    }
+
+    /// Returns the x-coordinate, which is zero for the point at infinity.
+    pub fn x(&self) -> FieldElement<LIMBS, C> {
+        self.x
+    }
+
+    /// Returns the y-coordinate, which is zero for the point at infinity.
+    pub fn y(&self) -> FieldElement<LIMBS, C> {
+        self.y
+    }
+
+    /// Returns `true` for the point at infinity.
+    pub fn is_identity(&self) -> bool {
+        self.infinity
+    }
+
+    /// Returns `true` if the point satisfies the curve equation or is the point at infinity.
+    pub fn is_on_curve(&self) -> bool {
+        let rhs = self.x.square().add(&C::A).mul(&self.x).add(&C::B);
+        self.infinity || self.y.square() == rhs
+    }
+
+    /// Returns `-self`.
+    pub fn neg(&self) -> Self {
+        Self {
+            x: self.x,
+            y: self.y.neg(),
+            infinity: self.infinity,
+        }
+    }
+
+    /// Converts the point to projective coordinates.
+    pub fn to_projective(&self) -> ProjectivePoint<LIMBS, C> {
+        ProjectivePoint::from_affine(self)
+    }
+}
+
+impl<const LIMBS: usize, C: CurveParams<LIMBS>> Default for AffinePoint<LIMBS, C> {
+    fn default() -> Self {
+        Self::IDENTITY
+    }
+}
+
+/// A point in homogeneous projective coordinates `(X : Y : Z)`, representing `(X/Z, Y/Z)`.
+///
+/// The identity is any point with `Z = 0`; equality compares the represented points.
+#[derive(Debug, Clone, Copy)]
+pub struct ProjectivePoint<const LIMBS: usize, C: CurveParams<LIMBS>> {
+    x: FieldElement<LIMBS, C>,
+    y: FieldElement<LIMBS, C>,
+    z: FieldElement<LIMBS, C>,
+}
+
+impl<const LIMBS: usize, C: CurveParams<LIMBS>> ProjectivePoint<LIMBS, C> {
+    /// The point at infinity, `(0 : 1 : 0)`.
+    pub const IDENTITY: Self = Self {
+        x: Fp::ZERO,
+        y: Fp::ONE,
+        z: Fp::ZERO,
+    };
+
+    /// The standard generator.
+    pub const GENERATOR: Self = Self {
+        x: C::GENERATOR.0,
+        y: C::GENERATOR.1,
+        z: Fp::ONE,
+    };
+
+    const A_SHAPE: CoeffA = {
+        let three = Fp::from_uint_const(&Uint::from_u64(3));
+        if C::A.is_zero() {
+            CoeffA::Zero
+        } else if C::A.add(&three).is_zero() {
+            CoeffA::MinusThree
+        } else {
+            CoeffA::Generic
+        }
+    };
+
+    const B3: FieldElement<LIMBS, C> = C::B.double().add(&C::B);
+
+    /// Converts an affine point, mapping the point at infinity to [`ProjectivePoint::IDENTITY`].
+    pub fn from_affine(point: &AffinePoint<LIMBS, C>) -> Self {
+        let infinity = point.infinity as u64;
+        Self {
+            x: point.x,
+            y: Fp::select(&point.y, &Fp::ONE, infinity),
+            z: Fp::select(&Fp::ONE, &Fp::ZERO, infinity),
+        }
+    }
+
+    /// Converts to affine coordinates with one field inversion.
+    pub fn to_affine(&self) -> AffinePoint<LIMBS, C> {
+        match self.z.invert() {
+            Some(z_inv) => AffinePoint {
+                x: self.x.mul(&z_inv),
+                y: self.y.mul(&z_inv),
+                infinity: false,
+            },
+            None => AffinePoint::IDENTITY,
+        }
+    }
+
+    /// Returns `true` for the point at infinity.
+    pub fn is_identity(&self) -> bool {
+        self.z.is_zero()
+    }
+
+    /// Returns `true` if the point satisfies `Y^2 Z = X^3 + a X Z^2 + b Z^3`.
+    pub fn is_on_curve(&self) -> bool {
+        let z2 = self.z.square();
+        let lhs = self.y.square().mul(&self.z);
+        let rhs = self
+            .x
+            .square()
+            .add(&C::A.mul(&z2))
+            .mul(&self.x)
+            .add(&C::B.mul(&z2).mul(&self.z));
+        lhs == rhs
+    }
+
+    /// Returns `a` when `choice` is 0 and `b` when `choice` is 1, without branching.
+    fn select(a: &Self, b: &Self, choice: u64) -> Self {
+        Self {
+            x: Fp::select(&a.x, &b.x, choice),
+            y: Fp::select(&a.y, &b.y, choice),
+            z: Fp::select(&a.z, &b.z, choice),
+        }
+    }
+
+    /// Returns `self + other` using the complete addition formulas.
+    pub fn add(&self, other: &Self) -> Self {
+        match Self::A_SHAPE {
+            CoeffA::Zero => self.add_a_zero(other),
+            CoeffA::MinusThree => self.add_a_minus_three(other),
+            CoeffA::Generic => self.add_generic(other),
+        }
+    }
+
+    /// Returns `2 * self`.
+    pub fn double(&self) -> Self {
+        match Self::A_SHAPE {
+            CoeffA::Zero => self.double_a_zero(),
+            CoeffA::MinusThree => self.double_a_minus_three(),
+            CoeffA::Generic => self.double_generic(),
+        }
+    }
+
+    /// Returns `-self`.
+    pub fn neg(&self) -> Self {
+        Self {
+            x: self.x,
+            y: self.y.neg(),
+            z: self.z,
+        }
+    }
+
+    /// Returns `self - other`.
+    pub fn sub(&self, other: &Self) -> Self {
+        self.add(&other.neg())
+    }
+
+    /// Multiplies the point by `k` with a fixed 4-bit window, so the running time and memory
+    /// accesses depend only on `S`, not on the value of `k`.
+    pub fn mul<const S: usize>(&self, k: &Uint<S>) -> Self {
+        fixed_window(Self::IDENTITY, self, k, Self::add, Self::double)
+    }
+
+    /// Multiplies the point by a public `k`, skipping leading zero bits and absent additions.
+    pub fn mul_vartime<const S: usize>(&self, k: &Uint<S>) -> Self {
+        let mut acc = Self::IDENTITY;
+        for i in (0..k.bits()).rev() {
+            acc = acc.double();
+            if k.bit(i) {
+                acc = acc.add(self);
+            }
+        }
+        acc
+    }
+
+    /// Computes `a * self + b * other` for public scalars with Shamir's trick, sharing one chain
+    /// of doublings between both products.
+    pub fn double_mul_vartime<const S: usize>(
+        &self,
+        a: &Uint<S>,
+        other: &Self,
+        b: &Uint<S>,
+    ) -> Self {
+        let both = self.add(other);
+        let mut acc = Self::IDENTITY;
+        for i in (0..a.bits().max(b.bits())).rev() {
+            acc = acc.double();
+            match (a.bit(i), b.bit(i)) {
+                (true, true) => acc = acc.add(&both),
+                (true, false) => acc = acc.add(self),
+                (false, true) => acc = acc.add(other),
+                (false, false) => {}
+            }
+        }
+        acc
+    }
+
+    /// Algorithm 1 of Renes–Costello–Batina: addition for arbitrary `a`.
+    fn add_generic(&self, other: &Self) -> Self {
+        let (a, b3) = (&C::A, &Self::B3);
+        let (x1, y1, z1) = (&self.x, &self.y, &self.z);
+        let (x2, y2, z2) = (&other.x, &other.y, &other.z);
+
+        let t0 = x1.mul(x2);
+        let t1 = y1.mul(y2);
+        let t2 = z1.mul(z2);
+        let t3 = x1.add(y1).mul(&x2.add(y2)).sub(&t0.add(&t1));
+        let t4 = x1.add(z1).mul(&x2.add(z2)).sub(&t0.add(&t2));
+        let t5 = y1.add(z1).mul(&y2.add(z2)).sub(&t1.add(&t2));
+
+        let z3 = a.mul(&t4).add(&b3.mul(&t2));
+        let x3 = t1.sub(&z3);
+        let z3 = t1.add(&z3);
+        let y3 = x3.mul(&z3);
+
+        let t1 = t0.double().add(&t0);
+        let t2 = a.mul(&t2);
+        let t4 = b3.mul(&t4);
+        let t1 = t1.add(&t2);
+        let t2 = a.mul(&t0.sub(&t2));
+        let t4 = t4.add(&t2);
+
+        Self {
+            x: t3.mul(&x3).sub(&t5.mul(&t4)),
+            y: y3.add(&t1.mul(&t4)),
+            z: t5.mul(&z3).add(&t3.mul(&t1)),
+        }
+    }
+
+    /// Algorithm 3 of Renes–Costello–Batina: doubling for arbitrary `a`.
+    fn double_generic(&self) -> Self {
+        let (a, b3) = (&C::A, &Self::B3);
+        let (x, y, z) = (&self.x, &self.y, &self.z);
+
+        let t0 = x.square();
+        let t1 = y.square();
+        let t2 = z.square();
+        let t3 = x.mul(y).double();
+        let z3 = x.mul(z).double();
+
+        let y3 = a.mul(&z3).add(&b3.mul(&t2));
+        let x3 = t1.sub(&y3);
+        let y3 = t1.add(&y3);
+        let y3 = x3.mul(&y3);
+        let x3 = t3.mul(&x3);
+        let z3 = b3.mul(&z3);
+        let t2 = a.mul(&t2);
+        let t3 = a.mul(&t0.sub(&t2)).add(&z3);
+        let t0 = t0.double().add(&t0).add(&t2).mul(&t3);
+        let y3 = y3.add(&t0);
+        let t2 = y.mul(z).double();
+
+        Self {
+            x: x3.sub(&t2.mul(&t3)),
+            y: y3,
+            z: t2.mul(&t1).double().double(),
+        }
+    }
+
+    /// Algorithm 4 of Renes–Costello–Batina: addition for `a = -3`.
+    fn add_a_minus_three(&self, other: &Self) -> Self {
+        let b = &C::B;
+        let (x1, y1, z1) = (&self.x, &self.y, &self.z);
+        let (x2, y2, z2) = (&other.x, &other.y, &other.z);
+
+        let t0 = x1.mul(x2);
+        let t1 = y1.mul(y2);
+        let t2 = z1.mul(z2);
+        let t3 = x1.add(y1).mul(&x2.add(y2)).sub(&t0.add(&t1));
+        let t4 = y1.add(z1).mul(&y2.add(z2)).sub(&t1.add(&t2));
+        let y3 = x1.add(z1).mul(&x2.add(z2)).sub(&t0.add(&t2));
+
+        let x3 = y3.sub(&b.mul(&t2));
+        let x3 = x3.double().add(&x3);
+        let z3 = t1.sub(&x3);
+        let x3 = t1.add(&x3);
+
+        let t2 = t2.double().add(&t2);
+        let y3 = b.mul(&y3).sub(&t2).sub(&t0);
+        let y3 = y3.double().add(&y3);
+        let t0 = t0.double().add(&t0).sub(&t2);
+
+        Self {
+            x: t3.mul(&x3).sub(&t4.mul(&y3)),
+            y: x3.mul(&z3).add(&t0.mul(&y3)),
+            z: t4.mul(&z3).add(&t3.mul(&t0)),
+        }
+    }
+
+    /// Algorithm 6 of Renes–Costello–Batina: doubling for `a = -3`.
+    fn double_a_minus_three(&self) -> Self {
+        let b = &C::B;
+        let (x, y, z) = (&self.x, &self.y, &self.z);
+
+        let t0 = x.square();
+        let t1 = y.square();
+        let t2 = z.square();
+        let t3 = x.mul(y).double();
+        let z3 = x.mul(z).double();
+
+        let y3 = b.mul(&t2).sub(&z3);
+        let y3 = y3.double().add(&y3);
+        let x3 = t1.sub(&y3);
+        let y3 = t1.add(&y3);
+        let y3 = x3.mul(&y3);
+        let x3 = x3.mul(&t3);
+
+        let t2 = t2.double().add(&t2);
+        let z3 = b.mul(&z3).sub(&t2).sub(&t0);
+        let z3 = z3.double().add(&z3);
+        let t0 = t0.double().add(&t0).sub(&t2);
+        let y3 = y3.add(&t0.mul(&z3));
+        let t0 = y.mul(z).double();
+
+        Self {
+            x: x3.sub(&t0.mul(&z3)),
+            y: y3,
+            z: t0.mul(&t1).double().double(),
+        }
+    }
+
+    /// Algorithm 7 of Renes–Costello–Batina: addition for `a = 0`.
+    fn add_a_zero(&self, other: &Self) -> Self {
+        let b3 = &Self::B3;
+        let (x1, y1, z1) = (&self.x, &self.y, &self.z);
+        let (x2, y2, z2) = (&other.x, &other.y, &other.z);
+
+        let t0 = x1.mul(x2);
+        let t1 = y1.mul(y2);
+        let t2 = z1.mul(z2);
+        let t3 = x1.add(y1).mul(&x2.add(y2)).sub(&t0.add(&t1));
+        let t4 = y1.add(z1).mul(&y2.add(z2)).sub(&t1.add(&t2));
+        let y3 = x1.add(z1).mul(&x2.add(z2)).sub(&t0.add(&t2));
+
+        let t0 = t0.double().add(&t0);
+        let t2 = b3.mul(&t2);
+        let z3 = t1.add(&t2);
+        let t1 = t1.sub(&t2);
+        let y3 = b3.mul(&y3);
+
+        Self {
+            x: t3.mul(&t1).sub(&t4.mul(&y3)),
+            y: t1.mul(&z3).add(&y3.mul(&t0)),
+            z: z3.mul(&t4).add(&t0.mul(&t3)),
+        }
+    }
+
+    /// Algorithm 9 of Renes–Costello–Batina: doubling for `a = 0`.
+    fn double_a_zero(&self) -> Self {
+        let b3 = &Self::B3;
+        let (x, y, z) = (&self.x, &self.y, &self.z);
+
+        let t0 = y.square();
+        let z3 = t0.double().double().double();
+        let t1 = y.mul(z);
+        let t2 = b3.mul(&z.square());
+        let x3 = t2.mul(&z3);
+        let y3 = t0.add(&t2);
+        let z3 = t1.mul(&z3);
+        let t2 = t2.double().add(&t2);
+        let t0 = t0.sub(&t2);
+        let y3 = t0.mul(&y3).add(&x3);
+
+        Self {
+            x: t0.mul(&x.mul(y)).double(),
+            y: y3,
+            z: z3,
+        }
+    }
+}
+
+impl<const LIMBS: usize, C: CurveParams<LIMBS>> PartialEq for ProjectivePoint<LIMBS, C> {
+    fn eq(&self, other: &Self) -> bool {
+        self.x.mul(&other.z) == other.x.mul(&self.z) && self.y.mul(&other.z) == other.y.mul(&self.z)
+    }
+}
+
+impl<const LIMBS: usize, C: CurveParams<LIMBS>> Eq for ProjectivePoint<LIMBS, C> {}
+
+impl<const LIMBS: usize, C: CurveParams<LIMBS>> ConditionallySelectable
+    for ProjectivePoint<LIMBS, C>
+{
+    fn conditional_select(a: &Self, b: &Self, choice: Choice) -> Self {
+        Self::select(a, b, choice.to_bit())
+    }
+}
+
+impl<const LIMBS: usize, C: CurveParams<LIMBS>> Default for ProjectivePoint<LIMBS, C> {
+    fn default() -> Self {
+        Self::IDENTITY
+    }
+}
+
+impl<const LIMBS: usize, C: CurveParams<LIMBS>> From<AffinePoint<LIMBS, C>>
+    for ProjectivePoint<LIMBS, C>
+{
+    fn from(point: AffinePoint<LIMBS, C>) -> Self {
+        Self::from_affine(&point)
+    }
+}
+
+impl<const LIMBS: usize, C: CurveParams<LIMBS>> From<ProjectivePoint<LIMBS, C>>
+    for AffinePoint<LIMBS, C>
+{
+    fn from(point: ProjectivePoint<LIMBS, C>) -> Self {
+        point.to_affine()
+    }
+}
+
+/// Implements a point operator and its assigning form for all owned/borrowed combinations.
+macro_rules! impl_point_binop {
+    ($trait:ident, $method:ident, $assign_trait:ident, $assign_method:ident) => {
+        impl<const LIMBS: usize, C: CurveParams<LIMBS>>
+            core::ops::$trait<&ProjectivePoint<LIMBS, C>> for &ProjectivePoint<LIMBS, C>
+        {
+            type Output = ProjectivePoint<LIMBS, C>;
+
+            fn $method(self, rhs: &ProjectivePoint<LIMBS, C>) -> ProjectivePoint<LIMBS, C> {
+                ProjectivePoint::$method(self, rhs)
+            }
+        }
+
+        impl<const LIMBS: usize, C: CurveParams<LIMBS>> core::ops::$trait<ProjectivePoint<LIMBS, C>>
+            for &ProjectivePoint<LIMBS, C>
+        {
+            type Output = ProjectivePoint<LIMBS, C>;
+
+            fn $method(self, rhs: ProjectivePoint<LIMBS, C>) -> ProjectivePoint<LIMBS, C> {
+                ProjectivePoint::$method(self, &rhs)
+            }
+        }
+
+        impl<const LIMBS: usize, C: CurveParams<LIMBS>>
+            core::ops::$trait<&ProjectivePoint<LIMBS, C>> for ProjectivePoint<LIMBS, C>
+        {
+            type Output = ProjectivePoint<LIMBS, C>;
+
+            fn $method(self, rhs: &ProjectivePoint<LIMBS, C>) -> ProjectivePoint<LIMBS, C> {
+                ProjectivePoint::$method(&self, rhs)
+            }
+        }
+
+        impl<const LIMBS: usize, C: CurveParams<LIMBS>> core::ops::$trait<ProjectivePoint<LIMBS, C>>
+            for ProjectivePoint<LIMBS, C>
+        {
+            type Output = ProjectivePoint<LIMBS, C>;
+
+            fn $method(self, rhs: ProjectivePoint<LIMBS, C>) -> ProjectivePoint<LIMBS, C> {
+                ProjectivePoint::$method(&self, &rhs)
+            }
+        }
+
+        impl<const LIMBS: usize, C: CurveParams<LIMBS>>
+            core::ops::$assign_trait<&ProjectivePoint<LIMBS, C>> for ProjectivePoint<LIMBS, C>
+        {
+            fn $assign_method(&mut self, rhs: &ProjectivePoint<LIMBS, C>) {
+                *self = ProjectivePoint::$method(self, rhs);
+            }
+        }
+
+        impl<const LIMBS: usize, C: CurveParams<LIMBS>>
+            core::ops::$assign_trait<ProjectivePoint<LIMBS, C>> for ProjectivePoint<LIMBS, C>
+        {
+            fn $assign_method(&mut self, rhs: ProjectivePoint<LIMBS, C>) {
+                *self = ProjectivePoint::$method(self, &rhs);
+            }
+        }
+    };
+}
+
+impl_point_binop!(Add, add, AddAssign, add_assign);
+impl_point_binop!(Sub, sub, SubAssign, sub_assign);
+
+impl<const LIMBS: usize, C: CurveParams<LIMBS>> core::ops::Neg for ProjectivePoint<LIMBS, C> {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        ProjectivePoint::neg(&self)
+    }
+}
+
+impl<const LIMBS: usize, C: CurveParams<LIMBS>> core::ops::Neg for &ProjectivePoint<LIMBS, C> {
+    type Output = ProjectivePoint<LIMBS, C>;
+
+    fn neg(self) -> ProjectivePoint<LIMBS, C> {
+        ProjectivePoint::neg(self)
+    }
+}
+
+impl<const LIMBS: usize, C: CurveParams<LIMBS>> core::ops::Mul<&Scalar<LIMBS, C>>
+    for &ProjectivePoint<LIMBS, C>
+{
+    type Output = ProjectivePoint<LIMBS, C>;
+
+    fn mul(self, rhs: &Scalar<LIMBS, C>) -> ProjectivePoint<LIMBS, C> {
+        ProjectivePoint::mul(self, &rhs.to_uint())
+    }
+}
+
+impl<const LIMBS: usize, C: CurveParams<LIMBS>> core::ops::Mul<&Scalar<LIMBS, C>>
+    for ProjectivePoint<LIMBS, C>
+{
+    type Output = ProjectivePoint<LIMBS, C>;
+
+    fn mul(self, rhs: &Scalar<LIMBS, C>) -> ProjectivePoint<LIMBS, C> {
+        ProjectivePoint::mul(&self, &rhs.to_uint())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::curves::{p256, p384, secp256k1};
+
+    /// P-256 moved to `a = -48` by the isomorphism `(x, y) -> (4x, 8y)`, which exercises the
+    /// generic formulas on a curve whose order is known.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    struct P256Iso;
+
+    impl CurveParams<4> for P256Iso {
+        type Base = p256::BaseParams;
+        type Scalar = p256::ScalarParams;
+
+        const A: Fp<4, p256::BaseParams> = Fp::from_uint_const(&Uint::from_be_hex(
+            "FFFFFFFF00000001000000000000000000000000FFFFFFFFFFFFFFFFFFFFFFCF",
+        ));
+        const B: Fp<4, p256::BaseParams> = Fp::from_uint_const(&Uint::from_be_hex(
+            "B18D76408EA4F9D6FAEF555DA621AF194741AC1D14EC3D8EF38F0F89F49812D6",
+        ));
+        const GENERATOR: (Fp<4, p256::BaseParams>, Fp<4, p256::BaseParams>) = (
+            Fp::from_uint_const(&Uint::from_be_hex(
+                "AC5F47CC84B1091EE2F39B958E9103C9DC0DF603B7ACCE83D284E51762630A59",
+            )),
+            Fp::from_uint_const(&Uint::from_be_hex(
+                "7F1A1719F0D3FCDA773F5A53E07CF0B15E719AB9598AF6765DB20341BDFA8FAA",
+            )),
+        );
+    }
+
+    /// Checks the group laws on small multiples of the generator.
+    fn check_group_laws<const LIMBS: usize, C: CurveParams<LIMBS>>() {
+        let g = ProjectivePoint::<LIMBS, C>::GENERATOR;
+        let id = ProjectivePoint::<LIMBS, C>::IDENTITY;
+        assert!(g.is_on_curve());
+        assert!(AffinePoint::<LIMBS, C>::GENERATOR.is_on_curve());
+
+        // Identity and inverses, with no special-casing in the formulas
+        assert_eq!(g.add(&id), g);
+        assert_eq!(id.add(&g), g);
+        assert_eq!(id.add(&id), id);
+        assert_eq!(id.double(), id);
+        assert!(g.add(&g.neg()).is_identity());
+        assert!(g.sub(&g).is_identity());
+
+        // Doubling agrees with addition, and both commute and associate
+        let g2 = g.double();
+        assert_eq!(g.add(&g), g2);
+        let g3 = g2.add(&g);
+        assert_eq!(g.add(&g2), g3);
+        assert_eq!(g3.sub(&g), g2);
+        let g5 = g2.add(&g3);
+        assert_eq!(g2.double().add(&g), g5);
+        assert_eq!(g.add(&g2).add(&g2), g.add(&g2.add(&g2)));
+        assert!(g5.is_on_curve());
+
+        // Scalar multiplication, constant-time and variable-time
+        let five = Uint::<1>::from_u64(5);
+        assert_eq!(g.mul(&five), g5);
+        assert_eq!(g.mul_vartime(&five), g5);
+        assert!(g.mul(&Uint::<2>::ZERO).is_identity());
+
+        // The group order annihilates the generator
+        let order = <C::Scalar as FieldParams<LIMBS>>::MODULUS;
+        assert!(g.mul(&order).is_identity());
+        assert!(g.mul_vartime(&order).is_identity());
+        let minus_one = order.sub(&Uint::<1>::from_u64(1));
+        assert_eq!(g.mul(&minus_one), g.neg());
+
+        // Affine round trips, including the identity
+        assert_eq!(g5.to_affine().to_projective(), g5);
+        assert_eq!(id.to_affine(), AffinePoint::IDENTITY);
+        assert!(AffinePoint::<LIMBS, C>::IDENTITY
+            .to_projective()
+            .is_identity());
+        let affine = g3.to_affine();
+        assert_eq!(AffinePoint::new(affine.x(), affine.y()), Some(affine));
+        assert!(AffinePoint::<LIMBS, C>::new(affine.x(), affine.y().add(&Fp::ONE)).is_none());
+        assert_eq!(affine.neg().to_projective(), g3.neg());
+        let y_is_odd = affine.y().to_uint().is_odd();
+        assert_eq!(AffinePoint::decompress(&affine.x(), y_is_odd), Some(affine));
+        assert_eq!(
+            AffinePoint::decompress(&affine.x(), !y_is_odd),
+            Some(affine.neg())
+        );
+
+        // Shamir's trick agrees with two separate multiplications
+        let (a, b) = (
+            Uint::<2>::from_u64(0x1234_5678),
+            Uint::<2>::from_u64(0xFEDC_BA98_7654),
+        );
+        assert_eq!(
+            g.double_mul_vartime(&a, &g3, &b),
+            g.mul(&a).add(&g3.mul(&b))
+        );
+        assert_eq!(g.double_mul_vartime(&a, &g3, &Uint::ZERO), g.mul(&a));
+        assert!(g
+            .double_mul_vartime(&Uint::<1>::ONE, &g.neg(), &Uint::ONE)
+            .is_identity());
+    }
+
+    #[test]
+    fn test_group_laws() {
+        check_group_laws::<4, secp256k1::Secp256k1>();
+        check_group_laws::<4, p256::P256>();
+        check_group_laws::<6, p384::P384>();
+        check_group_laws::<4, P256Iso>();
+    }
+
+    #[test]
+    fn test_formula_selection() {
+        assert!(ProjectivePoint::<4, secp256k1::Secp256k1>::A_SHAPE == CoeffA::Zero);
+        assert!(ProjectivePoint::<4, p256::P256>::A_SHAPE == CoeffA::MinusThree);
+        assert!(ProjectivePoint::<6, p384::P384>::A_SHAPE == CoeffA::MinusThree);
+        assert!(ProjectivePoint::<4, P256Iso>::A_SHAPE == CoeffA::Generic);
+    }
+
+    #[test]
+    fn test_generic_formulas_on_isomorphic_curve() {
+        let k = Uint::<4>::from_u64(112233445566778899);
+        let point = ProjectivePoint::<4, P256Iso>::GENERATOR.mul(&k).to_affine();
+        let expected_x =
+            Uint::from_be_hex("CE4542113B0548D201FFA18AA1AF9DE5DF6FECEB8F65BD3089E5544EBAAAE0BC");
+        let expected_y =
+            Uint::from_be_hex("8E0A6F03E4760D8DC1FA8F42D2F59D0AAC2078FCA39874DAD6D1C5B3A19B510D");
+        assert_eq!(point.x().to_uint(), expected_x);
+        assert_eq!(point.y().to_uint(), expected_y);
+    }
+
+    #[test]
+    fn test_operators() {
+        let g = secp256k1::ProjectivePoint::GENERATOR;
+        let three = secp256k1::Scalar::from_u64(3);
+        let mut acc = g;
+        acc += g;
+        acc += &g;
+        assert_eq!(acc, &g * &three);
+        assert_eq!(acc - g, g.double());
+        acc -= &g;
+        assert_eq!(-acc, g.double().neg());
+        assert_eq!(
+            g * &secp256k1::Scalar::ZERO,
+            secp256k1::ProjectivePoint::IDENTITY
+        );
+    }
+}
@@ -0,0 +1,203 @@
+//! Statistical timing-leakage tests in the style of dudect (Reparaz, Balasch and Verbauwhede,
+//! "Dude, is my code constant time?").
+//!
+//! Each test times one secret-facing operation on two classes of inputs, a fixed value and fresh
+//! random values, interleaved in random order. Welch's t-test then checks whether the two timing
+//! distributions have the same mean. A constant-time operation keeps `|t|` small however many
+//! samples are taken; a leak makes it grow with the sample count.
+//!
+//! The tests need an optimized build and a quiet machine, so they are ignored by default:
+//!
+//! ```text
+//! cargo test --release --test dudect -- --ignored --nocapture
+//! ```
+
+use std::hint::black_box;
+
+use nail::{curves::p256::Scalar, rand::Rng, MontyParams, Uint};
+
+/// `|t|` above this is treated as a leak. dudect reads values above 10 as definitely not constant
+/// time; the margin over the textbook 4.5 absorbs noise from other processes.
+const THRESHOLD: f64 = 10.0;
+
+/// Measurements discarded before sampling starts, to warm up caches and branch predictors.
+const WARMUP: usize = 10_000;
+
+/// SplitMix64, enough to draw inputs and shuffle the classes.
+struct SplitMix(u64);
+
+impl Rng for SplitMix {
+    fn next_u64(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+}
+
+/// Reads a cycle counter, falling back to a monotonic clock in nanoseconds.
+#[inline(always)]
+fn timestamp() -> u64 {
+    #[cfg(target_arch = "x86_64")]
+    {
+        // SAFETY: RDTSC is available on every x86_64 CPU.
+        unsafe { core::arch::x86_64::_rdtsc() }
+    }
+    #[cfg(not(target_arch = "x86_64"))]
+    {
+        use std::{sync::OnceLock, time::Instant};
+        static START: OnceLock<Instant> = OnceLock::new();
+        START.get_or_init(Instant::now).elapsed().as_nanos() as u64
+    }
+}
+
+/// Running mean and variance of each class (Welford's algorithm).
+#[derive(Default)]
+struct Welch {
+    n: [f64; 2],
+    mean: [f64; 2],
+    m2: [f64; 2],
+}
+
+impl Welch {
+    fn push(&mut self, class: usize, x: f64) {
+        self.n[class] += 1.0;
+        let delta = x - self.mean[class];
+        self.mean[class] += delta / self.n[class];
+        self.m2[class] += delta * (x - self.mean[class]);
+    }
+
+    /// Welch's t statistic for the difference between the two class means.
+    fn t(&self) -> f64 {
+        let var = |c: usize| self.m2[c] / (self.n[c] - 1.0);
+        let se = (var(0) / self.n[0] + var(1) / self.n[1]).sqrt();
+        if se == 0.0 {
+            return 0.0;
+        }
+        (self.mean[0] - self.mean[1]) / se
+    }
+}
+
+/// Times `op` on `samples` inputs, each the fixed value or a fresh random one with equal
+/// probability, and returns the largest `|t|` over the raw timings and timings cropped at a few
+/// percentiles, which removes interrupts and other outliers that swamp small differences.
+fn max_t<I, O>(
+    samples: usize,
+    fixed: I,
+    mut random: impl FnMut(&mut SplitMix) -> I,
+    op: impl Fn(&I) -> O,
+) -> f64
+where
+    I: Copy,
+{
+    let mut rng = SplitMix(0x243f_6a88_85a3_08d3);
+    let inputs: Vec<(usize, I)> = (0..WARMUP + samples)
+        .map(|_| {
+            let class = (rng.next_u64() & 1) as usize;
+            let input = if class == 0 { fixed } else { random(&mut rng) };
+            (class, input)
+        })
+        .collect();
+
+    let mut timings = Vec::with_capacity(samples);
+    for (i, (class, input)) in inputs.iter().enumerate() {
+        let input = black_box(*input);
+        let start = timestamp();
+        black_box(op(&input));
+        let elapsed = timestamp().wrapping_sub(start);
+        if i >= WARMUP {
+            timings.push((*class, elapsed));
+        }
+    }
+
+    let mut sorted: Vec<u64> = timings.iter().map(|&(_, t)| t).collect();
+    sorted.sort_unstable();
+    let mut max = 0.0f64;
+    for cutoff in [
+        u64::MAX,
+        sorted[samples / 2],
+        sorted[samples * 9 / 10],
+        sorted[samples * 99 / 100],
+    ] {
+        let mut welch = Welch::default();
+        for &(class, t) in timings.iter().filter(|&&(_, t)| t <= cutoff) {
+            welch.push(class, t as f64);
+        }
+        max = max.max(welch.t().abs());
+    }
+    max
+}
+
+fn assert_constant_time(name: &str, t: f64) {
+    println!("{name}: max |t| = {t:.2}");
+    assert!(
+        t < THRESHOLD,
+        "{name} leaks timing: max |t| = {t:.2} exceeds {THRESHOLD}"
+    );
+}
+
+#[test]
+#[ignore = "timing measurement; run with --release -- --ignored"]
+fn test_mul_is_constant_time() {
+    let b = Uint::<4>::random(&mut SplitMix(1));
+    let t = max_t(1_000_000, Uint::<4>::ZERO, Uint::random, |a| a.mul(&b));
+    assert_constant_time("Uint::mul", t);
+}
+
+#[test]
+#[ignore = "timing measurement; run with --release -- --ignored"]
+fn test_mulmod_bits_is_constant_time() {
+    let b = Uint::<4>::random(&mut SplitMix(2));
+    let t = max_t(1_000_000, Uint::<4>::ZERO, Uint::random, |a| {
+        a.mulmod_bits(&b, 255)
+    });
+    assert_constant_time("Uint::mulmod_bits", t);
+}
+
+#[test]
+#[ignore = "timing measurement; run with --release -- --ignored"]
+fn test_pow_is_constant_time() {
+    // The exponent is the secret, as in RSA decryption.
+    let mut rng = SplitMix(3);
+    let mut modulus = Uint::<4>::random(&mut rng);
+    modulus.limbs[0] |= 1;
+    let monty = MontyParams::new(&modulus);
+    let base = monty.to_monty(&Uint::random(&mut rng));
+    let t = max_t(100_000, Uint::<4>::ZERO, Uint::random, |exp| {
+        monty.pow(&base, exp)
+    });
+    assert_constant_time("MontyParams::pow", t);
+}
+
+#[test]
+#[ignore = "timing measurement; run with --release -- --ignored"]
+fn test_invert_is_constant_time() {
+    // Zero is rejected after the exponentiation, so the fixed class uses one instead.
+    let t = max_t(100_000, Scalar::ONE, Scalar::random, |x| x.invert());
+    assert_constant_time("Fp::invert", t);
+}
+
+#[test]
+#[ignore = "timing measurement; run with --release -- --ignored"]
+fn test_comparisons_are_constant_time() {
+    let b = Uint::<4>::random(&mut SplitMix(4));
+    let t = max_t(1_000_000, Uint::<4>::ZERO, Uint::random, |a| {
+        (a.ct_lt(&b).to_bit(), a.ct_eq(&b).to_bit(), a.cmp(&b))
+    });
+    assert_constant_time("Uint comparisons", t);
+}
+
+#[test]
+#[ignore = "timing measurement; run with --release -- --ignored"]
+fn test_ct_rem_is_constant_time() {
+    // The modulus is the secret, as when reducing modulo an RSA prime.
+    let a = Uint::<4>::random(&mut SplitMix(5));
+    let fixed = Uint::<4>::ONE.shl(128);
+    let t = max_t(100_000, fixed, Uint::random, |m| {
+        let mut m = *m;
+        m.limbs[0] |= 1;
+        a.ct_rem(&m)
+    });
+    assert_constant_time("Uint::ct_rem", t);
+}